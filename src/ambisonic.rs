@@ -0,0 +1,603 @@
+//! # Ambisonic B-Format
+//!
+//! Module containing type for handling first-order ambisonic B-format audio
+//! data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`AmbisonicB`] samples
+///
+/// [`AmbisonicB`]: struct.AmbisonicB.html
+pub type AmbisonicBTrackT = Vec<AmbisonicB>;
+
+/// Struct representing a first-order ambisonic B-format audio sample.
+///
+/// The components use the traditional (FuMa) normalization, where the
+/// omnidirectional `w` component is attenuated by -3dB relative to the
+/// directional components.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct AmbisonicB {
+    /// Omnidirectional (pressure) component.
+    pub w: Sample,
+    /// Front-back figure-of-eight component.
+    pub x: Sample,
+    /// Left-right figure-of-eight component.
+    pub y: Sample,
+    /// Up-down figure-of-eight component.
+    pub z: Sample,
+}
+
+impl AmbisonicB {
+    /// Returns a new AmbisonicB object with default <0,0,0,0> values.
+    pub const fn new() -> Self {
+        AmbisonicB::SILENCE
+    }
+
+    /// Returns a new AmbisonicB object created from the individual B-format
+    /// components.
+    ///
+    /// # Parameters
+    ///
+    /// * `w` - the omnidirectional component.
+    /// * `x` - the front-back component.
+    /// * `y` - the left-right component.
+    /// * `z` - the up-down component.
+    pub const fn from(w: Sample, x: Sample, y: Sample, z: Sample) -> Self {
+        AmbisonicB { w, x, y, z }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 4] {
+        let s: [Sample; 4] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new AmbisonicB object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 4]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Encodes the given sample at the given azimuth and elevation, both given
+    /// in degrees. This is the implementation shared by the [`Panner`] impls.
+    ///
+    /// [`Panner`]: ../trait.Panner.html
+    fn encode(s: Sample, azimuth: AccurateMath, elevation: AccurateMath) -> Self {
+        let s = s.0 as AccurateMath;
+        // The ambisonic convention measures azimuth counter-clockwise
+        let az = -azimuth.to_radians();
+        let el = elevation.to_radians();
+
+        AmbisonicB {
+            w: Sample((s * std::f64::consts::FRAC_1_SQRT_2) as FastMath),
+            x: Sample((s * az.cos() * el.cos()) as FastMath),
+            y: Sample((s * az.sin() * el.cos()) as FastMath),
+            z: Sample((s * el.sin()) as FastMath),
+        }
+    }
+}
+
+impl SampleFormat for AmbisonicB {
+    const CHANNEL_COUNT: usize = 4;
+
+    const SILENCE: Self = AmbisonicB {
+        w: Sample(0.0),
+        x: Sample(0.0),
+        y: Sample(0.0),
+        z: Sample(0.0),
+    };
+    const UNITY: Self = AmbisonicB {
+        w: Sample(1.0),
+        x: Sample(1.0),
+        y: Sample(1.0),
+        z: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        AmbisonicB {
+            w: Sample(x.0 * FastMath::sqrt(0.5)),
+            ..Default::default()
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        Sample(self.w.0 / FastMath::sqrt(0.5))
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[
+            ChannelLabel::Ambisonic(0),
+            ChannelLabel::Ambisonic(3),
+            ChannelLabel::Ambisonic(1),
+            ChannelLabel::Ambisonic(2),
+        ]
+    }
+}
+
+/// Encodes a given sample into first-order B-format at a position given by the
+/// tuple `(azimuth, elevation)` in degrees. The azimuth is measured clockwise
+/// from the front, such that -90 is directly left and 90 is directly right,
+/// matching the other panners of this crate. The elevation is measured upwards
+/// from the horizontal plane.
+impl Panner<(f32, f32)> for AmbisonicB {
+    fn to_sample_format(s: Sample, g: (f32, f32)) -> Self {
+        AmbisonicB::encode(s, g.0 as AccurateMath, g.1 as AccurateMath)
+    }
+}
+impl Panner<(f64, f64)> for AmbisonicB {
+    fn to_sample_format(s: Sample, g: (f64, f64)) -> Self {
+        AmbisonicB::encode(s, g.0 as AccurateMath, g.1 as AccurateMath)
+    }
+}
+impl Panner<SphericalPosition> for AmbisonicB {
+    fn to_sample_format(s: Sample, g: SphericalPosition) -> Self {
+        AmbisonicB::encode(s, g.azimuth, g.elevation)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for AmbisonicB {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.w,
+            1 => &self.x,
+            2 => &self.y,
+            3 => &self.z,
+            _ => panic!(
+                "ERROR: Index {} is out of range for AmbisonicB with 4 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for AmbisonicB {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.w,
+            1 => &mut self.x,
+            2 => &mut self.y,
+            3 => &mut self.z,
+            _ => panic!(
+                "ERROR: Index {} is out of range for AmbisonicB with 4 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for AmbisonicB {
+    fn as_ref(&self) -> &[Sample] {
+        // AmbisonicB is #[repr(C)] and made up solely of 4 Sample fields.
+        unsafe { channel::as_slice(self, 4) }
+    }
+}
+impl AsMut<[Sample]> for AmbisonicB {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // AmbisonicB is #[repr(C)] and made up solely of 4 Sample fields.
+        unsafe { channel::as_mut_slice(self, 4) }
+    }
+}
+
+impl std::ops::Neg for AmbisonicB {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        AmbisonicB {
+            w: Sample(-self.w.0),
+            x: Sample(-self.x.0),
+            y: Sample(-self.y.0),
+            z: Sample(-self.z.0),
+        }
+    }
+}
+
+impl std::ops::Add<AmbisonicB> for AmbisonicB {
+    type Output = Self;
+
+    fn add(self, rhs: AmbisonicB) -> Self::Output {
+        AmbisonicB {
+            w: Sample(self.w.0 + rhs.w.0),
+            x: Sample(self.x.0 + rhs.x.0),
+            y: Sample(self.y.0 + rhs.y.0),
+            z: Sample(self.z.0 + rhs.z.0),
+        }
+    }
+}
+impl std::ops::AddAssign<AmbisonicB> for AmbisonicB {
+    fn add_assign(&mut self, rhs: AmbisonicB) {
+        self.w.0 += rhs.w.0;
+        self.x.0 += rhs.x.0;
+        self.y.0 += rhs.y.0;
+        self.z.0 += rhs.z.0;
+    }
+}
+
+impl std::ops::Sub<AmbisonicB> for AmbisonicB {
+    type Output = Self;
+
+    fn sub(self, rhs: AmbisonicB) -> Self {
+        AmbisonicB {
+            w: Sample(self.w.0 - rhs.w.0),
+            x: Sample(self.x.0 - rhs.x.0),
+            y: Sample(self.y.0 - rhs.y.0),
+            z: Sample(self.z.0 - rhs.z.0),
+        }
+    }
+}
+impl std::ops::SubAssign<AmbisonicB> for AmbisonicB {
+    fn sub_assign(&mut self, rhs: AmbisonicB) {
+        self.w.0 -= rhs.w.0;
+        self.x.0 -= rhs.x.0;
+        self.y.0 -= rhs.y.0;
+        self.z.0 -= rhs.z.0;
+    }
+}
+
+impl std::ops::Mul<AmbisonicB> for AmbisonicB {
+    type Output = AmbisonicB;
+
+    fn mul(self, rhs: AmbisonicB) -> Self::Output {
+        AmbisonicB {
+            w: Sample(self.w.0 * rhs.w.0),
+            x: Sample(self.x.0 * rhs.x.0),
+            y: Sample(self.y.0 * rhs.y.0),
+            z: Sample(self.z.0 * rhs.z.0),
+        }
+    }
+}
+impl std::ops::MulAssign<AmbisonicB> for AmbisonicB {
+    fn mul_assign(&mut self, rhs: AmbisonicB) {
+        self.w.0 *= rhs.w.0;
+        self.x.0 *= rhs.x.0;
+        self.y.0 *= rhs.y.0;
+        self.z.0 *= rhs.z.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for AmbisonicB {
+    type Output = AmbisonicB;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        AmbisonicB {
+            w: Sample(self.w.0 * rhs.0),
+            x: Sample(self.x.0 * rhs.0),
+            y: Sample(self.y.0 * rhs.0),
+            z: Sample(self.z.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for AmbisonicB {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.w.0 *= rhs.0;
+        self.x.0 *= rhs.0;
+        self.y.0 *= rhs.0;
+        self.z.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for AmbisonicB {
+    type Output = AmbisonicB;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        AmbisonicB {
+            w: Sample((self.w.0 as AccurateMath * rhs.0) as FastMath),
+            x: Sample((self.x.0 as AccurateMath * rhs.0) as FastMath),
+            y: Sample((self.y.0 as AccurateMath * rhs.0) as FastMath),
+            z: Sample((self.z.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for AmbisonicB {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.w.0 *= rhs.0 as FastMath;
+        self.x.0 *= rhs.0 as FastMath;
+        self.y.0 *= rhs.0 as FastMath;
+        self.z.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for AmbisonicB {
+    type Output = AmbisonicB;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for AmbisonicB {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for AmbisonicB {
+    type Output = AmbisonicB;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for AmbisonicB {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 4]> for AmbisonicB {
+    type Output = AmbisonicB;
+
+    fn mul(mut self, rhs: [Sample; 4]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 4]> for AmbisonicB {
+    fn mul_assign(&mut self, rhs: [Sample; 4]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for AmbisonicB {
+    fn sum<I: Iterator<Item = AmbisonicB>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a AmbisonicB> for AmbisonicB {
+    fn sum<I: Iterator<Item = &'a AmbisonicB>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for AmbisonicB {
+    fn product<I: Iterator<Item = AmbisonicB>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a AmbisonicB> for AmbisonicB {
+    fn product<I: Iterator<Item = &'a AmbisonicB>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for AmbisonicB {
+    fn from(s: Sample) -> Self {
+        AmbisonicB::from_sample(s)
+    }
+}
+impl Into<Sample> for AmbisonicB {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 4]> for AmbisonicB {
+    fn from(a: [Sample; 4]) -> Self {
+        AmbisonicB {
+            w: a[0],
+            x: a[1],
+            y: a[2],
+            z: a[3],
+        }
+    }
+}
+impl Into<[Sample; 4]> for AmbisonicB {
+    fn into(self) -> [Sample; 4] {
+        [self.w, self.x, self.y, self.z]
+    }
+}
+
+impl From<(Sample, Sample, Sample, Sample)> for AmbisonicB {
+    fn from(t: (Sample, Sample, Sample, Sample)) -> Self {
+        AmbisonicB {
+            w: t.0,
+            x: t.1,
+            y: t.2,
+            z: t.3,
+        }
+    }
+}
+impl Into<(Sample, Sample, Sample, Sample)> for AmbisonicB {
+    fn into(self) -> (Sample, Sample, Sample, Sample) {
+        (self.w, self.x, self.y, self.z)
+    }
+}
+
+impl TryFrom<&[u8]> for AmbisonicB {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 4 {
+            Err(SampleFormatError::WrongLength {
+                expected: 4,
+                actual: v.len(),
+            })
+        } else {
+            Ok(AmbisonicB {
+                w: sample_from_u8(v[0]),
+                x: sample_from_u8(v[1]),
+                y: sample_from_u8(v[2]),
+                z: sample_from_u8(v[3]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for AmbisonicB {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for AmbisonicB {
+    fn into(self) -> Vec<u8> {
+        vec![
+            sample_to_u8(self.w),
+            sample_to_u8(self.x),
+            sample_to_u8(self.y),
+            sample_to_u8(self.z),
+        ]
+    }
+}
+
+impl TryFrom<&[i16]> for AmbisonicB {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 4 {
+            Err(SampleFormatError::WrongLength {
+                expected: 4,
+                actual: v.len(),
+            })
+        } else {
+            Ok(AmbisonicB {
+                w: sample_from_i16(v[0]),
+                x: sample_from_i16(v[1]),
+                y: sample_from_i16(v[2]),
+                z: sample_from_i16(v[3]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for AmbisonicB {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for AmbisonicB {
+    fn into(self) -> Vec<i16> {
+        vec![
+            sample_to_i16(self.w),
+            sample_to_i16(self.x),
+            sample_to_i16(self.y),
+            sample_to_i16(self.z),
+        ]
+    }
+}
+
+impl TryFrom<&[i32]> for AmbisonicB {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 4 {
+            Err(SampleFormatError::WrongLength {
+                expected: 4,
+                actual: v.len(),
+            })
+        } else {
+            Ok(AmbisonicB {
+                w: sample_from_i24(v[0]),
+                x: sample_from_i24(v[1]),
+                y: sample_from_i24(v[2]),
+                z: sample_from_i24(v[3]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for AmbisonicB {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for AmbisonicB {
+    fn into(self) -> Vec<i32> {
+        vec![
+            sample_to_i24(self.w),
+            sample_to_i24(self.x),
+            sample_to_i24(self.y),
+            sample_to_i24(self.z),
+        ]
+    }
+}
+
+impl TryFrom<&[f32]> for AmbisonicB {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 4 {
+            Err(SampleFormatError::WrongLength {
+                expected: 4,
+                actual: v.len(),
+            })
+        } else {
+            Ok(AmbisonicB {
+                w: sample_from_f32(v[0]),
+                x: sample_from_f32(v[1]),
+                y: sample_from_f32(v[2]),
+                z: sample_from_f32(v[3]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for AmbisonicB {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for AmbisonicB {
+    fn into(self) -> Vec<f32> {
+        vec![
+            sample_to_f32(self.w),
+            sample_to_f32(self.x),
+            sample_to_f32(self.y),
+            sample_to_f32(self.z),
+        ]
+    }
+}
+
+impl TryFrom<&[f64]> for AmbisonicB {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 4 {
+            Err(SampleFormatError::WrongLength {
+                expected: 4,
+                actual: v.len(),
+            })
+        } else {
+            Ok(AmbisonicB {
+                w: sample_from_f64(v[0]),
+                x: sample_from_f64(v[1]),
+                y: sample_from_f64(v[2]),
+                z: sample_from_f64(v[3]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for AmbisonicB {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for AmbisonicB {
+    fn into(self) -> Vec<f64> {
+        vec![
+            sample_to_f64(self.w),
+            sample_to_f64(self.x),
+            sample_to_f64(self.y),
+            sample_to_f64(self.z),
+        ]
+    }
+}
@@ -0,0 +1,729 @@
+//! # Binaural
+//!
+//! Module containing type for handling binaural (headphone) audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Binaural`] samples
+///
+/// [`Binaural`]: struct.Binaural.html
+pub type BinauralTrackT = Vec<Binaural>;
+
+/// Radius of the modeled listener's head in meters.
+pub const HEAD_RADIUS: AccurateMath = 0.0875;
+
+/// Speed of sound in air in meters per second.
+pub const SPEED_OF_SOUND: AccurateMath = 343.0;
+
+/// Maximum interaural level difference in decibels, applied to sources
+/// directly to the side of the listener.
+pub const MAX_ILD: AccurateMath = 10.0;
+
+/// Struct representing a binaural audio sample, holding the signals arriving at
+/// each ear of the listener. Unlike [`Stereo`], which is intended for playback
+/// over loudspeakers, binaural samples are intended for headphone playback.
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Binaural {
+    /// Left ear sample value.
+    pub left: Sample,
+    /// Right ear sample value.
+    pub right: Sample,
+}
+
+impl Binaural {
+    /// Returns a new Binaural object with default <0,0> values.
+    pub const fn new() -> Self {
+        Binaural::SILENCE
+    }
+
+    /// Returns a new Binaural object created from individual left and right ear
+    /// audio samples.
+    ///
+    /// # Parameters
+    ///
+    /// * `l` - the left ear audio sample.
+    /// * `r` - the right ear audio sample.
+    pub const fn from(l: Sample, r: Sample) -> Self {
+        Binaural { left: l, right: r }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 2] {
+        let s: [Sample; 2] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Binaural object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 2]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Calculates the interaural time difference in seconds of a source at the
+    /// given azimuth in degrees using Woodworth's spherical head model. The
+    /// result is positive when the source is to the right of the listener,
+    /// meaning the left ear is delayed, and negative when it is to the left.
+    ///
+    /// # Parameters
+    ///
+    /// * `azimuth` - the azimuth of the source in degrees, measured clockwise
+    /// from the front.
+    pub fn interaural_time_difference(azimuth: AccurateMath) -> AccurateMath {
+        let az = azimuth.to_radians();
+        // Lateral angle, folding sources behind the listener to the front
+        let lat = az.sin().asin();
+
+        HEAD_RADIUS / SPEED_OF_SOUND * (lat + lat.sin())
+    }
+
+    /// Calculates the linear gains of the left and right ears respectively for
+    /// a source at the given azimuth in degrees. The ear nearest to the source
+    /// is kept at unity gain, while the far ear is attenuated by up to
+    /// [`MAX_ILD`] decibels for sources directly to the side of the listener.
+    ///
+    /// # Parameters
+    ///
+    /// * `azimuth` - the azimuth of the source in degrees, measured clockwise
+    /// from the front.
+    ///
+    /// [`MAX_ILD`]: constant.MAX_ILD.html
+    pub fn interaural_level_gains(azimuth: AccurateMath) -> (Math, Math) {
+        let lat = azimuth.to_radians().sin();
+        let far = db_to_linear(Math(-MAX_ILD * lat.abs())).0;
+
+        if lat >= 0.0 {
+            (Math(far), Math(1.0))
+        } else {
+            (Math(1.0), Math(far))
+        }
+    }
+}
+
+impl SampleFormat for Binaural {
+    const CHANNEL_COUNT: usize = 2;
+
+    const SILENCE: Self = Binaural {
+        left: Sample(0.0),
+        right: Sample(0.0),
+    };
+    const UNITY: Self = Binaural {
+        left: Sample(1.0),
+        right: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        Binaural {
+            left: Sample(x.0 * FastMath::sqrt(0.5)),
+            right: Sample(x.0 * FastMath::sqrt(0.5)),
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        Sample((self.left.0 + self.right.0) * FastMath::sqrt(0.5))
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[ChannelLabel::FrontLeft, ChannelLabel::FrontRight]
+    }
+}
+
+/// Pans a given sample for headphone playback to the given azimuth in degrees,
+/// measured clockwise from the front such that -90 is directly left and 90 is
+/// directly right. Only the interaural level difference is applied, as a
+/// stateless panner is unable to delay one of the ears. Use a
+/// [`BinauralPanner`] to also apply the interaural time difference.
+///
+/// [`BinauralPanner`]: struct.BinauralPanner.html
+impl Panner<f32> for Binaural {
+    fn to_sample_format(s: Sample, g: f32) -> Self {
+        let (l, r) = Binaural::interaural_level_gains(g as AccurateMath);
+
+        Binaural {
+            left: Sample((s.0 as AccurateMath * l.0) as FastMath),
+            right: Sample((s.0 as AccurateMath * r.0) as FastMath),
+        }
+    }
+}
+impl Panner<f64> for Binaural {
+    fn to_sample_format(s: Sample, g: f64) -> Self {
+        let (l, r) = Binaural::interaural_level_gains(g as AccurateMath);
+
+        Binaural {
+            left: Sample((s.0 as AccurateMath * l.0) as FastMath),
+            right: Sample((s.0 as AccurateMath * r.0) as FastMath),
+        }
+    }
+}
+
+/// Stateful panner for headphone playback applying both the interaural level
+/// difference and the interaural time difference to a monophonic source. The
+/// time difference is applied by delaying the ear furthest from the source by a
+/// whole number of samples.
+#[derive(Debug, Clone)]
+pub struct BinauralPanner {
+    sample_rate: AccurateMath,
+    delay: Vec<Sample>,
+    index: usize,
+}
+
+impl BinauralPanner {
+    /// Creates a new BinauralPanner for the given sample rate.
+    ///
+    /// # Parameters
+    ///
+    /// * `sample_rate` - the sample rate of the audio being processed in Hz.
+    pub fn new(sample_rate: Math) -> Self {
+        let max = Binaural::interaural_time_difference(90.0) * sample_rate.0;
+
+        BinauralPanner {
+            sample_rate: sample_rate.0,
+            delay: vec![Sample(0.0); max.ceil() as usize + 1],
+            index: 0,
+        }
+    }
+
+    /// Processes the next sample of the source, panning it to the given
+    /// azimuth in degrees measured clockwise from the front.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next monophonic sample of the source.
+    /// * `azimuth` - the azimuth of the source in degrees.
+    pub fn process(&mut self, s: Sample, azimuth: AccurateMath) -> Binaural {
+        let len = self.delay.len();
+        self.delay[self.index] = s;
+
+        let itd = Binaural::interaural_time_difference(azimuth) * self.sample_rate;
+        let lag = (itd.abs().round() as usize).min(len - 1);
+        let delayed = self.delay[(self.index + len - lag) % len];
+
+        self.index = (self.index + 1) % len;
+
+        let (l, r) = Binaural::interaural_level_gains(azimuth);
+        let (ls, rs) = if itd >= 0.0 {
+            (delayed, s)
+        } else {
+            (s, delayed)
+        };
+
+        Binaural {
+            left: Sample((ls.0 as AccurateMath * l.0) as FastMath),
+            right: Sample((rs.0 as AccurateMath * r.0) as FastMath),
+        }
+    }
+
+    /// Clears the internal delay line.
+    pub fn reset(&mut self) {
+        for s in self.delay.iter_mut() {
+            *s = Sample(0.0);
+        }
+        self.index = 0;
+    }
+}
+
+impl Balancer for Binaural {}
+
+/// Stateful panner for headphone playback modeling the listener's head as a
+/// sphere. Compared to the [`BinauralPanner`], the interaural time difference
+/// is applied with a fractional-sample delay, allowing it to change smoothly as
+/// the source moves, and the interaural level difference is applied with a
+/// high-shelf filter rather than a broadband gain. As the head only shadows the
+/// far ear from wavelengths shorter than itself, low frequencies reach both
+/// ears at the same level, which gives noticeably better externalization than
+/// attenuating the whole signal.
+///
+/// [`BinauralPanner`]: struct.BinauralPanner.html
+#[derive(Debug, Clone)]
+pub struct HeadShadowPanner {
+    sample_rate: AccurateMath,
+    delay: Vec<Sample>,
+    index: usize,
+    coefficient: AccurateMath,
+    lowpass: [AccurateMath; 2],
+}
+
+impl HeadShadowPanner {
+    /// Creates a new HeadShadowPanner for the given sample rate.
+    ///
+    /// # Parameters
+    ///
+    /// * `sample_rate` - the sample rate of the audio being processed in Hz.
+    pub fn new(sample_rate: Math) -> Self {
+        let max = Binaural::interaural_time_difference(90.0) * sample_rate.0;
+        let cutoff = HeadShadowPanner::shelf_frequency();
+
+        HeadShadowPanner {
+            sample_rate: sample_rate.0,
+            delay: vec![Sample(0.0); max.ceil() as usize + 2],
+            index: 0,
+            coefficient: (-2.0 * std::f64::consts::PI * cutoff / sample_rate.0).exp(),
+            lowpass: [0.0; 2],
+        }
+    }
+
+    /// Returns the corner frequency in Hz of the head shadow filter, above
+    /// which the far ear is attenuated. This is the frequency whose wavelength
+    /// is equal to the circumference of the modeled head.
+    pub fn shelf_frequency() -> AccurateMath {
+        SPEED_OF_SOUND / (2.0 * std::f64::consts::PI * HEAD_RADIUS)
+    }
+
+    /// Processes the next sample of the source, panning it to the given
+    /// azimuth in degrees measured clockwise from the front.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next monophonic sample of the source.
+    /// * `azimuth` - the azimuth of the source in degrees.
+    pub fn process(&mut self, s: Sample, azimuth: AccurateMath) -> Binaural {
+        let len = self.delay.len();
+        self.delay[self.index] = s;
+
+        let itd = Binaural::interaural_time_difference(azimuth) * self.sample_rate;
+        let lag = itd.abs().min((len - 2) as AccurateMath);
+        let whole = lag.floor() as usize;
+        let frac = lag - whole as AccurateMath;
+
+        let a = self.delay[(self.index + len - whole) % len].0 as AccurateMath;
+        let b = self.delay[(self.index + len - whole - 1) % len].0 as AccurateMath;
+        let delayed = a + (b - a) * frac;
+
+        self.index = (self.index + 1) % len;
+
+        let (l, r) = Binaural::interaural_level_gains(azimuth);
+        let (ls, rs) = if itd >= 0.0 {
+            (delayed, s.0 as AccurateMath)
+        } else {
+            (s.0 as AccurateMath, delayed)
+        };
+
+        Binaural {
+            left: Sample(self.shelve(0, ls, l.0) as FastMath),
+            right: Sample(self.shelve(1, rs, r.0) as FastMath),
+        }
+    }
+
+    /// Applies the head shadow filter of the given ear, passing frequencies
+    /// below the corner frequency unchanged and scaling those above it by the
+    /// given gain.
+    fn shelve(&mut self, ear: usize, x: AccurateMath, gain: AccurateMath) -> AccurateMath {
+        let c = self.coefficient;
+        let low = (1.0 - c) * x + c * self.lowpass[ear];
+        self.lowpass[ear] = low;
+
+        low + (x - low) * gain
+    }
+
+    /// Clears the internal delay line and filter state.
+    pub fn reset(&mut self) {
+        for s in self.delay.iter_mut() {
+            *s = Sample(0.0);
+        }
+        self.index = 0;
+        self.lowpass = [0.0; 2];
+    }
+}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Binaural {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.left,
+            1 => &self.right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Binaural with 2 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Binaural {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.left,
+            1 => &mut self.right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Binaural with 2 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Binaural {
+    fn as_ref(&self) -> &[Sample] {
+        // Binaural is #[repr(C)] and made up solely of 2 Sample fields.
+        unsafe { channel::as_slice(self, 2) }
+    }
+}
+impl AsMut<[Sample]> for Binaural {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Binaural is #[repr(C)] and made up solely of 2 Sample fields.
+        unsafe { channel::as_mut_slice(self, 2) }
+    }
+}
+
+impl std::ops::Neg for Binaural {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Binaural {
+            left: Sample(-self.left.0),
+            right: Sample(-self.right.0),
+        }
+    }
+}
+
+impl std::ops::Add<Binaural> for Binaural {
+    type Output = Self;
+
+    fn add(self, rhs: Binaural) -> Self::Output {
+        Binaural {
+            left: Sample(self.left.0 + rhs.left.0),
+            right: Sample(self.right.0 + rhs.right.0),
+        }
+    }
+}
+impl std::ops::AddAssign<Binaural> for Binaural {
+    fn add_assign(&mut self, rhs: Binaural) {
+        self.left.0 += rhs.left.0;
+        self.right.0 += rhs.right.0;
+    }
+}
+
+impl std::ops::Sub<Binaural> for Binaural {
+    type Output = Self;
+
+    fn sub(self, rhs: Binaural) -> Self {
+        Binaural {
+            left: Sample(self.left.0 - rhs.left.0),
+            right: Sample(self.right.0 - rhs.right.0),
+        }
+    }
+}
+impl std::ops::SubAssign<Binaural> for Binaural {
+    fn sub_assign(&mut self, rhs: Binaural) {
+        self.left.0 -= rhs.left.0;
+        self.right.0 -= rhs.right.0;
+    }
+}
+
+impl std::ops::Mul<Binaural> for Binaural {
+    type Output = Binaural;
+
+    fn mul(self, rhs: Binaural) -> Self::Output {
+        Binaural {
+            left: Sample(self.left.0 * rhs.left.0),
+            right: Sample(self.right.0 * rhs.right.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Binaural> for Binaural {
+    fn mul_assign(&mut self, rhs: Binaural) {
+        self.left.0 *= rhs.left.0;
+        self.right.0 *= rhs.right.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for Binaural {
+    type Output = Binaural;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        Binaural {
+            left: Sample(self.left.0 * rhs.0),
+            right: Sample(self.right.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for Binaural {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.left.0 *= rhs.0;
+        self.right.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for Binaural {
+    type Output = Binaural;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        Binaural {
+            left: Sample((self.left.0 as AccurateMath * rhs.0) as FastMath),
+            right: Sample((self.right.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for Binaural {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.left.0 *= rhs.0 as FastMath;
+        self.right.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for Binaural {
+    type Output = Binaural;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Binaural {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Binaural {
+    type Output = Binaural;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Binaural {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 2]> for Binaural {
+    type Output = Binaural;
+
+    fn mul(mut self, rhs: [Sample; 2]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 2]> for Binaural {
+    fn mul_assign(&mut self, rhs: [Sample; 2]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Binaural {
+    fn sum<I: Iterator<Item = Binaural>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Binaural> for Binaural {
+    fn sum<I: Iterator<Item = &'a Binaural>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Binaural {
+    fn product<I: Iterator<Item = Binaural>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Binaural> for Binaural {
+    fn product<I: Iterator<Item = &'a Binaural>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for Binaural {
+    fn from(s: Sample) -> Self {
+        Binaural::from_sample(s)
+    }
+}
+impl Into<Sample> for Binaural {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 2]> for Binaural {
+    fn from(a: [Sample; 2]) -> Self {
+        Binaural {
+            left: a[0],
+            right: a[1],
+        }
+    }
+}
+impl Into<[Sample; 2]> for Binaural {
+    fn into(self) -> [Sample; 2] {
+        [self.left, self.right]
+    }
+}
+
+impl From<(Sample, Sample)> for Binaural {
+    fn from(t: (Sample, Sample)) -> Self {
+        Binaural {
+            left: t.0,
+            right: t.1,
+        }
+    }
+}
+impl Into<(Sample, Sample)> for Binaural {
+    fn into(self) -> (Sample, Sample) {
+        (self.left, self.right)
+    }
+}
+
+impl TryFrom<&[u8]> for Binaural {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Binaural {
+                left: sample_from_u8(v[0]),
+                right: sample_from_u8(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for Binaural {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for Binaural {
+    fn into(self) -> Vec<u8> {
+        vec![sample_to_u8(self.left), sample_to_u8(self.right)]
+    }
+}
+
+impl TryFrom<&[i16]> for Binaural {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Binaural {
+                left: sample_from_i16(v[0]),
+                right: sample_from_i16(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for Binaural {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for Binaural {
+    fn into(self) -> Vec<i16> {
+        vec![sample_to_i16(self.left), sample_to_i16(self.right)]
+    }
+}
+
+impl TryFrom<&[i32]> for Binaural {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Binaural {
+                left: sample_from_i24(v[0]),
+                right: sample_from_i24(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for Binaural {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for Binaural {
+    fn into(self) -> Vec<i32> {
+        vec![sample_to_i24(self.left), sample_to_i24(self.right)]
+    }
+}
+
+impl TryFrom<&[f32]> for Binaural {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Binaural {
+                left: sample_from_f32(v[0]),
+                right: sample_from_f32(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Binaural {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Binaural {
+    fn into(self) -> Vec<f32> {
+        vec![sample_to_f32(self.left), sample_to_f32(self.right)]
+    }
+}
+
+impl TryFrom<&[f64]> for Binaural {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Binaural {
+                left: sample_from_f64(v[0]),
+                right: sample_from_f64(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Binaural {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Binaural {
+    fn into(self) -> Vec<f64> {
+        vec![sample_to_f64(self.left), sample_to_f64(self.right)]
+    }
+}
@@ -0,0 +1,31 @@
+//! # Bit Depth
+//!
+//! Module containing the encodings of PCM sample values supported by the bulk
+//! track conversions.
+
+/// Enum of the encodings in which PCM sample values can be stored.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BitDepth {
+    /// Unsigned 8-bit integers, with silence at 128.
+    U8,
+    /// Signed 16-bit integers.
+    I16,
+    /// Signed 24-bit integers packed into 3 bytes.
+    I24,
+    /// Signed 32-bit integers.
+    I32,
+    /// 32-bit IEEE 754 floating point values, nominally in the range -1 to 1.
+    F32,
+}
+
+impl BitDepth {
+    /// Returns the number of bytes used to store a single sample value.
+    pub const fn bytes(self) -> usize {
+        match self {
+            BitDepth::U8 => 1,
+            BitDepth::I16 => 2,
+            BitDepth::I24 => 3,
+            BitDepth::I32 | BitDepth::F32 => 4,
+        }
+    }
+}
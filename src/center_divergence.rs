@@ -0,0 +1,38 @@
+//! # Center Divergence
+//!
+//! Module containing the amount of a panned source diverted from the center
+//! channel to a phantom center between the front left and right channels.
+
+use super::*;
+
+/// Proportion of the center channel of a panned source that is diverted to a
+/// phantom center between the front left and right channels, given to the
+/// panners of formats with a center channel alongside the position of the
+/// source, e.g. `Lcr::to_sample_format(s, (0.0, CenterDivergence(0.5)))`.
+///
+/// A divergence of 0 leaves the center channel untouched, while a divergence
+/// of 1 removes it entirely, reproducing the center only as a phantom image.
+/// Values in between split the center between the two with constant power.
+/// Values outside of the range \[0,1\] are clamped to it.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct CenterDivergence(pub AccurateMath);
+
+impl CenterDivergence {
+    /// Diverts the given proportion of the center channel to the left and
+    /// right channels.
+    ///
+    /// # Parameters
+    ///
+    /// * `left` - the front left channel.
+    /// * `center` - the center channel.
+    /// * `right` - the front right channel.
+    pub(crate) fn apply(self, left: &mut Sample, center: &mut Sample, right: &mut Sample) {
+        let f = self.0.clamp(0.0, 1.0) * std::f64::consts::FRAC_PI_2;
+        let c = center.0 as AccurateMath;
+        let side = c * f.sin() * std::f64::consts::FRAC_1_SQRT_2;
+
+        left.0 += side as FastMath;
+        right.0 += side as FastMath;
+        center.0 = (c * f.cos()) as FastMath;
+    }
+}
@@ -0,0 +1,229 @@
+//! # Channel
+//!
+//! Module containing types describing the channels of the sample formats.
+
+use super::*;
+
+/// Enum labeling the speaker position or meaning of a single channel of a
+/// sample format.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ChannelLabel {
+    /// Front left speaker.
+    FrontLeft,
+    /// Front right speaker.
+    FrontRight,
+    /// Front center speaker.
+    Center,
+    /// Low-frequency effects channel.
+    Lfe,
+    /// Second low-frequency effects channel.
+    Lfe2,
+    /// Front left of center speaker.
+    FrontLeftCenter,
+    /// Front right of center speaker.
+    FrontRightCenter,
+    /// Side left speaker.
+    SideLeft,
+    /// Side right speaker.
+    SideRight,
+    /// Rear left speaker.
+    RearLeft,
+    /// Rear right speaker.
+    RearRight,
+    /// Rear center speaker.
+    RearCenter,
+    /// Top center speaker, directly above the listener.
+    TopCenter,
+    /// Top front left speaker.
+    TopFrontLeft,
+    /// Top front right speaker.
+    TopFrontRight,
+    /// Top front center speaker.
+    TopFrontCenter,
+    /// Top side left speaker.
+    TopSideLeft,
+    /// Top side right speaker.
+    TopSideRight,
+    /// Top rear left speaker.
+    TopRearLeft,
+    /// Top rear right speaker.
+    TopRearRight,
+    /// Top rear center speaker.
+    TopRearCenter,
+    /// Bottom front left speaker.
+    BottomFrontLeft,
+    /// Bottom front right speaker.
+    BottomFrontRight,
+    /// Bottom front center speaker.
+    BottomFrontCenter,
+    /// Mid (sum) channel of a mid/side encoded signal.
+    Mid,
+    /// Side (difference) channel of a mid/side encoded signal.
+    Side,
+    /// Ambisonic component with the given Ambisonic Channel Number (ACN).
+    Ambisonic(u16),
+    /// Channel with no defined position, identified only by its index.
+    Discrete(u16),
+}
+
+impl ChannelLabel {
+    /// Returns true if the channel is positioned to the left of the listener.
+    pub fn is_left(self) -> bool {
+        matches!(
+            self,
+            ChannelLabel::FrontLeft
+                | ChannelLabel::FrontLeftCenter
+                | ChannelLabel::SideLeft
+                | ChannelLabel::RearLeft
+                | ChannelLabel::TopFrontLeft
+                | ChannelLabel::TopSideLeft
+                | ChannelLabel::TopRearLeft
+                | ChannelLabel::BottomFrontLeft
+        )
+    }
+
+    /// Returns true if the channel is positioned to the right of the listener.
+    pub fn is_right(self) -> bool {
+        matches!(
+            self,
+            ChannelLabel::FrontRight
+                | ChannelLabel::FrontRightCenter
+                | ChannelLabel::SideRight
+                | ChannelLabel::RearRight
+                | ChannelLabel::TopFrontRight
+                | ChannelLabel::TopSideRight
+                | ChannelLabel::TopRearRight
+                | ChannelLabel::BottomFrontRight
+        )
+    }
+
+    /// Returns the nominal direction of the speaker of the channel relative to
+    /// the listener, or `None` for channels without a position, such as the
+    /// LFE, mid/side, Ambisonic and discrete channels. Elevated speakers are
+    /// placed at 45 degrees and bottom speakers at -30 degrees, with the top
+    /// center speaker directly above the listener.
+    ///
+    /// The position is shared by every format with the channel, and is used to
+    /// route channels between formats. The panners instead place the speakers
+    /// at the angles of the standard of each layout, such as the surround
+    /// speakers of 5.1 at ±110 degrees following ITU-R BS.775.
+    pub fn position(self) -> Option<SphericalPosition> {
+        let (azimuth, elevation) = match self {
+            ChannelLabel::FrontLeft => (-30.0, 0.0),
+            ChannelLabel::FrontRight => (30.0, 0.0),
+            ChannelLabel::Center => (0.0, 0.0),
+            ChannelLabel::FrontLeftCenter => (-15.0, 0.0),
+            ChannelLabel::FrontRightCenter => (15.0, 0.0),
+            ChannelLabel::SideLeft => (-90.0, 0.0),
+            ChannelLabel::SideRight => (90.0, 0.0),
+            ChannelLabel::RearLeft => (-135.0, 0.0),
+            ChannelLabel::RearRight => (135.0, 0.0),
+            ChannelLabel::RearCenter => (180.0, 0.0),
+            ChannelLabel::TopCenter => (0.0, 90.0),
+            ChannelLabel::TopFrontLeft => (-30.0, 45.0),
+            ChannelLabel::TopFrontRight => (30.0, 45.0),
+            ChannelLabel::TopFrontCenter => (0.0, 45.0),
+            ChannelLabel::TopSideLeft => (-90.0, 45.0),
+            ChannelLabel::TopSideRight => (90.0, 45.0),
+            ChannelLabel::TopRearLeft => (-135.0, 45.0),
+            ChannelLabel::TopRearRight => (135.0, 45.0),
+            ChannelLabel::TopRearCenter => (180.0, 45.0),
+            ChannelLabel::BottomFrontLeft => (-30.0, -30.0),
+            ChannelLabel::BottomFrontRight => (30.0, -30.0),
+            ChannelLabel::BottomFrontCenter => (0.0, -30.0),
+            ChannelLabel::Lfe
+            | ChannelLabel::Lfe2
+            | ChannelLabel::Mid
+            | ChannelLabel::Side
+            | ChannelLabel::Ambisonic(_)
+            | ChannelLabel::Discrete(_) => return None,
+        };
+
+        Some(SphericalPosition::new(azimuth, elevation))
+    }
+}
+
+/// Formats the label with the abbreviation commonly used on speaker layout
+/// diagrams, such as `L`, `C` or `LFE`. Ambisonic labels are formatted as their
+/// ACN and discrete labels as their index.
+impl std::fmt::Display for ChannelLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChannelLabel::FrontLeft => write!(f, "L"),
+            ChannelLabel::FrontRight => write!(f, "R"),
+            ChannelLabel::Center => write!(f, "C"),
+            ChannelLabel::Lfe => write!(f, "LFE"),
+            ChannelLabel::Lfe2 => write!(f, "LFE2"),
+            ChannelLabel::FrontLeftCenter => write!(f, "Lc"),
+            ChannelLabel::FrontRightCenter => write!(f, "Rc"),
+            ChannelLabel::SideLeft => write!(f, "Lss"),
+            ChannelLabel::SideRight => write!(f, "Rss"),
+            ChannelLabel::RearLeft => write!(f, "Lrs"),
+            ChannelLabel::RearRight => write!(f, "Rrs"),
+            ChannelLabel::RearCenter => write!(f, "Cs"),
+            ChannelLabel::TopCenter => write!(f, "Tc"),
+            ChannelLabel::TopFrontLeft => write!(f, "Tfl"),
+            ChannelLabel::TopFrontRight => write!(f, "Tfr"),
+            ChannelLabel::TopFrontCenter => write!(f, "Tfc"),
+            ChannelLabel::TopSideLeft => write!(f, "Tsl"),
+            ChannelLabel::TopSideRight => write!(f, "Tsr"),
+            ChannelLabel::TopRearLeft => write!(f, "Trl"),
+            ChannelLabel::TopRearRight => write!(f, "Trr"),
+            ChannelLabel::TopRearCenter => write!(f, "Trc"),
+            ChannelLabel::BottomFrontLeft => write!(f, "Bfl"),
+            ChannelLabel::BottomFrontRight => write!(f, "Bfr"),
+            ChannelLabel::BottomFrontCenter => write!(f, "Bfc"),
+            ChannelLabel::Mid => write!(f, "M"),
+            ChannelLabel::Side => write!(f, "S"),
+            ChannelLabel::Ambisonic(n) => write!(f, "ACN{}", n),
+            ChannelLabel::Discrete(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+/// Creates an array of `N` [`Discrete`] labels numbered from 0.
+///
+/// [`Discrete`]: enum.ChannelLabel.html#variant.Discrete
+pub(crate) const fn discrete_labels<const N: usize>() -> [ChannelLabel; N] {
+    let mut labels = [ChannelLabel::Discrete(0); N];
+    let mut i = 0;
+    while i < N {
+        labels[i] = ChannelLabel::Discrete(i as u16);
+        i += 1;
+    }
+    labels
+}
+
+/// Creates an array of `N` [`Ambisonic`] labels in ACN order.
+///
+/// [`Ambisonic`]: enum.ChannelLabel.html#variant.Ambisonic
+pub(crate) const fn ambisonic_labels<const N: usize>() -> [ChannelLabel; N] {
+    let mut labels = [ChannelLabel::Ambisonic(0); N];
+    let mut i = 0;
+    while i < N {
+        labels[i] = ChannelLabel::Ambisonic(i as u16);
+        i += 1;
+    }
+    labels
+}
+
+/// Views the channels of a sample format stored as named fields as a slice.
+///
+/// # Safety
+///
+/// `T` must be `#[repr(C)]` and made up solely of `n` `Sample` fields.
+pub(crate) unsafe fn as_slice<T>(t: &T, n: usize) -> &[Sample] {
+    debug_assert_eq!(std::mem::size_of::<T>(), n * std::mem::size_of::<Sample>());
+    std::slice::from_raw_parts(t as *const T as *const Sample, n)
+}
+
+/// Views the channels of a sample format stored as named fields as a mutable
+/// slice.
+///
+/// # Safety
+///
+/// `T` must be `#[repr(C)]` and made up solely of `n` `Sample` fields.
+pub(crate) unsafe fn as_mut_slice<T>(t: &mut T, n: usize) -> &mut [Sample] {
+    debug_assert_eq!(std::mem::size_of::<T>(), n * std::mem::size_of::<Sample>());
+    std::slice::from_raw_parts_mut(t as *mut T as *mut Sample, n)
+}
@@ -0,0 +1,151 @@
+//! # Channel Layout
+//!
+//! Module containing a descriptor for the order of channels within audio data,
+//! allowing data decoded in one channel order to be rearranged into another.
+
+use super::*;
+
+/// Type describing an ordering of channels by their labels.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ChannelLayout {
+    labels: Vec<ChannelLabel>,
+}
+
+impl ChannelLayout {
+    /// Creates a new layout from the given channel labels.
+    ///
+    /// # Parameters
+    ///
+    /// * `labels` - the labels of the channels in order.
+    pub fn new(labels: Vec<ChannelLabel>) -> Self {
+        ChannelLayout { labels }
+    }
+
+    /// Creates the layout in which the channels of the given sample format are
+    /// stored.
+    pub fn from_format<F: SampleFormat>() -> Self {
+        ChannelLayout {
+            labels: F::channel_labels().to_vec(),
+        }
+    }
+
+    /// Creates the layout of a `WAVEFORMATEXTENSIBLE` stream with the given
+    /// speaker mask.
+    ///
+    /// # Parameters
+    ///
+    /// * `mask` - the speaker mask of the stream.
+    pub fn from_mask(mask: ChannelMask) -> Self {
+        ChannelLayout {
+            labels: mask.labels(),
+        }
+    }
+
+    /// Returns the labels of the channels in order.
+    pub fn labels(&self) -> &[ChannelLabel] {
+        &self.labels
+    }
+
+    /// Returns the number of channels in the layout.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns true if the layout contains no channels.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Returns the index of the channel with the given label, if it is present
+    /// in the layout.
+    ///
+    /// # Parameters
+    ///
+    /// * `label` - the label of the channel to find.
+    pub fn index_of(&self, label: ChannelLabel) -> Option<usize> {
+        self.labels.iter().position(|l| *l == label)
+    }
+
+    /// Calculates the mapping from this layout to the given one. For each
+    /// channel of `to`, the returned vector contains the index of the same
+    /// channel within this layout. Both layouts must contain exactly the same
+    /// channels, otherwise an error is returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `to` - the layout to map to.
+    pub fn mapping(&self, to: &ChannelLayout) -> Result<Vec<usize>, SampleFormatError> {
+        if self.len() != to.len() {
+            return Err(SampleFormatError::ChannelCountMismatch {
+                left: self.len(),
+                right: to.len(),
+            });
+        }
+
+        to.labels
+            .iter()
+            .map(|l| {
+                self.index_of(*l)
+                    .ok_or(SampleFormatError::MissingChannel(*l))
+            })
+            .collect()
+    }
+}
+
+/// Rearranges a single frame of channels from one layout to another in place.
+///
+/// # Parameters
+///
+/// * `from` - the layout the frame is currently in.
+/// * `to` - the layout to rearrange the frame into.
+/// * `frame` - the channels of the frame, with length equal to that of the
+/// layouts.
+pub fn reorder(
+    from: &ChannelLayout,
+    to: &ChannelLayout,
+    frame: &mut [Sample],
+) -> Result<(), SampleFormatError> {
+    if frame.len() != from.len() {
+        return Err(SampleFormatError::WrongLength {
+            expected: from.len(),
+            actual: frame.len(),
+        });
+    }
+
+    reorder_interleaved(from, to, frame)
+}
+
+/// Rearranges a buffer of interleaved frames from one layout to another in
+/// place, such as a track read from a file.
+///
+/// # Parameters
+///
+/// * `from` - the layout the frames are currently in.
+/// * `to` - the layout to rearrange the frames into.
+/// * `data` - the interleaved frames, with a length that is a multiple of the
+/// length of the layouts.
+pub fn reorder_interleaved(
+    from: &ChannelLayout,
+    to: &ChannelLayout,
+    data: &mut [Sample],
+) -> Result<(), SampleFormatError> {
+    let map = from.mapping(to)?;
+    let n = map.len();
+
+    if n == 0 || data.len() % n != 0 {
+        return Err(SampleFormatError::UnalignedLength {
+            channels: n,
+            actual: data.len(),
+        });
+    }
+
+    let mut tmp = vec![Sample(0.0); n];
+    for frame in data.chunks_exact_mut(n) {
+        tmp.copy_from_slice(frame);
+        for (c, i) in frame.iter_mut().zip(map.iter()) {
+            *c = tmp[*i];
+        }
+    }
+
+    Ok(())
+}
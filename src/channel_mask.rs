@@ -0,0 +1,189 @@
+//! # Channel Mask
+//!
+//! Module containing the speaker mask used by Microsoft's
+//! `WAVEFORMATEXTENSIBLE` structure and WASAPI, and its mapping to the channels
+//! of the sample formats.
+
+use super::*;
+
+/// Bitflag type matching the `dwChannelMask` speaker mask of Microsoft's
+/// `WAVEFORMATEXTENSIBLE` structure. Each set bit represents a speaker present
+/// in the stream, and the channels of a stream are ordered by increasing bit.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ChannelMask(pub u32);
+
+impl ChannelMask {
+    /// Mask with no speakers set.
+    pub const NONE: ChannelMask = ChannelMask(0);
+    /// `SPEAKER_FRONT_LEFT`
+    pub const FRONT_LEFT: ChannelMask = ChannelMask(0x1);
+    /// `SPEAKER_FRONT_RIGHT`
+    pub const FRONT_RIGHT: ChannelMask = ChannelMask(0x2);
+    /// `SPEAKER_FRONT_CENTER`
+    pub const FRONT_CENTER: ChannelMask = ChannelMask(0x4);
+    /// `SPEAKER_LOW_FREQUENCY`
+    pub const LOW_FREQUENCY: ChannelMask = ChannelMask(0x8);
+    /// `SPEAKER_BACK_LEFT`
+    pub const BACK_LEFT: ChannelMask = ChannelMask(0x10);
+    /// `SPEAKER_BACK_RIGHT`
+    pub const BACK_RIGHT: ChannelMask = ChannelMask(0x20);
+    /// `SPEAKER_FRONT_LEFT_OF_CENTER`
+    pub const FRONT_LEFT_OF_CENTER: ChannelMask = ChannelMask(0x40);
+    /// `SPEAKER_FRONT_RIGHT_OF_CENTER`
+    pub const FRONT_RIGHT_OF_CENTER: ChannelMask = ChannelMask(0x80);
+    /// `SPEAKER_BACK_CENTER`
+    pub const BACK_CENTER: ChannelMask = ChannelMask(0x100);
+    /// `SPEAKER_SIDE_LEFT`
+    pub const SIDE_LEFT: ChannelMask = ChannelMask(0x200);
+    /// `SPEAKER_SIDE_RIGHT`
+    pub const SIDE_RIGHT: ChannelMask = ChannelMask(0x400);
+    /// `SPEAKER_TOP_CENTER`
+    pub const TOP_CENTER: ChannelMask = ChannelMask(0x800);
+    /// `SPEAKER_TOP_FRONT_LEFT`
+    pub const TOP_FRONT_LEFT: ChannelMask = ChannelMask(0x1000);
+    /// `SPEAKER_TOP_FRONT_CENTER`
+    pub const TOP_FRONT_CENTER: ChannelMask = ChannelMask(0x2000);
+    /// `SPEAKER_TOP_FRONT_RIGHT`
+    pub const TOP_FRONT_RIGHT: ChannelMask = ChannelMask(0x4000);
+    /// `SPEAKER_TOP_BACK_LEFT`
+    pub const TOP_BACK_LEFT: ChannelMask = ChannelMask(0x8000);
+    /// `SPEAKER_TOP_BACK_CENTER`
+    pub const TOP_BACK_CENTER: ChannelMask = ChannelMask(0x10000);
+    /// `SPEAKER_TOP_BACK_RIGHT`
+    pub const TOP_BACK_RIGHT: ChannelMask = ChannelMask(0x20000);
+
+    /// All speaker bits in increasing order, paired with their labels.
+    const SPEAKERS: [(ChannelMask, ChannelLabel); 18] = [
+        (ChannelMask::FRONT_LEFT, ChannelLabel::FrontLeft),
+        (ChannelMask::FRONT_RIGHT, ChannelLabel::FrontRight),
+        (ChannelMask::FRONT_CENTER, ChannelLabel::Center),
+        (ChannelMask::LOW_FREQUENCY, ChannelLabel::Lfe),
+        (ChannelMask::BACK_LEFT, ChannelLabel::RearLeft),
+        (ChannelMask::BACK_RIGHT, ChannelLabel::RearRight),
+        (
+            ChannelMask::FRONT_LEFT_OF_CENTER,
+            ChannelLabel::FrontLeftCenter,
+        ),
+        (
+            ChannelMask::FRONT_RIGHT_OF_CENTER,
+            ChannelLabel::FrontRightCenter,
+        ),
+        (ChannelMask::BACK_CENTER, ChannelLabel::RearCenter),
+        (ChannelMask::SIDE_LEFT, ChannelLabel::SideLeft),
+        (ChannelMask::SIDE_RIGHT, ChannelLabel::SideRight),
+        (ChannelMask::TOP_CENTER, ChannelLabel::TopCenter),
+        (ChannelMask::TOP_FRONT_LEFT, ChannelLabel::TopFrontLeft),
+        (ChannelMask::TOP_FRONT_CENTER, ChannelLabel::TopFrontCenter),
+        (ChannelMask::TOP_FRONT_RIGHT, ChannelLabel::TopFrontRight),
+        (ChannelMask::TOP_BACK_LEFT, ChannelLabel::TopRearLeft),
+        (ChannelMask::TOP_BACK_CENTER, ChannelLabel::TopRearCenter),
+        (ChannelMask::TOP_BACK_RIGHT, ChannelLabel::TopRearRight),
+    ];
+
+    /// Returns true if all speakers of `other` are set in this mask.
+    pub fn contains(self, other: ChannelMask) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the number of speakers set in the mask.
+    pub fn count(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns the speaker bit of the given channel label, or `None` if the
+    /// label has no equivalent speaker within the mask.
+    ///
+    /// # Parameters
+    ///
+    /// * `label` - the channel label to map.
+    pub fn from_label(label: ChannelLabel) -> Option<ChannelMask> {
+        ChannelMask::SPEAKERS
+            .iter()
+            .find(|(_, l)| *l == label)
+            .map(|(m, _)| *m)
+    }
+
+    /// Returns the mask containing all of the given labels, or `None` if any of
+    /// the labels have no equivalent speaker or if a speaker is given twice.
+    ///
+    /// # Parameters
+    ///
+    /// * `labels` - the channel labels to map.
+    pub fn from_labels(labels: &[ChannelLabel]) -> Option<ChannelMask> {
+        let mut mask = ChannelMask::NONE;
+
+        for l in labels {
+            let m = ChannelMask::from_label(*l)?;
+            if mask.contains(m) {
+                return None;
+            }
+            mask |= m;
+        }
+
+        Some(mask)
+    }
+
+    /// Returns the mask of the given sample format, or `None` if any of its
+    /// channels have no equivalent speaker.
+    ///
+    /// Note that a `WAVEFORMATEXTENSIBLE` stream is always ordered by
+    /// increasing speaker bit, which isn't necessarily the order in which the
+    /// channels are stored in the sample format.
+    pub fn from_format<F: SampleFormat>() -> Option<ChannelMask> {
+        ChannelMask::from_labels(F::channel_labels())
+    }
+
+    /// Returns the labels of the speakers set in the mask, in the order the
+    /// channels appear within a `WAVEFORMATEXTENSIBLE` stream. Reserved bits
+    /// are ignored.
+    pub fn labels(self) -> Vec<ChannelLabel> {
+        ChannelMask::SPEAKERS
+            .iter()
+            .filter(|(m, _)| self.contains(*m))
+            .map(|(_, l)| *l)
+            .collect()
+    }
+
+    /// Returns true if the speakers set in the mask are exactly the channels
+    /// of the given sample format.
+    pub fn matches_format<F: SampleFormat>(self) -> bool {
+        ChannelMask::from_format::<F>() == Some(self)
+    }
+}
+
+impl std::ops::BitOr for ChannelMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: ChannelMask) -> Self::Output {
+        ChannelMask(self.0 | rhs.0)
+    }
+}
+impl std::ops::BitOrAssign for ChannelMask {
+    fn bitor_assign(&mut self, rhs: ChannelMask) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl std::ops::BitAnd for ChannelMask {
+    type Output = Self;
+
+    fn bitand(self, rhs: ChannelMask) -> Self::Output {
+        ChannelMask(self.0 & rhs.0)
+    }
+}
+impl std::ops::BitAndAssign for ChannelMask {
+    fn bitand_assign(&mut self, rhs: ChannelMask) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl From<u32> for ChannelMask {
+    fn from(m: u32) -> Self {
+        ChannelMask(m)
+    }
+}
+impl From<ChannelMask> for u32 {
+    fn from(m: ChannelMask) -> Self {
+        m.0
+    }
+}
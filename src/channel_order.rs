@@ -0,0 +1,81 @@
+//! # Channel Order
+//!
+//! Module containing the common conventions used by different ecosystems for
+//! ordering the channels of surround audio data.
+
+use super::*;
+
+/// Enum of the common conventions for ordering the channels of 5.1 and 7.1
+/// audio data.
+///
+/// | Order  | 5.1               | 7.1                       |
+/// |--------|-------------------|---------------------------|
+/// | Smpte  | L R C LFE Ls Rs   | L R C LFE Lss Rss Lrs Rrs |
+/// | Ffmpeg | L R C LFE Ls Rs   | L R C LFE Lrs Rrs Lss Rss |
+/// | Wav    | L R C LFE Ls Rs   | L R C LFE Lrs Rrs Lss Rss |
+/// | Film   | L C R Ls Rs LFE   | L C R Lss Rss Lrs Rrs LFE |
+///
+/// The channels of [`Surround51`] and [`Surround71`] are stored in SMPTE order.
+///
+/// [`Surround51`]: ../surround51/struct.Surround51.html
+/// [`Surround71`]: ../surround71/struct.Surround71.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ChannelOrder {
+    /// SMPTE/ITU order, used by most broadcast equipment.
+    Smpte,
+    /// Order used by FFmpeg.
+    Ffmpeg,
+    /// Order used by `WAVEFORMATEXTENSIBLE` streams, sorted by speaker mask.
+    Wav,
+    /// Film order, used by Pro Tools and DTS.
+    Film,
+}
+
+impl ChannelOrder {
+    /// Returns, for each position of a 5.1 frame in this order, the index of
+    /// the channel within a [`Surround51`] sample.
+    ///
+    /// [`Surround51`]: ../surround51/struct.Surround51.html
+    pub fn surround51_indices(self) -> [usize; 6] {
+        match self {
+            ChannelOrder::Smpte | ChannelOrder::Ffmpeg | ChannelOrder::Wav => [0, 1, 2, 3, 4, 5],
+            ChannelOrder::Film => [0, 2, 1, 4, 5, 3],
+        }
+    }
+
+    /// Returns, for each position of a 7.1 frame in this order, the index of
+    /// the channel within a [`Surround71`] sample.
+    ///
+    /// [`Surround71`]: ../surround71/struct.Surround71.html
+    pub fn surround71_indices(self) -> [usize; 8] {
+        match self {
+            ChannelOrder::Smpte => [0, 1, 2, 3, 4, 5, 6, 7],
+            ChannelOrder::Ffmpeg | ChannelOrder::Wav => [0, 1, 2, 3, 6, 7, 4, 5],
+            ChannelOrder::Film => [0, 2, 1, 4, 5, 6, 7, 3],
+        }
+    }
+
+    /// Returns the layout of a 5.1 frame in this order.
+    pub fn surround51(self) -> ChannelLayout {
+        let labels = Surround51::channel_labels();
+
+        ChannelLayout::new(
+            self.surround51_indices()
+                .iter()
+                .map(|i| labels[*i])
+                .collect(),
+        )
+    }
+
+    /// Returns the layout of a 7.1 frame in this order.
+    pub fn surround71(self) -> ChannelLayout {
+        let labels = Surround71::channel_labels();
+
+        ChannelLayout::new(
+            self.surround71_indices()
+                .iter()
+                .map(|i| labels[*i])
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,94 @@
+//! # Clip
+//!
+//! Module containing the clipping of samples to the range -1 to 1 of full
+//! scale, as required before converting them to integer PCM, or to any other
+//! range while counting the values clipped.
+
+use super::*;
+
+/// Enum of the ways values outside of the range -1 to 1 are brought into it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ClipMode {
+    /// Values outside of the range are set to its nearest end. Values inside
+    /// the range are unchanged, but the sudden change in slope at full scale
+    /// adds harsh harmonics to overs.
+    Hard,
+    /// Every value is passed through the hyperbolic tangent, approaching full
+    /// scale smoothly. Quiet values are nearly unchanged, while values near
+    /// full scale are gently compressed.
+    Tanh,
+    /// Every value is passed through the cubic `x - 4x³/27`, which reaches
+    /// full scale at 1.5 with a slope of 0, beyond which values are set to full
+    /// scale. Cheaper than [`Tanh`] and compresses less below full scale.
+    ///
+    /// [`Tanh`]: #variant.Tanh
+    Cubic,
+}
+
+impl ClipMode {
+    /// Returns the value clipped to the range -1 to 1.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the value to clip.
+    pub fn apply(self, s: Sample) -> Sample {
+        match self {
+            ClipMode::Hard => Sample(s.0.clamp(-1.0, 1.0)),
+            ClipMode::Tanh => Sample(s.0.tanh()),
+            ClipMode::Cubic => {
+                let x = s.0.clamp(-1.5, 1.5);
+                Sample(x - 4.0 * x * x * x / 27.0)
+            }
+        }
+    }
+}
+
+/// Struct holding the number of samples and channel values clipped by
+/// [`clamp_track`].
+///
+/// [`clamp_track`]: fn.clamp_track.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ClipReport {
+    /// The number of samples with at least one channel clipped.
+    pub samples: usize,
+    /// The number of channel values clipped over all samples.
+    pub values: usize,
+}
+
+impl ClipReport {
+    /// Returns whether no values were clipped.
+    pub fn is_clean(&self) -> bool {
+        self.values == 0
+    }
+}
+
+/// Restricts every channel of every sample of the track to the given range.
+/// Returns a report of how many samples and values were outside of the range.
+///
+/// # Parameters
+///
+/// * `track` - the samples to restrict.
+/// * `min` - the lowest allowed value of a channel.
+/// * `max` - the highest allowed value of a channel.
+///
+/// # Panics
+///
+/// Panics if `min` is greater than `max`, or either is NaN.
+pub fn clamp_track<F: SampleFormat + Copy>(
+    track: &mut Track<F>,
+    min: Sample,
+    max: Sample,
+) -> ClipReport {
+    let mut report = ClipReport::default();
+
+    for s in track.iter_mut() {
+        let (clamped, clipped) = s.clamped(min, max);
+        *s = clamped;
+        if clipped > 0 {
+            report.samples += 1;
+            report.values += clipped;
+        }
+    }
+
+    report
+}
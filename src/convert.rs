@@ -0,0 +1,140 @@
+//! # Convert
+//!
+//! Module containing conversions between samples and floating-point PCM
+//! values and raw bytes, complementing the integer conversions of
+//! `bae_utils`.
+
+use super::*;
+use bae_utils::*;
+
+/// Converts a 32-bit float PCM value to a sample. No clamping is performed, as
+/// float PCM may legitimately exceed the range -1 to 1.
+pub(crate) fn sample_from_f32(x: f32) -> Sample {
+    Sample(x as FastMath)
+}
+
+/// Converts a sample to a 32-bit float PCM value.
+#[allow(clippy::unnecessary_cast)] // FastMath is a configurable alias
+pub(crate) fn sample_to_f32(s: Sample) -> f32 {
+    s.0 as f32
+}
+
+/// Converts a 64-bit float PCM value to a sample. No clamping is performed, as
+/// float PCM may legitimately exceed the range -1 to 1.
+pub(crate) fn sample_from_f64(x: f64) -> Sample {
+    Sample(x as FastMath)
+}
+
+/// Converts a sample to a 64-bit float PCM value.
+pub(crate) fn sample_to_f64(s: Sample) -> f64 {
+    s.0 as f64
+}
+
+/// Converts a sample to its level in dBFS. Silence returns negative infinity.
+pub(crate) fn sample_to_dbfs(s: Sample) -> Math {
+    Math(20.0 * (s.0 as AccurateMath).abs().log10())
+}
+
+/// Converts a level in dBFS to a positive sample.
+pub(crate) fn sample_from_dbfs(db: Math) -> Sample {
+    Sample(db_to_linear(db).0 as FastMath)
+}
+
+/// Converts 2 bytes of 16-bit PCM in the given byte order to a sample.
+pub(crate) fn sample_from_i16_bytes(b: &[u8], endianness: Endianness) -> Sample {
+    let b = [b[0], b[1]];
+    sample_from_i16(match endianness {
+        Endianness::Little => i16::from_le_bytes(b),
+        Endianness::Big => i16::from_be_bytes(b),
+    })
+}
+
+/// Converts a sample to 2 bytes of 16-bit PCM in the given byte order.
+pub(crate) fn sample_to_i16_bytes(s: Sample, endianness: Endianness) -> [u8; 2] {
+    let x = sample_to_i16(s);
+    match endianness {
+        Endianness::Little => x.to_le_bytes(),
+        Endianness::Big => x.to_be_bytes(),
+    }
+}
+
+/// Converts 3 bytes of packed 24-bit PCM in the given byte order to a sample.
+pub(crate) fn sample_from_i24_bytes(b: &[u8], endianness: Endianness) -> Sample {
+    // Placing the value in the upper bytes and shifting back sign-extends it.
+    let x = match endianness {
+        Endianness::Little => i32::from_le_bytes([0, b[0], b[1], b[2]]),
+        Endianness::Big => i32::from_be_bytes([b[0], b[1], b[2], 0]),
+    };
+    sample_from_i24(x >> 8)
+}
+
+/// Converts a sample to 3 bytes of packed 24-bit PCM in the given byte order.
+pub(crate) fn sample_to_i24_bytes(s: Sample, endianness: Endianness) -> [u8; 3] {
+    let x = sample_to_i24(s);
+    match endianness {
+        Endianness::Little => {
+            let b = x.to_le_bytes();
+            [b[0], b[1], b[2]]
+        }
+        Endianness::Big => {
+            let b = x.to_be_bytes();
+            [b[1], b[2], b[3]]
+        }
+    }
+}
+
+/// Converts 4 bytes of 32-bit integer PCM in the given byte order to a sample.
+pub(crate) fn sample_from_i32_bytes(b: &[u8], endianness: Endianness) -> Sample {
+    let b = [b[0], b[1], b[2], b[3]];
+    let x = match endianness {
+        Endianness::Little => i32::from_le_bytes(b),
+        Endianness::Big => i32::from_be_bytes(b),
+    };
+    Sample((x as AccurateMath / 2_147_483_648.0) as FastMath)
+}
+
+/// Converts a sample to 4 bytes of 32-bit integer PCM in the given byte order.
+/// Values outside of the range -1 to 1 saturate.
+pub(crate) fn sample_to_i32_bytes(s: Sample, endianness: Endianness) -> [u8; 4] {
+    let x = (s.0 as AccurateMath * 2_147_483_648.0) as i32;
+    match endianness {
+        Endianness::Little => x.to_le_bytes(),
+        Endianness::Big => x.to_be_bytes(),
+    }
+}
+
+/// Converts 4 bytes of 32-bit float PCM in the given byte order to a sample.
+pub(crate) fn sample_from_f32_bytes(b: &[u8], endianness: Endianness) -> Sample {
+    let b = [b[0], b[1], b[2], b[3]];
+    sample_from_f32(match endianness {
+        Endianness::Little => f32::from_le_bytes(b),
+        Endianness::Big => f32::from_be_bytes(b),
+    })
+}
+
+/// Converts a sample to 4 bytes of 32-bit float PCM in the given byte order.
+pub(crate) fn sample_to_f32_bytes(s: Sample, endianness: Endianness) -> [u8; 4] {
+    let x = sample_to_f32(s);
+    match endianness {
+        Endianness::Little => x.to_le_bytes(),
+        Endianness::Big => x.to_be_bytes(),
+    }
+}
+
+/// Decodes the first frame of the given bytes, where each channel is stored in
+/// `width` bytes. Returns an error if the bytes are too short to hold a frame.
+pub(crate) fn decode_frame<'a>(
+    bytes: &'a [u8],
+    channels: usize,
+    width: usize,
+    read: impl Fn(&[u8]) -> Sample + 'a,
+) -> Result<impl Iterator<Item = Sample> + 'a, SampleFormatError> {
+    if bytes.len() < channels * width {
+        return Err(SampleFormatError::WrongLength {
+            expected: channels * width,
+            actual: bytes.len(),
+        });
+    }
+
+    Ok(bytes.chunks_exact(width).take(channels).map(read))
+}
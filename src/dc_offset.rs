@@ -0,0 +1,119 @@
+//! # DC Offset
+//!
+//! Module containing the measurement and removal of DC offset, a constant
+//! value added to each channel by the analog stages of some audio interfaces.
+
+use super::*;
+
+/// Returns the DC offset of each channel over the track, the mean of its
+/// values. An empty track returns silence.
+///
+/// # Parameters
+///
+/// * `track` - the samples to measure.
+pub fn dc_offset<F: SampleFormat>(track: &[F]) -> F {
+    let mut sums = vec![0.0 as AccurateMath; F::CHANNEL_COUNT];
+    for s in track {
+        for (sum, c) in sums.iter_mut().zip(s.as_ref()) {
+            *sum += c.0 as AccurateMath;
+        }
+    }
+
+    let mut offset = F::SILENCE;
+    if !track.is_empty() {
+        for (o, sum) in offset.as_mut().iter_mut().zip(sums.iter()) {
+            *o = Sample((sum / track.len() as AccurateMath) as FastMath);
+        }
+    }
+
+    offset
+}
+
+/// Removes the DC offset of each channel from the track by subtracting the
+/// mean of its values. Returns the offset removed.
+///
+/// This removes the offset exactly without affecting any other frequency, but
+/// requires the whole track. For streams, or offsets that drift over time, use
+/// a [`DcBlocker`].
+///
+/// # Parameters
+///
+/// * `track` - the samples to correct.
+///
+/// [`DcBlocker`]: struct.DcBlocker.html
+pub fn remove_dc_offset<F: SampleFormat + Copy>(track: &mut [F]) -> F {
+    let offset = dc_offset(track);
+
+    for s in track.iter_mut() {
+        *s = s.zip_with(offset, |x, o| Sample(x.0 - o.0));
+    }
+
+    offset
+}
+
+/// Stateful one-pole high-pass filter removing the DC offset of each channel
+/// as samples are fed to it, one at a time or in chunks.
+///
+/// `y[n] = x[n] - x[n-1] + R * y[n-1]`
+///
+/// where `R` is set by the cutoff frequency. Frequencies well above the cutoff
+/// are passed unchanged, so a cutoff of a few Hz removes the offset without
+/// audibly affecting the audio.
+#[derive(Debug, Clone)]
+pub struct DcBlocker<F> {
+    r: AccurateMath,
+    x1: F,
+    y1: F,
+}
+
+impl<F: SampleFormat + Copy> DcBlocker<F> {
+    /// Creates a new DcBlocker for the given sample rate.
+    ///
+    /// # Parameters
+    ///
+    /// * `sample_rate` - the sample rate of the audio being processed in Hz.
+    /// * `cutoff` - the cutoff frequency of the filter in Hz.
+    pub fn new(sample_rate: Math, cutoff: Math) -> Self {
+        DcBlocker {
+            r: (-2.0 * std::f64::consts::PI * cutoff.0 / sample_rate.0).exp(),
+            x1: F::SILENCE,
+            y1: F::SILENCE,
+        }
+    }
+
+    /// Processes the next sample. Returns the sample with its DC offset
+    /// removed.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next sample.
+    pub fn process(&mut self, s: F) -> F {
+        let r = self.r;
+        let y = s
+            .zip_with(self.x1, |x, x1| Sample(x.0 - x1.0))
+            .zip_with(self.y1, |d, y1| {
+                Sample((d.0 as AccurateMath + r * y1.0 as AccurateMath) as FastMath)
+            });
+
+        self.x1 = s;
+        self.y1 = y;
+        y
+    }
+
+    /// Processes every sample of the track in place, in order.
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to process.
+    pub fn process_track(&mut self, track: &mut [F]) {
+        for s in track.iter_mut() {
+            *s = self.process(*s);
+        }
+    }
+
+    /// Clears the internal state of the filter.
+    pub fn reset(&mut self) {
+        self.x1 = F::SILENCE;
+        self.y1 = F::SILENCE;
+    }
+}
@@ -0,0 +1,87 @@
+//! # Display
+//!
+//! Module containing the implementation shared by the [`Display`] impls of the
+//! sample formats.
+//!
+//! [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+
+use super::*;
+use std::fmt;
+
+/// Number of decimal places used for linear values when the formatter doesn't
+/// specify a precision.
+const DEFAULT_PRECISION: usize = 4;
+
+/// Formats the channels of a sample format as their label, linear value and
+/// level in dBFS, e.g. `L: 0.5000 (-6.02 dBFS), R: 0.0000 (-inf dBFS)`.
+pub(crate) fn fmt<F: SampleFormat>(s: &F, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt_channels(
+        f,
+        F::channel_labels()
+            .iter()
+            .copied()
+            .zip(s.as_ref().iter().copied()),
+    )
+}
+
+/// Formats the given labeled channels in the same way as [`fmt`].
+///
+/// [`fmt`]: fn.fmt.html
+pub(crate) fn fmt_channels(
+    f: &mut fmt::Formatter<'_>,
+    channels: impl Iterator<Item = (ChannelLabel, Sample)>,
+) -> fmt::Result {
+    let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
+
+    for (i, (l, s)) in channels.enumerate() {
+        if i != 0 {
+            write!(f, ", ")?;
+        }
+        write!(
+            f,
+            "{}: {:.*} ({:.2} dBFS)",
+            l,
+            precision,
+            s.0,
+            sample_to_dbfs(s).0
+        )?;
+    }
+
+    Ok(())
+}
+
+macro_rules! impl_display {
+    (@impl [$($g:tt)*] $t:ty) => {
+        /// Formats each channel as its label, linear value and level in dBFS,
+        /// e.g. `L: 0.5000 (-6.02 dBFS)`. The precision of the linear values
+        /// can be set with the formatter, e.g. `{:.2}`.
+        impl<$($g)*> fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt(self, f)
+            }
+        }
+    };
+    ($($t:ty),* $(,)?) => {
+        $(impl_display!(@impl [] $t);)*
+    };
+}
+
+impl_display!(
+    AmbisonicB,
+    Binaural,
+    DualMono,
+    Lcr,
+    MidSide,
+    Mono,
+    Quad,
+    Stereo,
+    Surround21,
+    Surround222,
+    Surround51,
+    Surround512,
+    Surround61,
+    Surround71,
+    Surround714,
+);
+impl_display!(@impl [const N: usize] MultiChannel<N>);
+impl_display!(@impl [const ORDER: usize, const N: usize] Hoa<ORDER, N>);
@@ -0,0 +1,94 @@
+//! # Dither
+//!
+//! Module containing TPDF (triangular probability density function) dither,
+//! applied when reducing samples to integer PCM so that the quantization error
+//! is decorrelated from the audio.
+
+use super::*;
+
+/// Stateful TPDF dither, adding noise of up to 1 LSB (least significant bit)
+/// in either direction to each channel and rounding it to the nearest value of
+/// the target bit depth.
+///
+/// Without dither, the quantization error of quiet material follows the audio,
+/// producing audible distortion. Dither trades this for a constant, benign
+/// noise floor. The noise is generated by a fast pseudo-random generator
+/// seeded on creation, so the same seed always produces the same output.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TpdfDither {
+    state: u64,
+}
+
+impl TpdfDither {
+    /// Creates a new TpdfDither from the given seed.
+    ///
+    /// # Parameters
+    ///
+    /// * `seed` - the seed of the pseudo-random generator.
+    pub const fn new(seed: u64) -> Self {
+        // The generator never leaves the all-zero state.
+        TpdfDither {
+            state: if seed == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                seed
+            },
+        }
+    }
+
+    /// Dithers every channel of the sample for the given bit depth. The
+    /// channels of the result lie exactly on values of the bit depth, within
+    /// the range -1 to 1, so they are converted to it without further error.
+    /// Samples are returned unchanged for 32-bit depths, whose quantization
+    /// error is far below audibility.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the sample to dither.
+    /// * `bit_depth` - the bit depth the sample will be converted to.
+    pub fn dither<F: SampleFormat>(&mut self, mut s: F, bit_depth: BitDepth) -> F {
+        let scale: AccurateMath = match bit_depth {
+            BitDepth::U8 => 128.0,
+            BitDepth::I16 => 32_768.0,
+            BitDepth::I24 => 8_388_608.0,
+            BitDepth::I32 | BitDepth::F32 => return s,
+        };
+
+        for c in s.as_mut() {
+            let noise = self.uniform() - self.uniform();
+            let q = (c.0 as AccurateMath * scale + noise).round();
+            *c = Sample((q.clamp(-scale, scale - 1.0) / scale) as FastMath);
+        }
+
+        s
+    }
+
+    /// Dithers every sample of the track in place for the given bit depth.
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to dither.
+    /// * `bit_depth` - the bit depth the track will be converted to.
+    pub fn dither_track<F: SampleFormat + Copy>(&mut self, track: &mut [F], bit_depth: BitDepth) {
+        for s in track.iter_mut() {
+            *s = self.dither(*s, bit_depth);
+        }
+    }
+
+    /// Returns a pseudo-random value uniformly distributed from 0 to 1, using
+    /// xorshift64*.
+    fn uniform(&mut self) -> AccurateMath {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        let x = self.state.wrapping_mul(0x2545_F491_4F6C_DD1D);
+
+        (x >> 11) as AccurateMath / (1u64 << 53) as AccurateMath
+    }
+}
+
+impl Default for TpdfDither {
+    fn default() -> Self {
+        TpdfDither::new(0)
+    }
+}
@@ -0,0 +1,138 @@
+//! # Downmix Builder
+//!
+//! Module containing a builder of downmixes from one sample format to another
+//! with custom gains for each source channel.
+
+use super::*;
+use std::marker::PhantomData;
+
+/// Builder of a downmix from the format `F` to the format `G`, producing a
+/// [`MixMatrix`] or a closure to apply over tracks.
+///
+/// The channels are routed as done by [`ConvertFormat`]. The gain in decibels
+/// of any source channel can be given to replace the gain it is routed with,
+/// e.g. the center and surround mix levels of a broadcast downmix. An LFE
+/// channel given a gain is routed in the same way as a center channel, as it is
+/// otherwise discarded.
+///
+/// ```rust
+/// # use bae_sf::*;
+/// # use bae_types::Math;
+/// let downmix: MixMatrix<6, 2> = DownmixBuilder::<Surround51, Stereo>::new()
+///     .gain(ChannelLabel::Center, Math(-3.0))
+///     .gain(ChannelLabel::SideLeft, Math(-6.0))
+///     .gain(ChannelLabel::SideRight, Math(-6.0))
+///     .mute(ChannelLabel::Lfe)
+///     .build();
+/// ```
+///
+/// [`MixMatrix`]: ../mix_matrix/struct.MixMatrix.html
+/// [`ConvertFormat`]: ../trait.ConvertFormat.html
+#[derive(Debug, Clone)]
+pub struct DownmixBuilder<F, G> {
+    gains: Vec<(ChannelLabel, AccurateMath)>,
+    formats: PhantomData<fn(F) -> G>,
+}
+
+impl<F: SampleFormat, G: SampleFormat> DownmixBuilder<F, G> {
+    /// Creates a new DownmixBuilder routing the channels as done by
+    /// [`ConvertFormat`].
+    ///
+    /// [`ConvertFormat`]: ../trait.ConvertFormat.html
+    pub fn new() -> Self {
+        DownmixBuilder {
+            gains: Vec::new(),
+            formats: PhantomData,
+        }
+    }
+
+    /// Sets the gain of the source channel with the given label. The channel is
+    /// mixed into each channel it is routed to at this gain, keeping the
+    /// polarity of the routing. Labels that aren't a channel of `F` are
+    /// ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `label` - the label of the source channel.
+    /// * `db` - the gain of the channel in decibels.
+    pub fn gain(mut self, label: ChannelLabel, db: Math) -> Self {
+        let gain = bae_utils::db_to_linear(db).0;
+
+        match self.gains.iter_mut().find(|(l, _)| *l == label) {
+            Some(g) => g.1 = gain,
+            None => self.gains.push((label, gain)),
+        }
+
+        self
+    }
+
+    /// Discards the source channel with the given label from the downmix.
+    ///
+    /// # Parameters
+    ///
+    /// * `label` - the label of the source channel.
+    pub fn mute(self, label: ChannelLabel) -> Self {
+        self.gain(label, Math(AccurateMath::NEG_INFINITY))
+    }
+
+    /// Builds the matrix of the downmix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F` doesn't have `IN` channels, or `G` doesn't have `OUT`
+    /// channels.
+    pub fn build<const IN: usize, const OUT: usize>(&self) -> MixMatrix<IN, OUT> {
+        let mut matrix = MixMatrix::conversion::<F, G>();
+
+        for (i, label) in F::channel_labels().iter().enumerate() {
+            for (o, g) in self.route(*label).iter().flatten() {
+                matrix.set_gain(*o, i, *g);
+            }
+        }
+
+        matrix
+    }
+
+    /// Builds a closure applying the downmix to a sample, without requiring
+    /// the channel counts of the formats to be named.
+    pub fn build_fn(&self) -> impl Fn(F) -> G {
+        let routes: Vec<Vec<(usize, AccurateMath)>> = F::channel_labels()
+            .iter()
+            .map(|label| self.route(*label).iter().flatten().copied().collect())
+            .collect();
+
+        move |s: F| {
+            let mut out = G::SILENCE;
+            for (x, route) in s.as_ref().iter().zip(routes.iter()) {
+                for (o, g) in route {
+                    out[*o].0 += (x.0 as AccurateMath * g) as FastMath;
+                }
+            }
+            out
+        }
+    }
+
+    /// Returns the channels of `G` the source channel with the given label is
+    /// mixed into, with their gains.
+    fn route(&self, label: ChannelLabel) -> routing::Route {
+        let to = G::channel_labels();
+        let mut route = routing::route(label, to);
+
+        if let Some((_, gain)) = self.gains.iter().find(|(l, _)| *l == label) {
+            if route == [None, None] && matches!(label, ChannelLabel::Lfe | ChannelLabel::Lfe2) {
+                route = routing::route(ChannelLabel::Center, to);
+            }
+            for (_, g) in route.iter_mut().flatten() {
+                *g = gain * g.signum();
+            }
+        }
+
+        route
+    }
+}
+
+impl<F: SampleFormat, G: SampleFormat> Default for DownmixBuilder<F, G> {
+    fn default() -> Self {
+        DownmixBuilder::new()
+    }
+}
@@ -0,0 +1,513 @@
+//! # Dual Mono
+//!
+//! Module containing type for handling dual mono audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`DualMono`] samples
+///
+/// [`DualMono`]: struct.DualMono.html
+pub type DualMonoTrackT = Vec<DualMono>;
+
+/// Enum selecting one of the two channels of a [`DualMono`] sample.
+///
+/// [`DualMono`]: struct.DualMono.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DualMonoChannel {
+    /// The first channel.
+    First,
+    /// The second channel.
+    Second,
+}
+
+/// Struct representing a dual mono audio sample, carrying two independent
+/// monophonic signals such as two languages of the same program. Unlike
+/// [`Stereo`], the channels are not downmixed by summing them, instead one of
+/// the two is selected. Converting to a monophonic sample selects the first
+/// channel, and converting from one copies it to both channels unchanged.
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct DualMono {
+    /// First independent monophonic sample value.
+    pub first: Sample,
+    /// Second independent monophonic sample value.
+    pub second: Sample,
+}
+
+impl DualMono {
+    /// Returns a new DualMono object with default <0,0> values.
+    pub const fn new() -> Self {
+        DualMono::SILENCE
+    }
+
+    /// Returns a new DualMono object created from two independent monophonic
+    /// audio samples.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - the first monophonic audio sample.
+    /// * `b` - the second monophonic audio sample.
+    pub const fn from(a: Sample, b: Sample) -> Self {
+        DualMono {
+            first: a,
+            second: b,
+        }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 2] {
+        let s: [Sample; 2] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new DualMono object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 2]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Downmixes the sample to a monophonic sample by selecting one of the two
+    /// channels. The channels are never summed, as they carry unrelated
+    /// signals.
+    ///
+    /// # Parameters
+    ///
+    /// * `channel` - the channel to select.
+    pub fn select(self, channel: DualMonoChannel) -> Sample {
+        match channel {
+            DualMonoChannel::First => self.first,
+            DualMonoChannel::Second => self.second,
+        }
+    }
+}
+
+impl SampleFormat for DualMono {
+    const CHANNEL_COUNT: usize = 2;
+
+    const SILENCE: Self = DualMono {
+        first: Sample(0.0),
+        second: Sample(0.0),
+    };
+    const UNITY: Self = DualMono {
+        first: Sample(1.0),
+        second: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        DualMono {
+            first: x,
+            second: x,
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        self.first
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[ChannelLabel::Discrete(0), ChannelLabel::Discrete(1)]
+    }
+}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for DualMono {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.first,
+            1 => &self.second,
+            _ => panic!(
+                "ERROR: Index {} is out of range for DualMono with 2 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for DualMono {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.first,
+            1 => &mut self.second,
+            _ => panic!(
+                "ERROR: Index {} is out of range for DualMono with 2 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for DualMono {
+    fn as_ref(&self) -> &[Sample] {
+        // DualMono is #[repr(C)] and made up solely of 2 Sample fields.
+        unsafe { channel::as_slice(self, 2) }
+    }
+}
+impl AsMut<[Sample]> for DualMono {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // DualMono is #[repr(C)] and made up solely of 2 Sample fields.
+        unsafe { channel::as_mut_slice(self, 2) }
+    }
+}
+
+impl std::ops::Neg for DualMono {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        DualMono {
+            first: Sample(-self.first.0),
+            second: Sample(-self.second.0),
+        }
+    }
+}
+
+impl std::ops::Add<DualMono> for DualMono {
+    type Output = Self;
+
+    fn add(self, rhs: DualMono) -> Self::Output {
+        DualMono {
+            first: Sample(self.first.0 + rhs.first.0),
+            second: Sample(self.second.0 + rhs.second.0),
+        }
+    }
+}
+impl std::ops::AddAssign<DualMono> for DualMono {
+    fn add_assign(&mut self, rhs: DualMono) {
+        self.first.0 += rhs.first.0;
+        self.second.0 += rhs.second.0;
+    }
+}
+
+impl std::ops::Sub<DualMono> for DualMono {
+    type Output = Self;
+
+    fn sub(self, rhs: DualMono) -> Self {
+        DualMono {
+            first: Sample(self.first.0 - rhs.first.0),
+            second: Sample(self.second.0 - rhs.second.0),
+        }
+    }
+}
+impl std::ops::SubAssign<DualMono> for DualMono {
+    fn sub_assign(&mut self, rhs: DualMono) {
+        self.first.0 -= rhs.first.0;
+        self.second.0 -= rhs.second.0;
+    }
+}
+
+impl std::ops::Mul<DualMono> for DualMono {
+    type Output = DualMono;
+
+    fn mul(self, rhs: DualMono) -> Self::Output {
+        DualMono {
+            first: Sample(self.first.0 * rhs.first.0),
+            second: Sample(self.second.0 * rhs.second.0),
+        }
+    }
+}
+impl std::ops::MulAssign<DualMono> for DualMono {
+    fn mul_assign(&mut self, rhs: DualMono) {
+        self.first.0 *= rhs.first.0;
+        self.second.0 *= rhs.second.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for DualMono {
+    type Output = DualMono;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        DualMono {
+            first: Sample(self.first.0 * rhs.0),
+            second: Sample(self.second.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for DualMono {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.first.0 *= rhs.0;
+        self.second.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for DualMono {
+    type Output = DualMono;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        DualMono {
+            first: Sample((self.first.0 as AccurateMath * rhs.0) as FastMath),
+            second: Sample((self.second.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for DualMono {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.first.0 *= rhs.0 as FastMath;
+        self.second.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for DualMono {
+    type Output = DualMono;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for DualMono {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for DualMono {
+    type Output = DualMono;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for DualMono {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 2]> for DualMono {
+    type Output = DualMono;
+
+    fn mul(mut self, rhs: [Sample; 2]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 2]> for DualMono {
+    fn mul_assign(&mut self, rhs: [Sample; 2]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for DualMono {
+    fn sum<I: Iterator<Item = DualMono>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a DualMono> for DualMono {
+    fn sum<I: Iterator<Item = &'a DualMono>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for DualMono {
+    fn product<I: Iterator<Item = DualMono>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a DualMono> for DualMono {
+    fn product<I: Iterator<Item = &'a DualMono>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for DualMono {
+    fn from(s: Sample) -> Self {
+        DualMono::from_sample(s)
+    }
+}
+impl Into<Sample> for DualMono {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 2]> for DualMono {
+    fn from(a: [Sample; 2]) -> Self {
+        DualMono {
+            first: a[0],
+            second: a[1],
+        }
+    }
+}
+impl Into<[Sample; 2]> for DualMono {
+    fn into(self) -> [Sample; 2] {
+        [self.first, self.second]
+    }
+}
+
+impl From<(Sample, Sample)> for DualMono {
+    fn from(t: (Sample, Sample)) -> Self {
+        DualMono {
+            first: t.0,
+            second: t.1,
+        }
+    }
+}
+impl Into<(Sample, Sample)> for DualMono {
+    fn into(self) -> (Sample, Sample) {
+        (self.first, self.second)
+    }
+}
+
+impl TryFrom<&[u8]> for DualMono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(DualMono {
+                first: sample_from_u8(v[0]),
+                second: sample_from_u8(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for DualMono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for DualMono {
+    fn into(self) -> Vec<u8> {
+        vec![sample_to_u8(self.first), sample_to_u8(self.second)]
+    }
+}
+
+impl TryFrom<&[i16]> for DualMono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(DualMono {
+                first: sample_from_i16(v[0]),
+                second: sample_from_i16(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for DualMono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for DualMono {
+    fn into(self) -> Vec<i16> {
+        vec![sample_to_i16(self.first), sample_to_i16(self.second)]
+    }
+}
+
+impl TryFrom<&[i32]> for DualMono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(DualMono {
+                first: sample_from_i24(v[0]),
+                second: sample_from_i24(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for DualMono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for DualMono {
+    fn into(self) -> Vec<i32> {
+        vec![sample_to_i24(self.first), sample_to_i24(self.second)]
+    }
+}
+
+impl TryFrom<&[f32]> for DualMono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(DualMono {
+                first: sample_from_f32(v[0]),
+                second: sample_from_f32(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for DualMono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for DualMono {
+    fn into(self) -> Vec<f32> {
+        vec![sample_to_f32(self.first), sample_to_f32(self.second)]
+    }
+}
+
+impl TryFrom<&[f64]> for DualMono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(DualMono {
+                first: sample_from_f64(v[0]),
+                second: sample_from_f64(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for DualMono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for DualMono {
+    fn into(self) -> Vec<f64> {
+        vec![sample_to_f64(self.first), sample_to_f64(self.second)]
+    }
+}
@@ -0,0 +1,809 @@
+//! # Dynamic Format
+//!
+//! Module containing type for handling audio data with a number of channels
+//! only known at runtime, such as when it is read from a file header.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`DynFormat`] samples
+///
+/// [`DynFormat`]: struct.DynFormat.html
+pub type DynFormatTrackT = Vec<DynFormat>;
+
+/// Struct representing an audio sample with a number of channels decided at
+/// runtime.
+///
+/// As the channel count is not known at compile time, this type cannot
+/// implement [`SampleFormat`], but it provides the same arithmetic and
+/// conversion operations. Arithmetic between two samples requires both to have
+/// the same number of channels. The operators panic when this is not the case,
+/// while the `try_*` methods return an error instead.
+///
+/// [`SampleFormat`]: ../trait.SampleFormat.html
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DynFormat {
+    /// The channels of the sample.
+    pub channels: Vec<Sample>,
+}
+
+impl DynFormat {
+    /// Returns a new DynFormat object with the given number of channels, all
+    /// set to 0.
+    ///
+    /// # Parameters
+    ///
+    /// * `channels` - the number of channels of the sample.
+    pub fn new(channels: usize) -> Self {
+        DynFormat {
+            channels: vec![Sample(0.0); channels],
+        }
+    }
+
+    /// Returns a new DynFormat object created from the given channels.
+    ///
+    /// # Parameters
+    ///
+    /// * `channels` - the channels of the sample.
+    pub fn from(channels: Vec<Sample>) -> Self {
+        DynFormat { channels }
+    }
+
+    /// Creates an object with the given number of channels from a single
+    /// monophonic sample. The sample is spread evenly across all channels with
+    /// constant power.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - the monophonic sample.
+    /// * `channels` - the number of channels of the sample.
+    pub fn from_sample(x: Sample, channels: usize) -> Self {
+        let g = 1.0 / (channels as FastMath).sqrt();
+
+        DynFormat {
+            channels: vec![Sample(x.0 * g); channels],
+        }
+    }
+
+    /// Creates an object with the given number of channels, all set to the
+    /// given value. Unlike [`from_sample`], no gain is applied.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - the value of every channel.
+    /// * `channels` - the number of channels of the sample.
+    ///
+    /// [`from_sample`]: #method.from_sample
+    pub fn splat(x: Sample, channels: usize) -> Self {
+        DynFormat {
+            channels: vec![x; channels],
+        }
+    }
+
+    /// Converts the sample to a monophonic sample by summing all channels with
+    /// the inverse gain of [`from_sample`]. A sample with no channels is
+    /// silent.
+    ///
+    /// [`from_sample`]: #method.from_sample
+    pub fn into_sample(self) -> Sample {
+        if self.channels.is_empty() {
+            return Sample(0.0);
+        }
+
+        let g = 1.0 / (self.channels.len() as FastMath).sqrt();
+
+        Sample(self.channels.iter().map(|c| c.0).sum::<FastMath>() * g)
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(&self) -> Vec<Math> {
+        self.channels.iter().map(|c| sample_to_dbfs(*c)).collect()
+    }
+
+    /// Creates an object with one channel per given level, each set to the
+    /// positive linear value of the level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: Vec<Math>) -> Self {
+        DynFormat {
+            channels: db.into_iter().map(sample_from_dbfs).collect(),
+        }
+    }
+
+    /// Creates an object with the given number of channels from 16-bit PCM bytes
+    /// in the given byte order. Any data following the first frame is ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `bytes` - the raw data, at least 2 bytes per channel long.
+    /// * `channels` - the number of channels of the sample.
+    /// * `endianness` - the byte order of the data.
+    pub fn from_i16_bytes(
+        bytes: &[u8],
+        channels: usize,
+        endianness: Endianness,
+    ) -> Result<Self, SampleFormatError> {
+        let read = |b: &[u8]| sample_from_i16_bytes(b, endianness);
+        Ok(DynFormat {
+            channels: decode_frame(bytes, channels, 2, read)?.collect(),
+        })
+    }
+
+    /// Converts the sample to 16-bit PCM bytes in the given byte order.
+    ///
+    /// # Parameters
+    ///
+    /// * `endianness` - the byte order of the data.
+    pub fn into_i16_bytes(self, endianness: Endianness) -> Vec<u8> {
+        self.channels
+            .into_iter()
+            .flat_map(|c| sample_to_i16_bytes(c, endianness))
+            .collect()
+    }
+
+    /// Creates an object with the given number of channels from packed 24-bit PCM bytes
+    /// in the given byte order. Any data following the first frame is ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `bytes` - the raw data, at least 3 bytes per channel long.
+    /// * `channels` - the number of channels of the sample.
+    /// * `endianness` - the byte order of the data.
+    pub fn from_packed_i24(
+        bytes: &[u8],
+        channels: usize,
+        endianness: Endianness,
+    ) -> Result<Self, SampleFormatError> {
+        let read = |b: &[u8]| sample_from_i24_bytes(b, endianness);
+        Ok(DynFormat {
+            channels: decode_frame(bytes, channels, 3, read)?.collect(),
+        })
+    }
+
+    /// Converts the sample to packed 24-bit PCM bytes in the given byte order.
+    ///
+    /// # Parameters
+    ///
+    /// * `endianness` - the byte order of the data.
+    pub fn into_packed_i24(self, endianness: Endianness) -> Vec<u8> {
+        self.channels
+            .into_iter()
+            .flat_map(|c| sample_to_i24_bytes(c, endianness))
+            .collect()
+    }
+
+    /// Creates an object with the given number of channels from 32-bit float PCM bytes
+    /// in the given byte order. Any data following the first frame is ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `bytes` - the raw data, at least 4 bytes per channel long.
+    /// * `channels` - the number of channels of the sample.
+    /// * `endianness` - the byte order of the data.
+    pub fn from_f32_bytes(
+        bytes: &[u8],
+        channels: usize,
+        endianness: Endianness,
+    ) -> Result<Self, SampleFormatError> {
+        let read = |b: &[u8]| sample_from_f32_bytes(b, endianness);
+        Ok(DynFormat {
+            channels: decode_frame(bytes, channels, 4, read)?.collect(),
+        })
+    }
+
+    /// Converts the sample to 32-bit float PCM bytes in the given byte order.
+    ///
+    /// # Parameters
+    ///
+    /// * `endianness` - the byte order of the data.
+    pub fn into_f32_bytes(self, endianness: Endianness) -> Vec<u8> {
+        self.channels
+            .into_iter()
+            .flat_map(|c| sample_to_f32_bytes(c, endianness))
+            .collect()
+    }
+
+    /// Rearranges the channels of the sample from one layout to another.
+    ///
+    /// # Parameters
+    ///
+    /// * `from` - the layout the channels are currently in.
+    /// * `to` - the layout to rearrange the channels into.
+    pub fn reorder(
+        mut self,
+        from: &ChannelLayout,
+        to: &ChannelLayout,
+    ) -> Result<Self, SampleFormatError> {
+        reorder(from, to, &mut self.channels)?;
+        Ok(self)
+    }
+
+    /// Returns the number of channels of the sample.
+    pub fn num_samples(&self) -> usize {
+        self.channels.len()
+    }
+
+    /// Returns true if both samples have the same number of channels and every
+    /// channel is within `epsilon` of the same channel of `other`.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the sample to compare against.
+    /// * `epsilon` - the largest allowed absolute difference of a channel.
+    pub fn approx_eq(&self, other: &DynFormat, epsilon: FastMath) -> bool {
+        self.channels.len() == other.channels.len()
+            && self
+                .channels
+                .iter()
+                .zip(other.channels.iter())
+                .all(|(a, b)| (a.0 - b.0).abs() <= epsilon)
+    }
+
+    /// Applies the given function to every channel of the sample, such as for
+    /// waveshaping or clipping.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - the function applied to each channel.
+    pub fn map(mut self, f: impl Fn(Sample) -> Sample) -> Self {
+        for c in self.channels.iter_mut() {
+            *c = f(*c);
+        }
+        self
+    }
+
+    /// Adds the given sample to this one, returning an error if the channel
+    /// counts differ.
+    pub fn try_add(self, rhs: DynFormat) -> Result<Self, SampleFormatError> {
+        self.try_zip_with(rhs, |a, b| Sample(a.0 + b.0))
+    }
+
+    /// Subtracts the given sample from this one, returning an error if the
+    /// channel counts differ.
+    pub fn try_sub(self, rhs: DynFormat) -> Result<Self, SampleFormatError> {
+        self.try_zip_with(rhs, |a, b| Sample(a.0 - b.0))
+    }
+
+    /// Multiplies this sample with the given one channel by channel, returning
+    /// an error if the channel counts differ.
+    pub fn try_mul(self, rhs: DynFormat) -> Result<Self, SampleFormatError> {
+        self.try_zip_with(rhs, |a, b| Sample(a.0 * b.0))
+    }
+
+    /// Returns the sample with the absolute value of every channel.
+    pub fn abs(self) -> Self {
+        self.map(|s| Sample(s.0.abs()))
+    }
+
+    /// Restricts every channel of the sample to the given range.
+    ///
+    /// # Parameters
+    ///
+    /// * `min` - the lowest allowed value of a channel.
+    /// * `max` - the highest allowed value of a channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`, or either is NaN.
+    pub fn clamp(self, min: Sample, max: Sample) -> Self {
+        self.map(|s| Sample(s.0.clamp(min.0, max.0)))
+    }
+
+    /// Returns the lowest value of all channels of the sample, or positive
+    /// infinity if the sample has no channels.
+    pub fn channel_min(&self) -> Sample {
+        Sample(
+            self.channels
+                .iter()
+                .fold(FastMath::INFINITY, |m, s| m.min(s.0)),
+        )
+    }
+
+    /// Returns the highest value of all channels of the sample, or negative
+    /// infinity if the sample has no channels.
+    pub fn channel_max(&self) -> Sample {
+        Sample(
+            self.channels
+                .iter()
+                .fold(FastMath::NEG_INFINITY, |m, s| m.max(s.0)),
+        )
+    }
+
+    /// Returns true if every channel of the sample is exactly 0.
+    pub fn is_silent(&self) -> bool {
+        self.is_silent_within(Sample(0.0))
+    }
+
+    /// Returns true if the magnitude of every channel of the sample is at most
+    /// the given threshold.
+    ///
+    /// # Parameters
+    ///
+    /// * `threshold` - the largest magnitude considered silent.
+    pub fn is_silent_within(&self, threshold: Sample) -> bool {
+        self.channels.iter().all(|s| s.0.abs() <= threshold.0)
+    }
+
+    /// Returns true if no channel of the sample is NaN or infinite.
+    pub fn is_finite(&self) -> bool {
+        self.channels.iter().all(|s| s.0.is_finite())
+    }
+
+    /// Combines each channel of this sample with the same channel of the given
+    /// one using the given function, such as for crossfading or ring
+    /// modulation.
+    ///
+    /// # Parameters
+    ///
+    /// * `rhs` - the sample to combine with this one.
+    /// * `f` - the function combining a channel of each sample.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the channel counts of the two samples differ.
+    pub fn zip_with(self, rhs: DynFormat, f: impl Fn(Sample, Sample) -> Sample) -> Self {
+        self.try_zip_with(rhs, f).unwrap()
+    }
+
+    /// Linearly interpolates between the sample and `rhs`.
+    ///
+    /// # Parameters
+    ///
+    /// * `rhs` - the sample to interpolate towards.
+    /// * `t` - the position between the samples, where 0 returns this sample
+    /// and 1 returns `rhs`. Values outside of this range extrapolate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the channel counts of the two samples differ.
+    pub fn lerp(self, rhs: DynFormat, t: Math) -> Self {
+        let t = t.0;
+        self.zip_with(rhs, |a, b| {
+            Sample((a.0 as AccurateMath + (b.0 - a.0) as AccurateMath * t) as FastMath)
+        })
+    }
+
+    /// Combines each channel of this sample with the same channel of the given
+    /// one using the given function, returning an error if the channel counts
+    /// differ.
+    ///
+    /// # Parameters
+    ///
+    /// * `rhs` - the sample to combine with this one.
+    /// * `f` - the function combining a channel of each sample.
+    pub fn try_zip_with(
+        mut self,
+        rhs: DynFormat,
+        f: impl Fn(Sample, Sample) -> Sample,
+    ) -> Result<Self, SampleFormatError> {
+        if self.channels.len() != rhs.channels.len() {
+            return Err(SampleFormatError::ChannelCountMismatch {
+                left: self.channels.len(),
+                right: rhs.channels.len(),
+            });
+        }
+
+        for (c, r) in self.channels.iter_mut().zip(rhs.channels) {
+            *c = f(*c, r);
+        }
+
+        Ok(self)
+    }
+}
+
+/// Formats each channel as its index, linear value and level in dBFS, e.g.
+/// `0: 0.5000 (-6.02 dBFS)`. The precision of the linear values can be set
+/// with the formatter, e.g. `{:.2}`.
+impl std::fmt::Display for DynFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        display::fmt_channels(
+            f,
+            self.channels
+                .iter()
+                .enumerate()
+                .map(|(i, s)| (ChannelLabel::Discrete(i as u16), *s)),
+        )
+    }
+}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for DynFormat {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.channels[i]
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for DynFormat {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.channels[i]
+    }
+}
+
+impl AsRef<[Sample]> for DynFormat {
+    fn as_ref(&self) -> &[Sample] {
+        &self.channels
+    }
+}
+impl AsMut<[Sample]> for DynFormat {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        &mut self.channels
+    }
+}
+
+impl std::ops::Neg for DynFormat {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        for c in self.channels.iter_mut() {
+            c.0 = -c.0;
+        }
+        self
+    }
+}
+
+/// # Panics
+///
+/// Panics if the channel counts of the two samples differ.
+impl std::ops::Add<DynFormat> for DynFormat {
+    type Output = Self;
+
+    fn add(self, rhs: DynFormat) -> Self::Output {
+        self.try_add(rhs).unwrap()
+    }
+}
+/// # Panics
+///
+/// Panics if the channel counts of the two samples differ.
+impl std::ops::AddAssign<DynFormat> for DynFormat {
+    fn add_assign(&mut self, rhs: DynFormat) {
+        *self = std::mem::take(self).try_add(rhs).unwrap();
+    }
+}
+
+/// # Panics
+///
+/// Panics if the channel counts of the two samples differ.
+impl std::ops::Sub<DynFormat> for DynFormat {
+    type Output = Self;
+
+    fn sub(self, rhs: DynFormat) -> Self {
+        self.try_sub(rhs).unwrap()
+    }
+}
+/// # Panics
+///
+/// Panics if the channel counts of the two samples differ.
+impl std::ops::SubAssign<DynFormat> for DynFormat {
+    fn sub_assign(&mut self, rhs: DynFormat) {
+        *self = std::mem::take(self).try_sub(rhs).unwrap();
+    }
+}
+
+/// # Panics
+///
+/// Panics if the channel counts of the two samples differ.
+impl std::ops::Mul<DynFormat> for DynFormat {
+    type Output = DynFormat;
+
+    fn mul(self, rhs: DynFormat) -> Self::Output {
+        self.try_mul(rhs).unwrap()
+    }
+}
+/// # Panics
+///
+/// Panics if the channel counts of the two samples differ.
+impl std::ops::MulAssign<DynFormat> for DynFormat {
+    fn mul_assign(&mut self, rhs: DynFormat) {
+        *self = std::mem::take(self).try_mul(rhs).unwrap();
+    }
+}
+
+impl std::ops::Mul<Sample> for DynFormat {
+    type Output = DynFormat;
+
+    fn mul(mut self, rhs: Sample) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<Sample> for DynFormat {
+    fn mul_assign(&mut self, rhs: Sample) {
+        for c in self.channels.iter_mut() {
+            c.0 *= rhs.0;
+        }
+    }
+}
+
+impl std::ops::Mul<Math> for DynFormat {
+    type Output = DynFormat;
+
+    fn mul(mut self, rhs: Math) -> Self::Output {
+        for c in self.channels.iter_mut() {
+            c.0 = (c.0 as AccurateMath * rhs.0) as FastMath;
+        }
+        self
+    }
+}
+impl std::ops::MulAssign<Math> for DynFormat {
+    fn mul_assign(&mut self, rhs: Math) {
+        for c in self.channels.iter_mut() {
+            c.0 *= rhs.0 as FastMath;
+        }
+    }
+}
+
+impl std::ops::Mul<f32> for DynFormat {
+    type Output = DynFormat;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for DynFormat {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for DynFormat {
+    type Output = DynFormat;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for DynFormat {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+/// # Panics
+///
+/// Panics if the length of the array differs from the channel count.
+impl<const N: usize> std::ops::Mul<[Sample; N]> for DynFormat {
+    type Output = DynFormat;
+
+    fn mul(mut self, rhs: [Sample; N]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+/// # Panics
+///
+/// Panics if the length of the array differs from the channel count.
+impl<const N: usize> std::ops::MulAssign<[Sample; N]> for DynFormat {
+    fn mul_assign(&mut self, rhs: [Sample; N]) {
+        assert_eq!(
+            self.channels.len(),
+            N,
+            "ERROR: Mismatched channel counts {} and {}.",
+            self.channels.len(),
+            N
+        );
+        for (c, g) in self.channels.iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+/// The sum of an empty iterator is a sample with no channels.
+///
+/// # Panics
+///
+/// Panics if the channel counts of the samples differ.
+impl std::iter::Sum for DynFormat {
+    fn sum<I: Iterator<Item = DynFormat>>(iter: I) -> Self {
+        iter.reduce(|a, b| a + b).unwrap_or_default()
+    }
+}
+/// The sum of an empty iterator is a sample with no channels.
+///
+/// # Panics
+///
+/// Panics if the channel counts of the samples differ.
+impl<'a> std::iter::Sum<&'a DynFormat> for DynFormat {
+    fn sum<I: Iterator<Item = &'a DynFormat>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+/// The product of an empty iterator is a sample with no channels.
+///
+/// # Panics
+///
+/// Panics if the channel counts of the samples differ.
+impl std::iter::Product for DynFormat {
+    fn product<I: Iterator<Item = DynFormat>>(iter: I) -> Self {
+        iter.reduce(|a, b| a * b).unwrap_or_default()
+    }
+}
+/// The product of an empty iterator is a sample with no channels.
+///
+/// # Panics
+///
+/// Panics if the channel counts of the samples differ.
+impl<'a> std::iter::Product<&'a DynFormat> for DynFormat {
+    fn product<I: Iterator<Item = &'a DynFormat>>(iter: I) -> Self {
+        iter.cloned().product()
+    }
+}
+
+impl Into<Sample> for DynFormat {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+/// The channel count of the created sample is the length of the given array.
+impl<const N: usize> From<[Sample; N]> for DynFormat {
+    fn from(a: [Sample; N]) -> Self {
+        DynFormat {
+            channels: a.to_vec(),
+        }
+    }
+}
+
+/// The channel count of the created sample is the length of the given slice.
+impl TryFrom<&[u8]> for DynFormat {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(SampleFormatError::WrongLength {
+                expected: 1,
+                actual: 0,
+            })
+        } else {
+            Ok(DynFormat {
+                channels: v.iter().copied().map(sample_from_u8).collect(),
+            })
+        }
+    }
+}
+/// The channel count of the created sample is the length of the given vector.
+impl TryFrom<Vec<u8>> for DynFormat {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for DynFormat {
+    fn into(self) -> Vec<u8> {
+        self.channels.into_iter().map(sample_to_u8).collect()
+    }
+}
+
+/// The channel count of the created sample is the length of the given slice.
+impl TryFrom<&[i16]> for DynFormat {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(SampleFormatError::WrongLength {
+                expected: 1,
+                actual: 0,
+            })
+        } else {
+            Ok(DynFormat {
+                channels: v.iter().copied().map(sample_from_i16).collect(),
+            })
+        }
+    }
+}
+/// The channel count of the created sample is the length of the given vector.
+impl TryFrom<Vec<i16>> for DynFormat {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for DynFormat {
+    fn into(self) -> Vec<i16> {
+        self.channels.into_iter().map(sample_to_i16).collect()
+    }
+}
+
+/// The channel count of the created sample is the length of the given slice.
+impl TryFrom<&[i32]> for DynFormat {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(SampleFormatError::WrongLength {
+                expected: 1,
+                actual: 0,
+            })
+        } else {
+            Ok(DynFormat {
+                channels: v.iter().copied().map(sample_from_i24).collect(),
+            })
+        }
+    }
+}
+/// The channel count of the created sample is the length of the given vector.
+impl TryFrom<Vec<i32>> for DynFormat {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for DynFormat {
+    fn into(self) -> Vec<i32> {
+        self.channels.into_iter().map(sample_to_i24).collect()
+    }
+}
+
+/// The channel count of the created sample is the length of the given slice.
+impl TryFrom<&[f32]> for DynFormat {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(SampleFormatError::WrongLength {
+                expected: 1,
+                actual: 0,
+            })
+        } else {
+            Ok(DynFormat {
+                channels: v.iter().copied().map(sample_from_f32).collect(),
+            })
+        }
+    }
+}
+/// The channel count of the created sample is the length of the given vector.
+impl TryFrom<Vec<f32>> for DynFormat {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for DynFormat {
+    fn into(self) -> Vec<f32> {
+        self.channels.into_iter().map(sample_to_f32).collect()
+    }
+}
+
+/// The channel count of the created sample is the length of the given slice.
+impl TryFrom<&[f64]> for DynFormat {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(SampleFormatError::WrongLength {
+                expected: 1,
+                actual: 0,
+            })
+        } else {
+            Ok(DynFormat {
+                channels: v.iter().copied().map(sample_from_f64).collect(),
+            })
+        }
+    }
+}
+/// The channel count of the created sample is the length of the given vector.
+impl TryFrom<Vec<f64>> for DynFormat {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for DynFormat {
+    fn into(self) -> Vec<f64> {
+        self.channels.into_iter().map(sample_to_f64).collect()
+    }
+}
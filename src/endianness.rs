@@ -0,0 +1,23 @@
+//! # Endianness
+//!
+//! Module containing the byte order used when converting samples to and from
+//! raw bytes.
+
+/// Enum of the byte orders in which multi-byte sample values can be stored.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Least significant byte first, as used by WAV files.
+    #[default]
+    Little,
+    /// Most significant byte first, as used by AIFF files and network streams.
+    Big,
+}
+
+impl Endianness {
+    /// The byte order of the target platform.
+    pub const NATIVE: Endianness = if cfg!(target_endian = "big") {
+        Endianness::Big
+    } else {
+        Endianness::Little
+    };
+}
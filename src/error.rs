@@ -0,0 +1,61 @@
+//! # Error
+//!
+//! Module containing the error type returned by the fallible conversions and
+//! operations of the sample formats.
+
+use super::*;
+
+/// Enum of the ways in which converting or combining sample formats can fail.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SampleFormatError {
+    /// The given data doesn't contain the number of values required.
+    WrongLength {
+        /// The number of values required.
+        expected: usize,
+        /// The number of values given.
+        actual: usize,
+    },
+    /// The given data isn't a whole number of frames.
+    UnalignedLength {
+        /// The number of channels in a frame.
+        channels: usize,
+        /// The number of values given.
+        actual: usize,
+    },
+    /// Two samples or layouts that must have the same number of channels
+    /// differ.
+    ChannelCountMismatch {
+        /// The number of channels of the left-hand side.
+        left: usize,
+        /// The number of channels of the right-hand side.
+        right: usize,
+    },
+    /// A channel required by the destination layout is missing from the source
+    /// layout.
+    MissingChannel(ChannelLabel),
+}
+
+impl std::fmt::Display for SampleFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SampleFormatError::WrongLength { expected, actual } => write!(
+                f,
+                "Given data was length {}. This function requires length {}.",
+                actual, expected
+            ),
+            SampleFormatError::UnalignedLength { channels, actual } => write!(
+                f,
+                "Given data was length {}. This function requires a multiple of {}.",
+                actual, channels
+            ),
+            SampleFormatError::ChannelCountMismatch { left, right } => {
+                write!(f, "Mismatched channel counts {} and {}.", left, right)
+            }
+            SampleFormatError::MissingChannel(label) => {
+                write!(f, "Channel {:?} is missing from the source layout.", label)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SampleFormatError {}
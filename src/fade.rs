@@ -0,0 +1,102 @@
+//! # Fade
+//!
+//! Module containing fades and crossfades over tracks, as used when editing
+//! clips of audio.
+
+use super::*;
+
+/// Enum of the shapes of the gain curve of a fade.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FadeCurve {
+    /// The gain changes linearly. Crossfades dip in power by 3 dB at their
+    /// middle with uncorrelated material, but keep a constant level with
+    /// identical material.
+    Linear,
+    /// The gain follows a quarter sine wave, so that crossfades keep a constant
+    /// power with uncorrelated material.
+    EqualPower,
+    /// The gain follows half a cosine wave, starting and ending gently.
+    SCurve,
+}
+
+impl FadeCurve {
+    /// Returns the gain of a fade in at the given position.
+    ///
+    /// # Parameters
+    ///
+    /// * `t` - the position in the fade, where 0 is the start and 1 is the end.
+    /// Values outside of this range are clamped to it.
+    pub fn gain(self, t: AccurateMath) -> AccurateMath {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            FadeCurve::Linear => t,
+            FadeCurve::EqualPower => (t * FRAC_PI_2).sin(),
+            FadeCurve::SCurve => 0.5 - 0.5 * (t * PI).cos(),
+        }
+    }
+}
+
+/// Fades in the start of the track from silence. The first sample is silenced,
+/// and samples after the fade are unchanged.
+///
+/// # Parameters
+///
+/// * `track` - the track to fade.
+/// * `len` - the length of the fade in samples. Lengths greater than the track
+/// are shortened to it.
+/// * `curve` - the shape of the fade.
+pub fn fade_in<F: SampleFormat>(track: &mut [F], len: usize, curve: FadeCurve) {
+    let len = len.min(track.len());
+    for (n, s) in track.iter_mut().take(len).enumerate() {
+        *s *= curve.gain(n as AccurateMath / len as AccurateMath);
+    }
+}
+
+/// Fades out the end of the track to silence. The last sample is silenced, and
+/// samples before the fade are unchanged.
+///
+/// # Parameters
+///
+/// * `track` - the track to fade.
+/// * `len` - the length of the fade in samples. Lengths greater than the track
+/// are shortened to it.
+/// * `curve` - the shape of the fade.
+pub fn fade_out<F: SampleFormat>(track: &mut [F], len: usize, curve: FadeCurve) {
+    let len = len.min(track.len());
+    for (n, s) in track.iter_mut().rev().take(len).enumerate() {
+        *s *= curve.gain(n as AccurateMath / len as AccurateMath);
+    }
+}
+
+/// Joins the tracks, fading out the end of `a` while fading in the start of
+/// `b` over the same samples. The result is `len` samples shorter than the two
+/// tracks together.
+///
+/// # Parameters
+///
+/// * `a` - the track faded out.
+/// * `b` - the track faded in.
+/// * `len` - the length of the crossfade in samples. Lengths greater than
+/// either track are shortened to the shorter track.
+/// * `curve` - the shape of both fades.
+pub fn crossfade<F: SampleFormat + Copy>(
+    a: &[F],
+    b: &[F],
+    len: usize,
+    curve: FadeCurve,
+) -> Track<F> {
+    let len = len.min(a.len()).min(b.len());
+    let (head, a_tail) = a.split_at(a.len() - len);
+    let (b_head, tail) = b.split_at(len);
+
+    let mut track: Track<F> = head.iter().copied().collect();
+    track.extend(a_tail.iter().zip(b_head).enumerate().map(|(n, (x, y))| {
+        let t = (n as AccurateMath + 0.5) / len as AccurateMath;
+        *x * curve.gain(1.0 - t) + *y * curve.gain(t)
+    }));
+    track.extend(tail.iter().copied());
+
+    track
+}
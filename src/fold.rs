@@ -0,0 +1,21 @@
+//! # Fold
+//!
+//! Module containing the implementations shared by the [`Sum`] and [`Product`]
+//! impls of the sample formats.
+//!
+//! [`Sum`]: https://doc.rust-lang.org/std/iter/trait.Sum.html
+//! [`Product`]: https://doc.rust-lang.org/std/iter/trait.Product.html
+
+use super::*;
+
+/// Adds together all samples of the iterator, returning silence if it is
+/// empty.
+pub(crate) fn sum<F: SampleFormat>(iter: impl Iterator<Item = F>) -> F {
+    iter.fold(F::SILENCE, |a, b| a + b)
+}
+
+/// Multiplies together all samples of the iterator channel by channel,
+/// returning a sample with every channel set to 1 if it is empty.
+pub(crate) fn product<F: SampleFormat>(iter: impl Iterator<Item = F>) -> F {
+    iter.fold(F::UNITY, |a, b| a * b)
+}
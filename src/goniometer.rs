@@ -0,0 +1,47 @@
+//! # Goniometer
+//!
+//! Module containing the conversion of stereo samples to coordinates for
+//! plotting a goniometer, also known as a vectorscope, which displays the
+//! stereo image of a signal.
+
+use super::*;
+
+/// Returns an iterator over the mid/side coordinates of each sample of the
+/// track, as tuples of the mid and side samples respectively, computed as done
+/// by [`to_mid_side`].
+///
+/// # Parameters
+///
+/// * `track` - the stereo samples to plot.
+///
+/// [`to_mid_side`]: ../stereo/fn.to_mid_side.html
+pub fn goniometer_mid_side(track: &[Stereo]) -> impl Iterator<Item = (Sample, Sample)> + '_ {
+    track.iter().map(|s| to_mid_side(*s))
+}
+
+/// Returns an iterator over the coordinates of each sample of the track on a
+/// Lissajous figure rotated by 45 degrees, as tuples of the horizontal and
+/// vertical coordinates respectively:
+///
+/// * `x = (R - L) / √2`
+/// * `y = (L + R) / √2`
+///
+/// Mono content is plotted as a vertical line, content in only the left or
+/// right channel as a diagonal line leaning to that side, and content of
+/// opposite polarity as a horizontal line. The rotation preserves distances,
+/// so a sample with both channels at full scale is plotted √2 from the center.
+///
+/// # Parameters
+///
+/// * `track` - the stereo samples to plot.
+pub fn goniometer_lissajous(track: &[Stereo]) -> impl Iterator<Item = (Sample, Sample)> + '_ {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    track.iter().map(|s| {
+        let (l, r) = (s.left.0 as AccurateMath, s.right.0 as AccurateMath);
+        (
+            Sample(((r - l) * FRAC_1_SQRT_2) as FastMath),
+            Sample(((l + r) * FRAC_1_SQRT_2) as FastMath),
+        )
+    })
+}
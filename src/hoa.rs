@@ -0,0 +1,583 @@
+//! # Higher-Order Ambisonics
+//!
+//! Module containing type for handling higher-order ambisonic audio data.
+//!
+//! Channels use Ambisonic Channel Numbering (ACN) ordering and SN3D
+//! normalization, commonly known together as the AmbiX format.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Hoa`] samples
+///
+/// [`Hoa`]: struct.Hoa.html
+pub type HoaTrackT<const ORDER: usize, const N: usize> = Vec<Hoa<ORDER, N>>;
+
+/// First-order ambisonic sample in ACN/SN3D format.
+pub type Hoa1 = Hoa<1, 4>;
+/// Second-order ambisonic sample in ACN/SN3D format.
+pub type Hoa2 = Hoa<2, 9>;
+/// Third-order ambisonic sample in ACN/SN3D format.
+pub type Hoa3 = Hoa<3, 16>;
+
+/// Struct representing a higher-order ambisonic audio sample of the given
+/// order.
+///
+/// The number of channels `N` must be equal to `(ORDER+1)^2`. As stable Rust
+/// cannot yet calculate an array length from a const generic parameter, the
+/// channel count is given as a separate parameter, and checked at compile time.
+/// The type aliases [`Hoa1`], [`Hoa2`], and [`Hoa3`] are provided for the most
+/// common orders.
+///
+/// [`Hoa1`]: type.Hoa1.html
+/// [`Hoa2`]: type.Hoa2.html
+/// [`Hoa3`]: type.Hoa3.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Hoa<const ORDER: usize, const N: usize> {
+    /// The ambisonic channels in ACN order.
+    pub channels: [Sample; N],
+}
+
+impl<const ORDER: usize, const N: usize> Hoa<ORDER, N> {
+    /// Compile-time check that the channel count matches the order.
+    const VALID: () = assert!(
+        (ORDER + 1) * (ORDER + 1) == N,
+        "The channel count of an Hoa sample must be (ORDER+1)^2"
+    );
+
+    /// Labels of the channels in ACN order.
+    const LABELS: [ChannelLabel; N] = ambisonic_labels::<N>();
+
+    /// Returns a new Hoa object with all channels set to 0.
+    pub const fn new() -> Self {
+        Hoa::SILENCE
+    }
+
+    /// Returns a new Hoa object created from the given channels in ACN order.
+    ///
+    /// # Parameters
+    ///
+    /// * `channels` - the ambisonic channels in ACN order.
+    pub const fn from(channels: [Sample; N]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID;
+
+        Hoa { channels }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; N] {
+        let s: [Sample; N] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Hoa object with each channel set to the positive linear
+    /// value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; N]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Returns the ambisonic order of the sample.
+    pub fn order() -> usize {
+        ORDER
+    }
+
+    /// Encodes the given sample at the given azimuth and elevation, both given
+    /// in degrees. This is the implementation shared by the [`Panner`] impls.
+    ///
+    /// [`Panner`]: ../trait.Panner.html
+    fn encode(s: Sample, azimuth: AccurateMath, elevation: AccurateMath) -> Self {
+        let mut h = Hoa::new();
+        // The ambisonic convention measures azimuth counter-clockwise
+        let az = -azimuth.to_radians();
+        let el = elevation.to_radians();
+
+        for l in 0..=ORDER {
+            for m in -(l as isize)..=(l as isize) {
+                let acn = (l * l) as isize + l as isize + m;
+                let y = spherical_harmonic(l, m, az, el);
+
+                h.channels[acn as usize] = Sample((s.0 as AccurateMath * y) as FastMath);
+            }
+        }
+
+        h
+    }
+}
+
+/// Calculates the real, SN3D normalized spherical harmonic of degree `l` and
+/// index `m` without the Condon-Shortley phase.
+fn spherical_harmonic(l: usize, m: isize, az: AccurateMath, el: AccurateMath) -> AccurateMath {
+    let am = m.unsigned_abs();
+
+    // (l-|m|)! / (l+|m|)!
+    let mut ratio = 1.0;
+    for k in (l - am + 1)..=(l + am) {
+        ratio /= k as AccurateMath;
+    }
+    let norm = if m == 0 { 1.0 } else { (2.0 * ratio).sqrt() };
+
+    let trig = if m >= 0 {
+        (am as AccurateMath * az).cos()
+    } else {
+        (am as AccurateMath * az).sin()
+    };
+
+    norm * legendre(l, am, el.sin()) * trig
+}
+
+/// Calculates the associated Legendre polynomial of degree `l` and order `m`
+/// at `x` without the Condon-Shortley phase.
+fn legendre(l: usize, m: usize, x: AccurateMath) -> AccurateMath {
+    // P_m^m = (2m-1)!! (1-x^2)^(m/2)
+    let mut pmm = 1.0;
+    let s = (1.0 - x * x).max(0.0).sqrt();
+    for k in 0..m {
+        pmm *= (2 * k + 1) as AccurateMath * s;
+    }
+    if l == m {
+        return pmm;
+    }
+
+    // P_(m+1)^m = x (2m+1) P_m^m
+    let mut pm1 = x * (2 * m + 1) as AccurateMath * pmm;
+    if l == m + 1 {
+        return pm1;
+    }
+
+    // (l-m) P_l^m = x (2l-1) P_(l-1)^m - (l+m-1) P_(l-2)^m
+    let mut pm2 = pmm;
+    for ll in (m + 2)..=l {
+        let p = (x * (2 * ll - 1) as AccurateMath * pm1 - (ll + m - 1) as AccurateMath * pm2)
+            / (ll - m) as AccurateMath;
+        pm2 = pm1;
+        pm1 = p;
+    }
+
+    pm1
+}
+
+impl<const ORDER: usize, const N: usize> Default for Hoa<ORDER, N> {
+    fn default() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID;
+
+        Hoa {
+            channels: [Sample(0.0); N],
+        }
+    }
+}
+
+impl<const ORDER: usize, const N: usize> SampleFormat for Hoa<ORDER, N> {
+    const CHANNEL_COUNT: usize = N;
+
+    const SILENCE: Self = Hoa::from([Sample(0.0); N]);
+    const UNITY: Self = Hoa::from([Sample(1.0); N]);
+
+    fn from_sample(x: Sample) -> Self {
+        let mut h = Hoa::new();
+        h.channels[0] = x;
+        h
+    }
+
+    fn into_sample(self) -> Sample {
+        self.channels[0]
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &Self::LABELS
+    }
+}
+
+/// Encodes a given sample into ambisonics at a position given by the tuple
+/// `(azimuth, elevation)` in degrees. The azimuth is measured clockwise from
+/// the front, such that -90 is directly left and 90 is directly right, matching
+/// the other panners of this crate. The elevation is measured upwards from the
+/// horizontal plane.
+impl<const ORDER: usize, const N: usize> Panner<(f32, f32)> for Hoa<ORDER, N> {
+    fn to_sample_format(s: Sample, g: (f32, f32)) -> Self {
+        Hoa::encode(s, g.0 as AccurateMath, g.1 as AccurateMath)
+    }
+}
+impl<const ORDER: usize, const N: usize> Panner<(f64, f64)> for Hoa<ORDER, N> {
+    fn to_sample_format(s: Sample, g: (f64, f64)) -> Self {
+        Hoa::encode(s, g.0 as AccurateMath, g.1 as AccurateMath)
+    }
+}
+impl<const ORDER: usize, const N: usize> Panner<SphericalPosition> for Hoa<ORDER, N> {
+    fn to_sample_format(s: Sample, g: SphericalPosition) -> Self {
+        Hoa::encode(s, g.azimuth, g.elevation)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl<const ORDER: usize, const N: usize> std::ops::Index<usize> for Hoa<ORDER, N> {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.channels[i]
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl<const ORDER: usize, const N: usize> std::ops::IndexMut<usize> for Hoa<ORDER, N> {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.channels[i]
+    }
+}
+
+impl<const ORDER: usize, const N: usize> AsRef<[Sample]> for Hoa<ORDER, N> {
+    fn as_ref(&self) -> &[Sample] {
+        &self.channels
+    }
+}
+impl<const ORDER: usize, const N: usize> AsMut<[Sample]> for Hoa<ORDER, N> {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        &mut self.channels
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::ops::Neg for Hoa<ORDER, N> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        for c in self.channels.iter_mut() {
+            c.0 = -c.0;
+        }
+        self
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::ops::Add<Hoa<ORDER, N>> for Hoa<ORDER, N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Hoa<ORDER, N>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+impl<const ORDER: usize, const N: usize> std::ops::AddAssign<Hoa<ORDER, N>> for Hoa<ORDER, N> {
+    fn add_assign(&mut self, rhs: Hoa<ORDER, N>) {
+        for (c, r) in self.channels.iter_mut().zip(rhs.channels.iter()) {
+            c.0 += r.0;
+        }
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::ops::Sub<Hoa<ORDER, N>> for Hoa<ORDER, N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Hoa<ORDER, N>) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<const ORDER: usize, const N: usize> std::ops::SubAssign<Hoa<ORDER, N>> for Hoa<ORDER, N> {
+    fn sub_assign(&mut self, rhs: Hoa<ORDER, N>) {
+        for (c, r) in self.channels.iter_mut().zip(rhs.channels.iter()) {
+            c.0 -= r.0;
+        }
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::ops::Mul<Hoa<ORDER, N>> for Hoa<ORDER, N> {
+    type Output = Hoa<ORDER, N>;
+
+    fn mul(mut self, rhs: Hoa<ORDER, N>) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl<const ORDER: usize, const N: usize> std::ops::MulAssign<Hoa<ORDER, N>> for Hoa<ORDER, N> {
+    fn mul_assign(&mut self, rhs: Hoa<ORDER, N>) {
+        for (c, r) in self.channels.iter_mut().zip(rhs.channels.iter()) {
+            c.0 *= r.0;
+        }
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::ops::Mul<Sample> for Hoa<ORDER, N> {
+    type Output = Hoa<ORDER, N>;
+
+    fn mul(mut self, rhs: Sample) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl<const ORDER: usize, const N: usize> std::ops::MulAssign<Sample> for Hoa<ORDER, N> {
+    fn mul_assign(&mut self, rhs: Sample) {
+        for c in self.channels.iter_mut() {
+            c.0 *= rhs.0;
+        }
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::ops::Mul<Math> for Hoa<ORDER, N> {
+    type Output = Hoa<ORDER, N>;
+
+    fn mul(mut self, rhs: Math) -> Self::Output {
+        for c in self.channels.iter_mut() {
+            c.0 = (c.0 as AccurateMath * rhs.0) as FastMath;
+        }
+        self
+    }
+}
+impl<const ORDER: usize, const N: usize> std::ops::MulAssign<Math> for Hoa<ORDER, N> {
+    fn mul_assign(&mut self, rhs: Math) {
+        for c in self.channels.iter_mut() {
+            c.0 *= rhs.0 as FastMath;
+        }
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::ops::Mul<f32> for Hoa<ORDER, N> {
+    type Output = Hoa<ORDER, N>;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl<const ORDER: usize, const N: usize> std::ops::MulAssign<f32> for Hoa<ORDER, N> {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::ops::Mul<f64> for Hoa<ORDER, N> {
+    type Output = Hoa<ORDER, N>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl<const ORDER: usize, const N: usize> std::ops::MulAssign<f64> for Hoa<ORDER, N> {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::ops::Mul<[Sample; N]> for Hoa<ORDER, N> {
+    type Output = Hoa<ORDER, N>;
+
+    fn mul(mut self, rhs: [Sample; N]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl<const ORDER: usize, const N: usize> std::ops::MulAssign<[Sample; N]> for Hoa<ORDER, N> {
+    fn mul_assign(&mut self, rhs: [Sample; N]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::iter::Sum for Hoa<ORDER, N> {
+    fn sum<I: Iterator<Item = Hoa<ORDER, N>>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a, const ORDER: usize, const N: usize> std::iter::Sum<&'a Hoa<ORDER, N>> for Hoa<ORDER, N> {
+    fn sum<I: Iterator<Item = &'a Hoa<ORDER, N>>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl<const ORDER: usize, const N: usize> std::iter::Product for Hoa<ORDER, N> {
+    fn product<I: Iterator<Item = Hoa<ORDER, N>>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a, const ORDER: usize, const N: usize> std::iter::Product<&'a Hoa<ORDER, N>>
+    for Hoa<ORDER, N>
+{
+    fn product<I: Iterator<Item = &'a Hoa<ORDER, N>>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl<const ORDER: usize, const N: usize> From<Sample> for Hoa<ORDER, N> {
+    fn from(s: Sample) -> Self {
+        Hoa::from_sample(s)
+    }
+}
+impl<const ORDER: usize, const N: usize> Into<Sample> for Hoa<ORDER, N> {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl<const ORDER: usize, const N: usize> From<[Sample; N]> for Hoa<ORDER, N> {
+    fn from(a: [Sample; N]) -> Self {
+        Hoa::from(a)
+    }
+}
+impl<const ORDER: usize, const N: usize> Into<[Sample; N]> for Hoa<ORDER, N> {
+    fn into(self) -> [Sample; N] {
+        self.channels
+    }
+}
+
+impl<const ORDER: usize, const N: usize> TryFrom<&[u8]> for Hoa<ORDER, N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < N {
+            Err(SampleFormatError::WrongLength {
+                expected: N,
+                actual: v.len(),
+            })
+        } else {
+            let mut h = Hoa::new();
+            for (c, x) in h.channels.iter_mut().zip(v) {
+                *c = sample_from_u8(*x);
+            }
+            Ok(h)
+        }
+    }
+}
+impl<const ORDER: usize, const N: usize> TryFrom<Vec<u8>> for Hoa<ORDER, N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl<const ORDER: usize, const N: usize> Into<Vec<u8>> for Hoa<ORDER, N> {
+    fn into(self) -> Vec<u8> {
+        self.channels.iter().map(|c| sample_to_u8(*c)).collect()
+    }
+}
+
+impl<const ORDER: usize, const N: usize> TryFrom<&[i16]> for Hoa<ORDER, N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < N {
+            Err(SampleFormatError::WrongLength {
+                expected: N,
+                actual: v.len(),
+            })
+        } else {
+            let mut h = Hoa::new();
+            for (c, x) in h.channels.iter_mut().zip(v) {
+                *c = sample_from_i16(*x);
+            }
+            Ok(h)
+        }
+    }
+}
+impl<const ORDER: usize, const N: usize> TryFrom<Vec<i16>> for Hoa<ORDER, N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl<const ORDER: usize, const N: usize> Into<Vec<i16>> for Hoa<ORDER, N> {
+    fn into(self) -> Vec<i16> {
+        self.channels.iter().map(|c| sample_to_i16(*c)).collect()
+    }
+}
+
+impl<const ORDER: usize, const N: usize> TryFrom<&[i32]> for Hoa<ORDER, N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < N {
+            Err(SampleFormatError::WrongLength {
+                expected: N,
+                actual: v.len(),
+            })
+        } else {
+            let mut h = Hoa::new();
+            for (c, x) in h.channels.iter_mut().zip(v) {
+                *c = sample_from_i24(*x);
+            }
+            Ok(h)
+        }
+    }
+}
+impl<const ORDER: usize, const N: usize> TryFrom<Vec<i32>> for Hoa<ORDER, N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl<const ORDER: usize, const N: usize> Into<Vec<i32>> for Hoa<ORDER, N> {
+    fn into(self) -> Vec<i32> {
+        self.channels.iter().map(|c| sample_to_i24(*c)).collect()
+    }
+}
+
+impl<const ORDER: usize, const N: usize> TryFrom<&[f32]> for Hoa<ORDER, N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < N {
+            Err(SampleFormatError::WrongLength {
+                expected: N,
+                actual: v.len(),
+            })
+        } else {
+            let mut h = Hoa::new();
+            for (c, x) in h.channels.iter_mut().zip(v) {
+                *c = sample_from_f32(*x);
+            }
+            Ok(h)
+        }
+    }
+}
+impl<const ORDER: usize, const N: usize> TryFrom<Vec<f32>> for Hoa<ORDER, N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl<const ORDER: usize, const N: usize> Into<Vec<f32>> for Hoa<ORDER, N> {
+    fn into(self) -> Vec<f32> {
+        self.channels.iter().map(|c| sample_to_f32(*c)).collect()
+    }
+}
+
+impl<const ORDER: usize, const N: usize> TryFrom<&[f64]> for Hoa<ORDER, N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < N {
+            Err(SampleFormatError::WrongLength {
+                expected: N,
+                actual: v.len(),
+            })
+        } else {
+            let mut h = Hoa::new();
+            for (c, x) in h.channels.iter_mut().zip(v) {
+                *c = sample_from_f64(*x);
+            }
+            Ok(h)
+        }
+    }
+}
+impl<const ORDER: usize, const N: usize> TryFrom<Vec<f64>> for Hoa<ORDER, N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl<const ORDER: usize, const N: usize> Into<Vec<f64>> for Hoa<ORDER, N> {
+    fn into(self) -> Vec<f64> {
+        self.channels.iter().map(|c| sample_to_f64(*c)).collect()
+    }
+}
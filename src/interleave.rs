@@ -0,0 +1,179 @@
+//! # Interleave
+//!
+//! Module containing conversions between tracks of samples and interleaved
+//! buffers, where the channels of each frame are stored one after another as
+//! is done by most audio files and devices.
+
+use super::*;
+
+/// Interleaves the channels of every sample of the track into a single buffer.
+///
+/// # Parameters
+///
+/// * `track` - the samples to interleave.
+pub fn interleave<F: SampleFormat>(track: &[F]) -> Vec<Sample> {
+    interleaved(track).collect()
+}
+
+/// Interleaves the channels of every sample of the track into the given
+/// buffer, without allocating.
+///
+/// # Parameters
+///
+/// * `track` - the samples to interleave.
+/// * `data` - the output buffer, exactly [`CHANNEL_COUNT`] times as long as the
+/// track.
+///
+/// [`CHANNEL_COUNT`]: ../trait.SampleFormat.html#associatedconstant.CHANNEL_COUNT
+pub fn interleave_into<F: SampleFormat>(
+    track: &[F],
+    data: &mut [Sample],
+) -> Result<(), SampleFormatError> {
+    let expected = track.len() * F::CHANNEL_COUNT;
+    if data.len() != expected {
+        return Err(SampleFormatError::WrongLength {
+            expected,
+            actual: data.len(),
+        });
+    }
+
+    for (d, s) in data.iter_mut().zip(interleaved(track)) {
+        *d = s;
+    }
+
+    Ok(())
+}
+
+/// Returns an iterator over the channels of every sample of the track in
+/// interleaved order.
+///
+/// # Parameters
+///
+/// * `track` - the samples to interleave.
+pub fn interleaved<F: SampleFormat>(track: &[F]) -> impl Iterator<Item = Sample> + '_ {
+    track.iter().flat_map(|s| s.as_ref().iter().copied())
+}
+
+/// Splits an interleaved buffer into a track of samples.
+///
+/// # Parameters
+///
+/// * `data` - the interleaved frames, with a length that is a multiple of
+/// [`CHANNEL_COUNT`].
+///
+/// [`CHANNEL_COUNT`]: ../trait.SampleFormat.html#associatedconstant.CHANNEL_COUNT
+pub fn deinterleave<F: SampleFormat>(data: &[Sample]) -> Result<Vec<F>, SampleFormatError> {
+    Ok(deinterleaved(data)?.collect())
+}
+
+/// Splits an interleaved buffer into the given track of samples, without
+/// allocating.
+///
+/// # Parameters
+///
+/// * `data` - the interleaved frames, exactly [`CHANNEL_COUNT`] times as long
+/// as the track.
+/// * `track` - the output samples.
+///
+/// [`CHANNEL_COUNT`]: ../trait.SampleFormat.html#associatedconstant.CHANNEL_COUNT
+pub fn deinterleave_into<F: SampleFormat>(
+    data: &[Sample],
+    track: &mut [F],
+) -> Result<(), SampleFormatError> {
+    let expected = track.len() * F::CHANNEL_COUNT;
+    if data.len() != expected {
+        return Err(SampleFormatError::WrongLength {
+            expected,
+            actual: data.len(),
+        });
+    }
+
+    for (s, frame) in track.iter_mut().zip(deinterleaved(data)?) {
+        *s = frame;
+    }
+
+    Ok(())
+}
+
+/// Returns an iterator over the samples of an interleaved buffer.
+///
+/// # Parameters
+///
+/// * `data` - the interleaved frames, with a length that is a multiple of
+/// [`CHANNEL_COUNT`].
+///
+/// [`CHANNEL_COUNT`]: ../trait.SampleFormat.html#associatedconstant.CHANNEL_COUNT
+pub fn deinterleaved<F: SampleFormat>(
+    data: &[Sample],
+) -> Result<impl Iterator<Item = F> + '_, SampleFormatError> {
+    frames(data, |x| x)
+}
+
+/// Returns an iterator over the samples of an interleaved buffer of 16-bit
+/// PCM values, decoded one at a time without allocating.
+///
+/// # Parameters
+///
+/// * `data` - the interleaved frames, with a length that is a multiple of
+/// [`CHANNEL_COUNT`].
+///
+/// [`CHANNEL_COUNT`]: ../trait.SampleFormat.html#associatedconstant.CHANNEL_COUNT
+pub fn samples_from_i16_interleaved<F: SampleFormat>(
+    data: &[i16],
+) -> Result<impl Iterator<Item = F> + '_, SampleFormatError> {
+    frames(data, bae_utils::sample_from_i16)
+}
+
+/// Returns an iterator over the samples of an interleaved buffer of 24-bit
+/// PCM values stored in `i32`, as for the `TryFrom<&[i32]>` impls of the
+/// sample formats, decoded one at a time without allocating.
+///
+/// # Parameters
+///
+/// * `data` - the interleaved frames, with a length that is a multiple of
+/// [`CHANNEL_COUNT`].
+///
+/// [`CHANNEL_COUNT`]: ../trait.SampleFormat.html#associatedconstant.CHANNEL_COUNT
+pub fn samples_from_i32_interleaved<F: SampleFormat>(
+    data: &[i32],
+) -> Result<impl Iterator<Item = F> + '_, SampleFormatError> {
+    frames(data, bae_utils::sample_from_i24)
+}
+
+/// Returns an iterator over the samples of an interleaved buffer of 32-bit
+/// float PCM values, decoded one at a time without allocating.
+///
+/// # Parameters
+///
+/// * `data` - the interleaved frames, with a length that is a multiple of
+/// [`CHANNEL_COUNT`].
+///
+/// [`CHANNEL_COUNT`]: ../trait.SampleFormat.html#associatedconstant.CHANNEL_COUNT
+pub fn samples_from_f32_interleaved<F: SampleFormat>(
+    data: &[f32],
+) -> Result<impl Iterator<Item = F> + '_, SampleFormatError> {
+    frames(data, sample_from_f32)
+}
+
+/// Returns an iterator over the samples of an interleaved buffer, converting
+/// each value to a channel with the given function.
+fn frames<T: Copy, F: SampleFormat>(
+    data: &[T],
+    read: fn(T) -> Sample,
+) -> Result<impl Iterator<Item = F> + '_, SampleFormatError> {
+    let n = F::CHANNEL_COUNT;
+    if n == 0 || data.len() % n != 0 {
+        return Err(SampleFormatError::UnalignedLength {
+            channels: n,
+            actual: data.len(),
+        });
+    }
+
+    Ok(data.chunks_exact(n).map(move |frame| {
+        let mut s = F::SILENCE;
+        for (c, v) in s.as_mut().iter_mut().zip(frame) {
+            *c = read(*v);
+        }
+        s
+    }))
+}
@@ -0,0 +1,622 @@
+//! # LCR
+//!
+//! Module containing type for handling LCR (3.0) audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Lcr`] samples
+///
+/// [`Lcr`]: struct.Lcr.html
+pub type LcrTrackT = Vec<Lcr>;
+
+/// Struct representing an LCR (left, center, right) audio sample.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Lcr {
+    /// Left sample value.
+    pub left: Sample,
+    /// Center sample value.
+    pub center: Sample,
+    /// Right sample value.
+    pub right: Sample,
+}
+
+impl Lcr {
+    /// Returns a new Lcr object with default <0,0,0> values.
+    pub const fn new() -> Self {
+        Lcr::SILENCE
+    }
+
+    /// Returns a new Lcr object created from the individual audio samples of
+    /// each channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `l` - the left audio sample.
+    /// * `c` - the center audio sample.
+    /// * `r` - the right audio sample.
+    pub const fn from(l: Sample, c: Sample, r: Sample) -> Self {
+        Lcr {
+            left: l,
+            center: c,
+            right: r,
+        }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 3] {
+        let s: [Sample; 3] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Lcr object with each channel set to the positive linear
+    /// value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 3]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+}
+
+impl SampleFormat for Lcr {
+    const CHANNEL_COUNT: usize = 3;
+
+    const SILENCE: Self = Lcr {
+        left: Sample(0.0),
+        center: Sample(0.0),
+        right: Sample(0.0),
+    };
+    const UNITY: Self = Lcr {
+        left: Sample(1.0),
+        center: Sample(1.0),
+        right: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        Lcr {
+            center: x,
+            ..Default::default()
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        Sample(self.center.0 + (self.left.0 + self.right.0) * FastMath::sqrt(0.5))
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[
+            ChannelLabel::FrontLeft,
+            ChannelLabel::Center,
+            ChannelLabel::FrontRight,
+        ]
+    }
+}
+
+/// Pans a given sample across the left, center, and right channels. The
+/// panning parameter `g` is a floating point value of the range \[-1,1\], where
+/// -1 is panned full left, 0 is panned fully to the center speaker, and 1 is
+/// panned full right. If the given value is not within this range, it is
+/// clamped to it.
+///
+/// Rather than creating a phantom center between the left and right speakers,
+/// the sample is panned between only the two adjacent speakers (left and
+/// center, or center and right) in the same way as the [`Stereo`] panner.
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+impl Panner<f32> for Lcr {
+    fn to_sample_format(s: Sample, g: f32) -> Self {
+        let g = g.clamp(-1.0, 1.0);
+
+        if g <= 0.0 {
+            let st = Stereo::to_sample_format(s, 2.0 * g + 1.0);
+
+            Lcr {
+                left: st.left,
+                center: st.right,
+                right: Sample(0.0),
+            }
+        } else {
+            let st = Stereo::to_sample_format(s, 2.0 * g - 1.0);
+
+            Lcr {
+                left: Sample(0.0),
+                center: st.left,
+                right: st.right,
+            }
+        }
+    }
+}
+impl Panner<f64> for Lcr {
+    fn to_sample_format(s: Sample, g: f64) -> Self {
+        let g = g.clamp(-1.0, 1.0);
+
+        if g <= 0.0 {
+            let st = Stereo::to_sample_format(s, 2.0 * g + 1.0);
+
+            Lcr {
+                left: st.left,
+                center: st.right,
+                right: Sample(0.0),
+            }
+        } else {
+            let st = Stereo::to_sample_format(s, 2.0 * g - 1.0);
+
+            Lcr {
+                left: Sample(0.0),
+                center: st.left,
+                right: st.right,
+            }
+        }
+    }
+}
+
+/// Pans a given sample to the given position in the same way as the panner
+/// taking only a floating point value, diverting part of the center channel to
+/// a phantom center with the given [`CenterDivergence`].
+///
+/// [`CenterDivergence`]: ../center_divergence/struct.CenterDivergence.html
+impl Panner<(f32, CenterDivergence)> for Lcr {
+    fn to_sample_format(s: Sample, g: (f32, CenterDivergence)) -> Self {
+        Lcr::to_sample_format(s, (g.0 as f64, g.1))
+    }
+}
+impl Panner<(f64, CenterDivergence)> for Lcr {
+    fn to_sample_format(s: Sample, g: (f64, CenterDivergence)) -> Self {
+        let mut p = Lcr::to_sample_format(s, g.0);
+        g.1.apply(&mut p.left, &mut p.center, &mut p.right);
+        p
+    }
+}
+
+impl From<Mono> for Lcr {
+    fn from(s: Mono) -> Self {
+        s.convert_format()
+    }
+}
+
+impl From<Stereo> for Lcr {
+    fn from(s: Stereo) -> Self {
+        s.convert_format()
+    }
+}
+
+impl Balancer for Lcr {}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Lcr {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.left,
+            1 => &self.center,
+            2 => &self.right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Lcr with 3 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Lcr {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.left,
+            1 => &mut self.center,
+            2 => &mut self.right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Lcr with 3 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Lcr {
+    fn as_ref(&self) -> &[Sample] {
+        // Lcr is #[repr(C)] and made up solely of 3 Sample fields.
+        unsafe { channel::as_slice(self, 3) }
+    }
+}
+impl AsMut<[Sample]> for Lcr {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Lcr is #[repr(C)] and made up solely of 3 Sample fields.
+        unsafe { channel::as_mut_slice(self, 3) }
+    }
+}
+
+impl std::ops::Neg for Lcr {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Lcr {
+            left: Sample(-self.left.0),
+            center: Sample(-self.center.0),
+            right: Sample(-self.right.0),
+        }
+    }
+}
+
+impl std::ops::Add<Lcr> for Lcr {
+    type Output = Self;
+
+    fn add(self, rhs: Lcr) -> Self::Output {
+        Lcr {
+            left: Sample(self.left.0 + rhs.left.0),
+            center: Sample(self.center.0 + rhs.center.0),
+            right: Sample(self.right.0 + rhs.right.0),
+        }
+    }
+}
+impl std::ops::AddAssign<Lcr> for Lcr {
+    fn add_assign(&mut self, rhs: Lcr) {
+        self.left.0 += rhs.left.0;
+        self.center.0 += rhs.center.0;
+        self.right.0 += rhs.right.0;
+    }
+}
+
+impl std::ops::Sub<Lcr> for Lcr {
+    type Output = Self;
+
+    fn sub(self, rhs: Lcr) -> Self {
+        Lcr {
+            left: Sample(self.left.0 - rhs.left.0),
+            center: Sample(self.center.0 - rhs.center.0),
+            right: Sample(self.right.0 - rhs.right.0),
+        }
+    }
+}
+impl std::ops::SubAssign<Lcr> for Lcr {
+    fn sub_assign(&mut self, rhs: Lcr) {
+        self.left.0 -= rhs.left.0;
+        self.center.0 -= rhs.center.0;
+        self.right.0 -= rhs.right.0;
+    }
+}
+
+impl std::ops::Mul<Lcr> for Lcr {
+    type Output = Lcr;
+
+    fn mul(self, rhs: Lcr) -> Self::Output {
+        Lcr {
+            left: Sample(self.left.0 * rhs.left.0),
+            center: Sample(self.center.0 * rhs.center.0),
+            right: Sample(self.right.0 * rhs.right.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Lcr> for Lcr {
+    fn mul_assign(&mut self, rhs: Lcr) {
+        self.left.0 *= rhs.left.0;
+        self.center.0 *= rhs.center.0;
+        self.right.0 *= rhs.right.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for Lcr {
+    type Output = Lcr;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        Lcr {
+            left: Sample(self.left.0 * rhs.0),
+            center: Sample(self.center.0 * rhs.0),
+            right: Sample(self.right.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for Lcr {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.left.0 *= rhs.0;
+        self.center.0 *= rhs.0;
+        self.right.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for Lcr {
+    type Output = Lcr;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        Lcr {
+            left: Sample((self.left.0 as AccurateMath * rhs.0) as FastMath),
+            center: Sample((self.center.0 as AccurateMath * rhs.0) as FastMath),
+            right: Sample((self.right.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for Lcr {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.left.0 *= rhs.0 as FastMath;
+        self.center.0 *= rhs.0 as FastMath;
+        self.right.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for Lcr {
+    type Output = Lcr;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Lcr {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Lcr {
+    type Output = Lcr;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Lcr {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 3]> for Lcr {
+    type Output = Lcr;
+
+    fn mul(mut self, rhs: [Sample; 3]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 3]> for Lcr {
+    fn mul_assign(&mut self, rhs: [Sample; 3]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Lcr {
+    fn sum<I: Iterator<Item = Lcr>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Lcr> for Lcr {
+    fn sum<I: Iterator<Item = &'a Lcr>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Lcr {
+    fn product<I: Iterator<Item = Lcr>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Lcr> for Lcr {
+    fn product<I: Iterator<Item = &'a Lcr>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for Lcr {
+    fn from(s: Sample) -> Self {
+        Lcr::from_sample(s)
+    }
+}
+impl Into<Sample> for Lcr {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 3]> for Lcr {
+    fn from(a: [Sample; 3]) -> Self {
+        Lcr {
+            left: a[0],
+            center: a[1],
+            right: a[2],
+        }
+    }
+}
+impl Into<[Sample; 3]> for Lcr {
+    fn into(self) -> [Sample; 3] {
+        [self.left, self.center, self.right]
+    }
+}
+
+impl From<(Sample, Sample, Sample)> for Lcr {
+    fn from(t: (Sample, Sample, Sample)) -> Self {
+        Lcr {
+            left: t.0,
+            center: t.1,
+            right: t.2,
+        }
+    }
+}
+impl Into<(Sample, Sample, Sample)> for Lcr {
+    fn into(self) -> (Sample, Sample, Sample) {
+        (self.left, self.center, self.right)
+    }
+}
+
+impl TryFrom<&[u8]> for Lcr {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 3 {
+            Err(SampleFormatError::WrongLength {
+                expected: 3,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Lcr {
+                left: sample_from_u8(v[0]),
+                center: sample_from_u8(v[1]),
+                right: sample_from_u8(v[2]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for Lcr {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for Lcr {
+    fn into(self) -> Vec<u8> {
+        vec![
+            sample_to_u8(self.left),
+            sample_to_u8(self.center),
+            sample_to_u8(self.right),
+        ]
+    }
+}
+
+impl TryFrom<&[i16]> for Lcr {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 3 {
+            Err(SampleFormatError::WrongLength {
+                expected: 3,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Lcr {
+                left: sample_from_i16(v[0]),
+                center: sample_from_i16(v[1]),
+                right: sample_from_i16(v[2]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for Lcr {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for Lcr {
+    fn into(self) -> Vec<i16> {
+        vec![
+            sample_to_i16(self.left),
+            sample_to_i16(self.center),
+            sample_to_i16(self.right),
+        ]
+    }
+}
+
+impl TryFrom<&[i32]> for Lcr {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 3 {
+            Err(SampleFormatError::WrongLength {
+                expected: 3,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Lcr {
+                left: sample_from_i24(v[0]),
+                center: sample_from_i24(v[1]),
+                right: sample_from_i24(v[2]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for Lcr {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for Lcr {
+    fn into(self) -> Vec<i32> {
+        vec![
+            sample_to_i24(self.left),
+            sample_to_i24(self.center),
+            sample_to_i24(self.right),
+        ]
+    }
+}
+
+impl TryFrom<&[f32]> for Lcr {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 3 {
+            Err(SampleFormatError::WrongLength {
+                expected: 3,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Lcr {
+                left: sample_from_f32(v[0]),
+                center: sample_from_f32(v[1]),
+                right: sample_from_f32(v[2]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Lcr {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Lcr {
+    fn into(self) -> Vec<f32> {
+        vec![
+            sample_to_f32(self.left),
+            sample_to_f32(self.center),
+            sample_to_f32(self.right),
+        ]
+    }
+}
+
+impl TryFrom<&[f64]> for Lcr {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 3 {
+            Err(SampleFormatError::WrongLength {
+                expected: 3,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Lcr {
+                left: sample_from_f64(v[0]),
+                center: sample_from_f64(v[1]),
+                right: sample_from_f64(v[2]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Lcr {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Lcr {
+    fn into(self) -> Vec<f64> {
+        vec![
+            sample_to_f64(self.left),
+            sample_to_f64(self.center),
+            sample_to_f64(self.right),
+        ]
+    }
+}
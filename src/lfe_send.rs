@@ -0,0 +1,38 @@
+//! # LFE Send
+//!
+//! Module containing the amount of a panned source fed to the low-frequency
+//! effects channel of the surround formats.
+
+use super::*;
+
+/// Linear gain with which a panned source is sent to the LFE channel, given to
+/// the panners of formats with an LFE channel alongside the position of the
+/// source, e.g. `Surround51::to_sample_format(s, (position, LfeSend(0.5)))`.
+///
+/// The unfiltered sample is routed to the LFE channel. It is assumed that the
+/// LFE channel is band-limited further down the signal chain, as is done by the
+/// crossover of common bass management systems, so no low-pass filter is
+/// applied by the panners.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct LfeSend(pub AccurateMath);
+
+impl LfeSend {
+    /// Sends no signal to the LFE channel.
+    pub const NONE: LfeSend = LfeSend(0.0);
+    /// Sends the sample to the LFE channel at unity gain.
+    pub const UNITY: LfeSend = LfeSend(1.0);
+
+    /// Creates a send with the given gain in decibels.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the gain of the send in decibels.
+    pub fn from_db(db: Math) -> Self {
+        LfeSend(bae_utils::db_to_linear(db).0)
+    }
+
+    /// Returns the sample fed to the LFE channel for the given source sample.
+    pub(crate) fn apply(self, s: Sample) -> Sample {
+        Sample((s.0 as AccurateMath * self.0) as FastMath)
+    }
+}
@@ -2,22 +2,155 @@
 //!
 //! Module containing different output formats like stereo, 2.1, 5.1, 7.1, etc.
 //!
-//! All functions that deal with converting raw bytes to numeric types assume
-//! the bytes are in little-endian format.
+//! All functions that deal with converting raw bytes to numeric types take an
+//! [`Endianness`] giving the byte order of the data.
 //!
 //! As there is no i24 built-in type, i32 is used in it's place where
 //! applicable. In most cases where a 24-bit sample is stored in a 32-bit data
 //! type, the upper byte is ignored or explicitly set to 0.
+//!
+//! # Features
+//!
+//! * `bytemuck` - Implements [`Pod`] and [`Zeroable`] for the fixed-size
+//! sample formats, so that slices of samples can be cast to slices of bytes or
+//! floats without copying.
+//! * `rayon` - Adds the [`parallel`] module, processing whole tracks over all
+//! cores with [rayon].
+//! * `serde` - Implements [`Serialize`] and [`Deserialize`] for the sample
+//! formats, representing each sample as a sequence of its channel values.
+//!
+//! [`Endianness`]: endianness/enum.Endianness.html
+//! [`Pod`]: https://docs.rs/bytemuck/1/bytemuck/trait.Pod.html
+//! [`Zeroable`]: https://docs.rs/bytemuck/1/bytemuck/trait.Zeroable.html
+//! [`parallel`]: parallel/index.html
+//! [rayon]: https://docs.rs/rayon/1/rayon/
+//! [`Serialize`]: https://docs.rs/serde/1/serde/trait.Serialize.html
+//! [`Deserialize`]: https://docs.rs/serde/1/serde/trait.Deserialize.html
 
 #![warn(missing_docs)]
 #![doc(html_root_url = "https://docs.rs/bae_sf/0.14.2")]
 
 use bae_types::*;
+use convert::*;
 
+mod convert;
+mod display;
+mod fold;
+mod panning;
+#[cfg(feature = "bytemuck")]
+mod pod;
+mod routing;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub mod ambisonic;
+pub mod binaural;
+pub mod bit_depth;
+pub mod center_divergence;
+pub mod channel;
+pub mod channel_layout;
+pub mod channel_mask;
+pub mod channel_order;
+pub mod clip;
+pub mod dc_offset;
+pub mod dither;
+pub mod downmix_builder;
+pub mod dual_mono;
+pub mod dyn_format;
+pub mod endianness;
+pub mod error;
+pub mod fade;
+pub mod goniometer;
+pub mod hoa;
+pub mod interleave;
+pub mod lcr;
+pub mod lfe_send;
+pub mod loudness;
+pub mod lt_rt;
+pub mod meter;
+pub mod mid_side;
+pub mod mix_matrix;
 pub mod mono;
+pub mod multichannel;
+pub mod normalize;
+pub mod pan_automation;
+pub mod pan_law;
+pub mod panner_ramp;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod pcm;
+pub mod quad;
+pub mod resample;
+pub mod ring_buffer;
+pub mod spatial;
+pub mod spatial_source;
+pub mod spherical_position;
 pub mod stereo;
+pub mod stereo_source;
+pub mod surround21;
+pub mod surround222;
+pub mod surround51;
+pub mod surround512;
+pub mod surround61;
+pub mod surround71;
+pub mod surround714;
+pub mod track;
+pub mod upmix;
+pub mod vbap;
+pub use ambisonic::*;
+pub use binaural::*;
+pub use bit_depth::*;
+pub use center_divergence::*;
+pub use channel::*;
+pub use channel_layout::*;
+pub use channel_mask::*;
+pub use channel_order::*;
+pub use clip::*;
+pub use dc_offset::*;
+pub use dither::*;
+pub use downmix_builder::*;
+pub use dual_mono::*;
+pub use dyn_format::*;
+pub use endianness::*;
+pub use error::*;
+pub use fade::*;
+pub use goniometer::*;
+pub use hoa::*;
+pub use interleave::*;
+pub use lcr::*;
+pub use lfe_send::*;
+pub use loudness::*;
+pub use lt_rt::*;
+pub use meter::*;
+pub use mid_side::*;
+pub use mix_matrix::*;
 pub use mono::*;
+pub use multichannel::*;
+pub use normalize::*;
+pub use pan_automation::*;
+pub use pan_law::*;
+pub use panner_ramp::*;
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+pub use pcm::*;
+pub use quad::*;
+pub use resample::*;
+pub use ring_buffer::*;
+pub use spatial::*;
+pub use spatial_source::*;
+pub use spherical_position::*;
 pub use stereo::*;
+pub use stereo_source::*;
+pub use surround21::*;
+pub use surround222::*;
+pub use surround51::*;
+pub use surround512::*;
+pub use surround61::*;
+pub use surround71::*;
+pub use surround714::*;
+pub use track::*;
+pub use upmix::*;
+pub use vbap::*;
 
 use std::convert::TryFrom;
 use std::ops::*;
@@ -31,30 +164,43 @@ use std::ops::*;
 /// * Most mathematical operators are required to be implemented to be able to
 /// perform common operations on sample values.
 /// * [`Mul`]/[`MulAssign`] is defined for both [`Math`] as well as [`Sample`]
-/// for the convenience of common audio operations.
+/// for the convenience of common audio operations, as well as for plain `f32`
+/// and `f64` values so that constant gains don't need to be wrapped.
+/// * [`Index`]/[`IndexMut`] implemented for `usize` - Allows reading and
+/// writing the nth channel without knowledge of the concrete type. Channels are
+/// indexed in the same order as they are converted to and from [`Vec`]s.
+/// * [`AsRef`]/[`AsMut`] implemented for `[Sample]` - Allows viewing all
+/// channels as a slice, in the same order as they are indexed.
 /// * [`From`]/[`Into`] implemented for [`Sample`] - These functions should be
 /// simple calls to [`from_sample`] and [`into_sample`] respectively.
 /// * [`TryFrom`]/[`Into`] implemented for [`Vec<_>`] - These functions should
-/// convert the sample values to the given standard integer types. As [`Vec`]s
+/// convert the sample values to the given standard integer or floating-point
+/// types. Floating-point values are not clamped. As [`Vec`]s
 /// are generic types, it cannot be assumed that any attempted conversions of
 /// [`Vec`]s to a given sample format will succeed. Therefore those conversions
 /// use [`TryFrom`] to indicate when there is an issue, which can be
-/// communicated with the given [`String`] used for the error type. An example
-/// of such an error could be (for the [`Stereo`] type):
+/// communicated with the given [`SampleFormatError`] used for the error
+/// type. The same conversions are implemented for slices, allowing a sample to
+/// be parsed from the front of a larger interleaved buffer without allocating.
+/// An example of such an error could be (for the [`Stereo`] type):
 ///
 /// ```rust
-/// # use bae_rs::Stereo;
+/// # use bae_sf::{SampleFormatError, Stereo};
 /// # use std::convert::TryFrom;
 ///
 /// let v: Vec<i16> = vec![];
 ///
-/// assert_eq!(Err("ERROR: Given vector was length 0. This function requires length 2.".to_owned()), Stereo::try_from(v));
+/// assert_eq!(Err(SampleFormatError::WrongLength { expected: 2, actual: 0 }), Stereo::try_from(v));
 /// ```
 ///
 /// [`Mul`]: https://doc.rust-lang.org/std/ops/trait.Mul.html
 /// [`MulAssign`]: https://doc.rust-lang.org/std/ops/trait.MulAssign.html
 /// [`Math`]: ../type.Math.html
 /// [`Sample`]: ../type.Sample.html
+/// [`Index`]: https://doc.rust-lang.org/std/ops/trait.Index.html
+/// [`IndexMut`]: https://doc.rust-lang.org/std/ops/trait.IndexMut.html
+/// [`AsRef`]: https://doc.rust-lang.org/std/convert/trait.AsRef.html
+/// [`AsMut`]: https://doc.rust-lang.org/std/convert/trait.AsMut.html
 /// [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
 /// [`Into`]: https://doc.rust-lang.org/std/convert/trait.Into.html
 /// [`from_sample`]: #tymethod.from_sample
@@ -62,7 +208,7 @@ use std::ops::*;
 /// [`TryFrom`]: https://doc.rust-lang.org/std/convert/trait.TryFrom.html
 /// [`Vec<_>`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
 /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
-/// [`String`]: https://doc.rust-lang.org/std/convert/trait.From.html
+/// [`SampleFormatError`]: error/enum.SampleFormatError.html
 /// [`Stereo`]: stereo/struct.Stereo.html
 pub trait SampleFormat:
     Default
@@ -77,30 +223,447 @@ pub trait SampleFormat:
     + MulAssign<Sample>
     + Mul<Math, Output = Self>
     + MulAssign<Math>
+    + Mul<f32, Output = Self>
+    + MulAssign<f32>
+    + Mul<f64, Output = Self>
+    + MulAssign<f64>
+    + Index<usize, Output = Sample>
+    + IndexMut<usize>
+    + AsRef<[Sample]>
+    + AsMut<[Sample]>
     + From<Sample>
     + Into<Sample>
-    + TryFrom<Vec<u8>, Error = String>
+    + for<'a> TryFrom<&'a [u8], Error = SampleFormatError>
+    + TryFrom<Vec<u8>, Error = SampleFormatError>
     + Into<Vec<u8>>
-    + TryFrom<Vec<i16>, Error = String>
+    + for<'a> TryFrom<&'a [i16], Error = SampleFormatError>
+    + TryFrom<Vec<i16>, Error = SampleFormatError>
     + Into<Vec<i16>>
-    + TryFrom<Vec<i32>, Error = String>
+    + for<'a> TryFrom<&'a [i32], Error = SampleFormatError>
+    + TryFrom<Vec<i32>, Error = SampleFormatError>
     + Into<Vec<i32>>
+    + for<'a> TryFrom<&'a [f32], Error = SampleFormatError>
+    + TryFrom<Vec<f32>, Error = SampleFormatError>
+    + Into<Vec<f32>>
+    + for<'a> TryFrom<&'a [f64], Error = SampleFormatError>
+    + TryFrom<Vec<f64>, Error = SampleFormatError>
+    + Into<Vec<f64>>
 {
+    /// The number of [`Sample`] values held within a given [`SampleFormat`].
+    /// As this is a constant it can be used to size buffers at compile time,
+    /// such as arrays holding the channels of a sample.
+    ///
+    /// [`Sample`]: ../type.Sample.html
+    /// [`SampleFormat`]: trait.SampleFormat.html
+    const CHANNEL_COUNT: usize;
+
+    /// A sample with all channels set to 0.
+    const SILENCE: Self;
+
+    /// A sample with all channels set to 1. Multiplying a sample by this
+    /// leaves it unchanged, making it the neutral per-channel gain.
+    const UNITY: Self;
+
     /// Creates an object from a single monophonic sample.
     fn from_sample(x: Sample) -> Self;
 
     /// Converts the given polyphonic sample to a monophonic sample.
     fn into_sample(self) -> Sample;
 
+    /// Creates an object with every channel set to the given value. Unlike
+    /// [`from_sample`], no panning or gain is applied.
+    ///
+    /// [`from_sample`]: #tymethod.from_sample
+    fn splat(x: Sample) -> Self {
+        let mut s = Self::default();
+        for c in s.as_mut() {
+            *c = x;
+        }
+        s
+    }
+
     /// Returns the number of [`Sample`] values held within a given
     /// [`SampleFormat`]. A common use for this would be for ensuring [`Vec`]s
     /// given to [`try_from`] have the correct size.
     ///
+    /// This is equivalent to [`CHANNEL_COUNT`], which should be preferred.
+    ///
     /// [`Sample`]: ../type.Sample.html
     /// [`SampleFormat`]: trait.SampleFormat.html
     /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
     /// [`try_from`]: https://doc.rust-lang.org/std/convert/trait.TryFrom.html#tymethod.try_from
-    fn num_samples() -> usize;
+    /// [`CHANNEL_COUNT`]: #associatedconstant.CHANNEL_COUNT
+    fn num_samples() -> usize {
+        Self::CHANNEL_COUNT
+    }
+
+    /// Returns the labels of the channels held within a given
+    /// [`SampleFormat`], in the same order as the channels are stored and
+    /// converted to and from [`Vec`]s. This allows mapping the channels to
+    /// hardware outputs without knowledge of the concrete type.
+    ///
+    /// [`SampleFormat`]: trait.SampleFormat.html
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    fn channel_labels() -> &'static [ChannelLabel];
+
+    /// Rearranges the channels of the sample from one layout to another. Both
+    /// layouts must contain exactly the channels of the format.
+    ///
+    /// # Parameters
+    ///
+    /// * `from` - the layout the channels are currently in.
+    /// * `to` - the layout to rearrange the channels into.
+    fn reorder(
+        mut self,
+        from: &ChannelLayout,
+        to: &ChannelLayout,
+    ) -> Result<Self, SampleFormatError>
+    where
+        Self: Sized,
+    {
+        let mut frame: Vec<Sample> = (0..Self::CHANNEL_COUNT).map(|i| self[i]).collect();
+        reorder(from, to, &mut frame)?;
+
+        for (i, s) in frame.into_iter().enumerate() {
+            self[i] = s;
+        }
+
+        Ok(self)
+    }
+
+    /// Creates an object from 16-bit PCM bytes in the given byte order. Any
+    /// data following the first frame is ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `bytes` - the raw data, at least 2 bytes per channel long.
+    /// * `endianness` - the byte order of the data.
+    fn from_i16_bytes(bytes: &[u8], endianness: Endianness) -> Result<Self, SampleFormatError> {
+        let read = |b: &[u8]| sample_from_i16_bytes(b, endianness);
+        let mut s = Self::default();
+        for (i, x) in decode_frame(bytes, Self::CHANNEL_COUNT, 2, read)?.enumerate() {
+            s[i] = x;
+        }
+        Ok(s)
+    }
+
+    /// Converts the sample to 16-bit PCM bytes in the given byte order.
+    ///
+    /// # Parameters
+    ///
+    /// * `endianness` - the byte order of the data.
+    fn into_i16_bytes(self, endianness: Endianness) -> Vec<u8> {
+        (0..Self::CHANNEL_COUNT)
+            .flat_map(|i| sample_to_i16_bytes(self[i], endianness))
+            .collect()
+    }
+
+    /// Creates an object from packed 24-bit PCM bytes in the given byte order,
+    /// where each channel is stored in 3 bytes as is done by 24-bit WAV and
+    /// AIFF files. Any data following the first frame is ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `bytes` - the packed data, at least 3 bytes per channel long.
+    /// * `endianness` - the byte order of the data.
+    fn from_packed_i24(bytes: &[u8], endianness: Endianness) -> Result<Self, SampleFormatError> {
+        let read = |b: &[u8]| sample_from_i24_bytes(b, endianness);
+        let mut s = Self::default();
+        for (i, x) in decode_frame(bytes, Self::CHANNEL_COUNT, 3, read)?.enumerate() {
+            s[i] = x;
+        }
+        Ok(s)
+    }
+
+    /// Converts the sample to packed 24-bit PCM bytes in the given byte order,
+    /// 3 bytes per channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `endianness` - the byte order of the data.
+    fn into_packed_i24(self, endianness: Endianness) -> Vec<u8> {
+        (0..Self::CHANNEL_COUNT)
+            .flat_map(|i| sample_to_i24_bytes(self[i], endianness))
+            .collect()
+    }
+
+    /// Creates an object from 32-bit float PCM bytes in the given byte order.
+    /// Any data following the first frame is ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `bytes` - the raw data, at least 4 bytes per channel long.
+    /// * `endianness` - the byte order of the data.
+    fn from_f32_bytes(bytes: &[u8], endianness: Endianness) -> Result<Self, SampleFormatError> {
+        let read = |b: &[u8]| sample_from_f32_bytes(b, endianness);
+        let mut s = Self::default();
+        for (i, x) in decode_frame(bytes, Self::CHANNEL_COUNT, 4, read)?.enumerate() {
+            s[i] = x;
+        }
+        Ok(s)
+    }
+
+    /// Converts the sample to 32-bit float PCM bytes in the given byte order.
+    ///
+    /// # Parameters
+    ///
+    /// * `endianness` - the byte order of the data.
+    fn into_f32_bytes(self, endianness: Endianness) -> Vec<u8> {
+        (0..Self::CHANNEL_COUNT)
+            .flat_map(|i| sample_to_f32_bytes(self[i], endianness))
+            .collect()
+    }
+
+    /// Returns true if every channel of the sample is within `epsilon` of the
+    /// same channel of `other`. As the result of floating-point processing is
+    /// rarely exact, this should be preferred over `==` when testing output.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the sample to compare against.
+    /// * `epsilon` - the largest allowed absolute difference of a channel.
+    fn approx_eq(&self, other: &Self, epsilon: FastMath) -> bool {
+        (0..Self::CHANNEL_COUNT).all(|i| (self[i].0 - other[i].0).abs() <= epsilon)
+    }
+
+    /// Applies the given function to every channel of the sample, such as for
+    /// waveshaping or clipping.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - the function applied to each channel.
+    fn map(mut self, f: impl Fn(Sample) -> Sample) -> Self {
+        for c in self.as_mut() {
+            *c = f(*c);
+        }
+        self
+    }
+
+    /// Combines each channel of the sample with the same channel of `other`
+    /// using the given function, such as for crossfading or ring modulation.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the sample to combine with this one.
+    /// * `f` - the function combining a channel of each sample.
+    fn zip_with(mut self, other: Self, f: impl Fn(Sample, Sample) -> Sample) -> Self {
+        for (c, o) in self.as_mut().iter_mut().zip(other.as_ref()) {
+            *c = f(*c, *o);
+        }
+        self
+    }
+
+    /// Linearly interpolates between the sample and `other`, such as for
+    /// crossfades or interpolating resamplers.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the sample to interpolate towards.
+    /// * `t` - the position between the samples, where 0 returns this sample
+    /// and 1 returns `other`. Values outside of this range extrapolate.
+    fn lerp(self, other: Self, t: Math) -> Self {
+        let t = t.0;
+        self.zip_with(other, |a, b| {
+            Sample((a.0 as AccurateMath + (b.0 - a.0) as AccurateMath * t) as FastMath)
+        })
+    }
+
+    /// Returns the sample with the absolute value of every channel.
+    fn abs(self) -> Self {
+        self.map(|s| Sample(s.0.abs()))
+    }
+
+    /// Restricts every channel of the sample to the given range.
+    ///
+    /// # Parameters
+    ///
+    /// * `min` - the lowest allowed value of a channel.
+    /// * `max` - the highest allowed value of a channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`, or either is NaN.
+    fn clamp(self, min: Sample, max: Sample) -> Self {
+        self.map(|s| Sample(s.0.clamp(min.0, max.0)))
+    }
+
+    /// Restricts every channel of the sample to the given range. Returns a
+    /// tuple of the restricted sample and the number of channels that were
+    /// outside of the range respectively.
+    ///
+    /// # Parameters
+    ///
+    /// * `min` - the lowest allowed value of a channel.
+    /// * `max` - the highest allowed value of a channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`, or either is NaN.
+    fn clamped(mut self, min: Sample, max: Sample) -> (Self, usize) {
+        let mut clipped = 0;
+        for c in self.as_mut() {
+            let x = c.0.clamp(min.0, max.0);
+            if x != c.0 {
+                clipped += 1;
+            }
+            *c = Sample(x);
+        }
+        (self, clipped)
+    }
+
+    /// Returns the lowest value of all channels of the sample.
+    fn channel_min(&self) -> Sample {
+        Sample(
+            self.as_ref()
+                .iter()
+                .fold(FastMath::INFINITY, |m, s| m.min(s.0)),
+        )
+    }
+
+    /// Returns the highest value of all channels of the sample. For the peak
+    /// level of the sample, use `abs().channel_max()`.
+    fn channel_max(&self) -> Sample {
+        Sample(
+            self.as_ref()
+                .iter()
+                .fold(FastMath::NEG_INFINITY, |m, s| m.max(s.0)),
+        )
+    }
+
+    /// Returns true if every channel of the sample is exactly 0.
+    fn is_silent(&self) -> bool {
+        self.is_silent_within(Sample(0.0))
+    }
+
+    /// Returns true if the magnitude of every channel of the sample is at most
+    /// the given threshold, such as for detecting the end of a reverb tail.
+    ///
+    /// # Parameters
+    ///
+    /// * `threshold` - the largest magnitude considered silent.
+    fn is_silent_within(&self, threshold: Sample) -> bool {
+        self.as_ref().iter().all(|s| s.0.abs() <= threshold.0)
+    }
+
+    /// Returns true if no channel of the sample is NaN or infinite.
+    fn is_finite(&self) -> bool {
+        self.as_ref().iter().all(|s| s.0.is_finite())
+    }
+
+    /// Returns the sample with the given channel silenced, such as for the mute
+    /// button of a monitoring control.
+    ///
+    /// # Parameters
+    ///
+    /// * `channel` - the index of the channel to silence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not less than the number of channels.
+    fn mute(mut self, channel: usize) -> Self {
+        self[channel] = Sample(0.0);
+        self
+    }
+
+    /// Returns the sample with every channel other than the given one
+    /// silenced, such as for the solo button of a monitoring control.
+    ///
+    /// # Parameters
+    ///
+    /// * `channel` - the index of the channel to keep.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is not less than the number of channels.
+    fn solo(self, channel: usize) -> Self {
+        let mut s = Self::SILENCE;
+        s[channel] = self[channel];
+        s
+    }
+
+    /// Silences the given channel of every sample of the track, as done by
+    /// [`mute`].
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to modify.
+    /// * `channel` - the index of the channel to silence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the track isn't empty and the index is not less than the
+    /// number of channels.
+    ///
+    /// [`mute`]: #method.mute
+    fn mute_track(track: &mut [Self], channel: usize) {
+        for s in track.iter_mut() {
+            s[channel] = Sample(0.0);
+        }
+    }
+
+    /// Silences every channel other than the given one of every sample of the
+    /// track, as done by [`solo`].
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to modify.
+    /// * `channel` - the index of the channel to keep.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the track isn't empty and the index is not less than the
+    /// number of channels.
+    ///
+    /// [`solo`]: #method.solo
+    fn solo_track(track: &mut [Self], channel: usize) {
+        for s in track.iter_mut() {
+            let kept = s[channel];
+            for c in s.as_mut() {
+                *c = Sample(0.0);
+            }
+            s[channel] = kept;
+        }
+    }
+
+    /// Returns the sample with the polarity of the given channels inverted,
+    /// such as for null tests or fixing miswired recordings. Channels given
+    /// more than once are only inverted once.
+    ///
+    /// # Parameters
+    ///
+    /// * `channels` - the indices of the channels to invert.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is not less than the number of channels.
+    fn invert_channels(mut self, channels: &[usize]) -> Self {
+        for (i, c) in channels.iter().enumerate() {
+            if !channels[..i].contains(c) {
+                self[*c] = Sample(-self[*c].0);
+            }
+        }
+        self
+    }
+
+    /// Inverts the polarity of the given channels of every sample of the
+    /// track, as done by [`invert_channels`].
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to modify.
+    /// * `channels` - the indices of the channels to invert.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the track isn't empty and any index is not less than the
+    /// number of channels.
+    ///
+    /// [`invert_channels`]: #method.invert_channels
+    fn invert_channels_track(track: &mut [Self], channels: &[usize]) {
+        for s in track.iter_mut() {
+            *s = std::mem::take(s).invert_channels(channels);
+        }
+    }
 }
 
 /// Trait implementing the ability to pan a monophonic sample into a polyphonic
@@ -112,4 +675,171 @@ pub trait SampleFormat:
 pub trait Panner<G>: SampleFormat {
     /// Converts the monophonic sample into a polyphonic sample.
     fn to_sample_format(s: Sample, g: G) -> Self;
+
+    /// Returns the gain applied to each channel when panning with the given
+    /// parameter, without applying it to a sample. The gains can be calculated
+    /// once and reused across a block of samples sharing the same parameter,
+    /// by multiplying them with each sample, e.g. `gains * s`.
+    ///
+    /// # Parameters
+    ///
+    /// * `g` - the panning parameter.
+    fn pan_gains(g: G) -> Self {
+        Self::to_sample_format(Sample(1.0), g)
+    }
+
+    /// Converts the monophonic sample into a polyphonic sample, applying the
+    /// given gain in decibels at the same time. The gain is applied to the
+    /// monophonic sample before it is panned, avoiding a second pass over the
+    /// channels of the panned sample.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the monophonic sample to pan.
+    /// * `g` - the panning parameter.
+    /// * `gain` - the gain in decibels.
+    fn pan_with_gain(s: Sample, g: G, gain: Math) -> Self {
+        let gain = bae_utils::db_to_linear(gain).0;
+
+        Self::to_sample_format(Sample((s.0 as AccurateMath * gain) as FastMath), g)
+    }
+}
+
+/// Object-safe counterpart of [`Panner`] for a panner whose parameter is
+/// fixed, allowing panners of different types to be stored behind a
+/// `Box<dyn DynPanner<F>>` and switched between at runtime. It is implemented
+/// for every panning parameter of `F`, e.g.
+/// `let p: Box<dyn DynPanner<Stereo>> = Box::new((0.5, PanLaw::Linear));`.
+///
+/// [`Panner`]: trait.Panner.html
+pub trait DynPanner<F: SampleFormat> {
+    /// Converts the monophonic sample into a polyphonic sample.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the monophonic sample to pan.
+    fn pan(&self, s: Sample) -> F;
+
+    /// Returns the gain applied to each channel by the panner.
+    fn gains(&self) -> F {
+        self.pan(Sample(1.0))
+    }
+}
+
+impl<F: Panner<G>, G: Copy> DynPanner<F> for G {
+    fn pan(&self, s: Sample) -> F {
+        F::to_sample_format(s, *self)
+    }
+}
+
+/// Trait implementing a balance control for sample formats with channels on
+/// both sides of the listener. Unlike a [`Panner`], which places a monophonic
+/// sample, balance attenuates the channels on one side of an already
+/// polyphonic sample, leaving the channels on the other side and in the
+/// center untouched. The side of each channel is taken from its
+/// [`ChannelLabel`].
+///
+/// [`Panner`]: trait.Panner.html
+/// [`ChannelLabel`]: channel/enum.ChannelLabel.html
+pub trait Balancer: SampleFormat {
+    /// Applies the balance control to the sample. The parameter `b` is a
+    /// floating point value of the range \[-1,1\], where -1 silences the
+    /// right channels, 0 leaves the sample unchanged, and 1 silences the left
+    /// channels. If the given value is not within this range, it is clamped to
+    /// it.
+    ///
+    /// # Parameters
+    ///
+    /// * `b` - the balance control value.
+    fn apply_balance(mut self, b: FastMath) -> Self {
+        let b = b.clamp(-1.0, 1.0);
+        let l = FastMath::min(1.0, 1.0 - b);
+        let r = FastMath::min(1.0, 1.0 + b);
+
+        for (c, label) in self.as_mut().iter_mut().zip(Self::channel_labels()) {
+            if label.is_left() {
+                c.0 *= l;
+            } else if label.is_right() {
+                c.0 *= r;
+            }
+        }
+
+        self
+    }
+}
+
+/// Trait implementing the conversion of a sample from one sample format to
+/// another, such as converting decoded audio into the layout of the output
+/// device. It is implemented for every pair of formats, routing the channels
+/// by their [`ChannelLabel`]s:
+///
+/// * Channels with the same label in both formats are copied unchanged.
+/// * Speakers missing from the target are folded into the nearest speaker on
+/// the same side of the listener at -3 dB. Center speakers are folded into the
+/// nearest center speaker, or split between the nearest left and right pair at
+/// -3 dB each, whichever is closer. A [`Stereo`] sample is then converted to
+/// [`Mono`] as `(L + R) / sqrt(2)`, and 5.1 to stereo as the ITU-R BS.775
+/// downmix without the LFE.
+/// * Mid/side channels are decoded into and encoded from the front pair, in
+/// the same way as the conversions between [`MidSide`] and [`Stereo`].
+/// * LFE, Ambisonic and discrete channels are only copied to channels with the
+/// same label, and are otherwise dropped.
+///
+/// Channels of the target without a source are silent, so converting to a
+/// format with more channels doesn't synthesize any new content.
+///
+/// Formats holding every speaker of a smaller format also implement [`From`]
+/// it, widening the sample with this conversion. Each channel is copied into
+/// the channel with the same label, except for a [`Mono`] sample widened to
+/// [`Stereo`], which is split between the left and right channels at -3 dB
+/// each, as done by [`from_sample`]. The opposite conversions are lossy, and
+/// are only done explicitly with [`convert_format`].
+///
+/// [`ChannelLabel`]: channel/enum.ChannelLabel.html
+/// [`Stereo`]: stereo/struct.Stereo.html
+/// [`Mono`]: mono/struct.Mono.html
+/// [`MidSide`]: mid_side/struct.MidSide.html
+/// [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
+/// [`from_sample`]: trait.SampleFormat.html#tymethod.from_sample
+/// [`convert_format`]: #tymethod.convert_format
+pub trait ConvertFormat<T: SampleFormat>: SampleFormat {
+    /// Converts the sample into the target format.
+    fn convert_format(self) -> T;
+}
+
+impl<F: SampleFormat, T: SampleFormat> ConvertFormat<T> for F {
+    fn convert_format(self) -> T {
+        let to = T::channel_labels();
+        let mut t = T::SILENCE;
+
+        for (s, label) in self.as_ref().iter().zip(F::channel_labels()) {
+            for (i, g) in routing::route(*label, to).iter().flatten() {
+                t[*i].0 += (s.0 as AccurateMath * g) as FastMath;
+            }
+        }
+
+        t
+    }
+}
+
+/// Trait implementing the ability to pan a stereophonic sample into a
+/// polyphonic sample, such as placing stereo material within a surround field.
+/// It is implemented for every format that can pan a monophonic sample to a
+/// [`SphericalPosition`], by panning each channel of the [`Stereo`] sample to
+/// its own position as given by a [`StereoSource`].
+///
+/// [`SphericalPosition`]: spherical_position/struct.SphericalPosition.html
+/// [`Stereo`]: stereo/struct.Stereo.html
+/// [`StereoSource`]: stereo_source/struct.StereoSource.html
+pub trait StereoPanner<G>: SampleFormat {
+    /// Converts the stereophonic sample into a polyphonic sample.
+    fn pan_stereo(s: Stereo, g: G) -> Self;
+}
+
+impl<F: Panner<SphericalPosition>> StereoPanner<StereoSource> for F {
+    fn pan_stereo(s: Stereo, g: StereoSource) -> Self {
+        let (l, r) = g.channel_positions();
+
+        F::to_sample_format(s.left, l) + F::to_sample_format(s.right, r)
+    }
 }
@@ -0,0 +1,249 @@
+//! # Loudness
+//!
+//! Module containing the measurement of loudness in LUFS (loudness units
+//! relative to full scale) as specified by ITU-R BS.1770 and EBU R128.
+
+use super::*;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// Length in seconds of the blocks the loudness is measured over, which
+/// overlap by 75%.
+const SUB_BLOCK: AccurateMath = 0.1;
+
+/// Number of sub-blocks in the window of the momentary loudness.
+const MOMENTARY_BLOCKS: usize = 4;
+
+/// Number of sub-blocks in the window of the short-term loudness.
+const SHORT_TERM_BLOCKS: usize = 30;
+
+/// Loudness in LUFS below which blocks are excluded from the integrated
+/// loudness.
+const ABSOLUTE_GATE: AccurateMath = -70.0;
+
+/// Loudness in LU relative to the absolute-gated loudness below which blocks
+/// are excluded from the integrated loudness.
+const RELATIVE_GATE: AccurateMath = -10.0;
+
+/// Stateful loudness meter measuring the momentary, short-term and integrated
+/// loudness of samples of the format `F` as they are fed to it, one at a time
+/// or in chunks.
+///
+/// Each channel is K-weighted and its mean square is weighted by the position
+/// of its speaker: channels to the sides of the listener, with an azimuth of 60
+/// to 120 degrees and an elevation below 30 degrees, are weighted by 1.41,
+/// LFE channels are excluded, and all other channels are weighted by 1.
+#[derive(Debug, Clone)]
+pub struct LoudnessMeter<F> {
+    coefficients: [[AccurateMath; 5]; 2],
+    state: Vec<[[AccurateMath; 4]; 2]>,
+    weights: Vec<AccurateMath>,
+    sub_block: usize,
+    count: usize,
+    energy: AccurateMath,
+    recent: VecDeque<AccurateMath>,
+    blocks: Vec<AccurateMath>,
+    format: PhantomData<fn(F)>,
+}
+
+impl<F: SampleFormat> LoudnessMeter<F> {
+    /// Creates a new LoudnessMeter for the given sample rate.
+    ///
+    /// # Parameters
+    ///
+    /// * `sample_rate` - the sample rate of the audio being measured in Hz.
+    pub fn new(sample_rate: Math) -> Self {
+        let rate = sample_rate.0;
+
+        LoudnessMeter {
+            coefficients: k_weighting(rate),
+            state: vec![[[0.0; 4]; 2]; F::CHANNEL_COUNT],
+            weights: F::channel_labels().iter().map(|l| weight(*l)).collect(),
+            sub_block: ((SUB_BLOCK * rate).round() as usize).max(1),
+            count: 0,
+            energy: 0.0,
+            recent: VecDeque::with_capacity(SHORT_TERM_BLOCKS),
+            blocks: Vec::new(),
+            format: PhantomData,
+        }
+    }
+
+    /// Measures the next sample.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next sample.
+    pub fn process(&mut self, s: F) {
+        for ((x, state), w) in s
+            .as_ref()
+            .iter()
+            .zip(self.state.iter_mut())
+            .zip(self.weights.iter())
+        {
+            let mut y = x.0 as AccurateMath;
+            for (c, s) in self.coefficients.iter().zip(state.iter_mut()) {
+                // The state holds x[n-1], x[n-2], y[n-1] and y[n-2]
+                let out = c[0] * y + c[1] * s[0] + c[2] * s[1] - c[3] * s[2] - c[4] * s[3];
+                *s = [y, s[0], out, s[2]];
+                y = out;
+            }
+            self.energy += w * y * y;
+        }
+
+        self.count += 1;
+        if self.count == self.sub_block {
+            if self.recent.len() == SHORT_TERM_BLOCKS {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(self.energy);
+            self.count = 0;
+            self.energy = 0.0;
+
+            if let Some(block) = self.mean_square(MOMENTARY_BLOCKS) {
+                self.blocks.push(block);
+            }
+        }
+    }
+
+    /// Measures every sample of the track in order.
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to measure.
+    pub fn process_track(&mut self, track: &[F])
+    where
+        F: Copy,
+    {
+        for s in track {
+            self.process(*s);
+        }
+    }
+
+    /// Returns the momentary loudness in LUFS, measured over the last 400
+    /// milliseconds. Returns negative infinity until 400 milliseconds have been
+    /// measured.
+    pub fn momentary(&self) -> Math {
+        Math(
+            self.mean_square(MOMENTARY_BLOCKS)
+                .map_or(AccurateMath::NEG_INFINITY, loudness),
+        )
+    }
+
+    /// Returns the short-term loudness in LUFS, measured over the last 3
+    /// seconds. Returns negative infinity until 3 seconds have been measured.
+    pub fn short_term(&self) -> Math {
+        Math(
+            self.mean_square(SHORT_TERM_BLOCKS)
+                .map_or(AccurateMath::NEG_INFINITY, loudness),
+        )
+    }
+
+    /// Returns the integrated loudness in LUFS of everything measured since the
+    /// meter was created or reset, gated to exclude silence and quiet passages.
+    /// Returns negative infinity if no 400 millisecond block is loud enough to
+    /// pass the gates.
+    pub fn integrated(&self) -> Math {
+        let gated = |threshold: AccurateMath| {
+            let (sum, n) = self
+                .blocks
+                .iter()
+                .filter(|b| loudness(**b) > threshold)
+                .fold((0.0, 0), |(sum, n), b| (sum + b, n + 1));
+
+            if n == 0 {
+                None
+            } else {
+                Some(sum / n as AccurateMath)
+            }
+        };
+
+        let relative = match gated(ABSOLUTE_GATE) {
+            Some(mean) => loudness(mean) + RELATIVE_GATE,
+            None => return Math(AccurateMath::NEG_INFINITY),
+        };
+
+        Math(gated(relative.max(ABSOLUTE_GATE)).map_or(AccurateMath::NEG_INFINITY, loudness))
+    }
+
+    /// Clears the filters and discards everything measured.
+    pub fn reset(&mut self) {
+        self.state.iter_mut().for_each(|s| *s = [[0.0; 4]; 2]);
+        self.count = 0;
+        self.energy = 0.0;
+        self.recent.clear();
+        self.blocks.clear();
+    }
+
+    /// Returns the weighted mean square over the last `n` sub-blocks, if that
+    /// many have been measured.
+    fn mean_square(&self, n: usize) -> Option<AccurateMath> {
+        if self.recent.len() < n {
+            return None;
+        }
+
+        let sum: AccurateMath = self.recent.iter().rev().take(n).sum();
+        Some(sum / (n * self.sub_block) as AccurateMath)
+    }
+}
+
+/// Returns the integrated loudness in LUFS of the whole track.
+///
+/// # Parameters
+///
+/// * `track` - the samples to measure.
+/// * `sample_rate` - the sample rate of the track in Hz.
+pub fn integrated_loudness<F: SampleFormat + Copy>(track: &[F], sample_rate: Math) -> Math {
+    let mut meter = LoudnessMeter::new(sample_rate);
+    meter.process_track(track);
+    meter.integrated()
+}
+
+/// Converts a weighted mean square to loudness in LUFS.
+fn loudness(mean_square: AccurateMath) -> AccurateMath {
+    -0.691 + 10.0 * mean_square.log10()
+}
+
+/// Returns the weight of the channel with the given label.
+fn weight(label: ChannelLabel) -> AccurateMath {
+    if matches!(label, ChannelLabel::Lfe | ChannelLabel::Lfe2) {
+        return 0.0;
+    }
+
+    match label.position() {
+        Some(p) if p.elevation.abs() < 30.0 && (60.0..=120.0).contains(&p.azimuth.abs()) => 1.41,
+        _ => 1.0,
+    }
+}
+
+/// Returns the coefficients `[b0, b1, b2, a1, a2]` of the high shelf and high
+/// pass stages of the K-weighting filter at the given sample rate, matching
+/// the coefficients given by BS.1770 for 48 kHz.
+fn k_weighting(sample_rate: AccurateMath) -> [[AccurateMath; 5]; 2] {
+    use std::f64::consts::PI;
+
+    let (f0, gain, q) = (1681.974450955533, 3.999843853973347, 0.7071752369554196);
+    let k = (PI * f0 / sample_rate).tan();
+    let vh = (10.0 as AccurateMath).powf(gain / 20.0);
+    let vb = vh.powf(0.4996667741545416);
+    let a0 = 1.0 + k / q + k * k;
+    let shelf = [
+        (vh + vb * k / q + k * k) / a0,
+        2.0 * (k * k - vh) / a0,
+        (vh - vb * k / q + k * k) / a0,
+        2.0 * (k * k - 1.0) / a0,
+        (1.0 - k / q + k * k) / a0,
+    ];
+
+    let (f0, q) = (38.13547087602444, 0.5003270373238773);
+    let k = (PI * f0 / sample_rate).tan();
+    let a0 = 1.0 + k / q + k * k;
+    let high_pass = [
+        1.0,
+        -2.0,
+        1.0,
+        2.0 * (k * k - 1.0) / a0,
+        (1.0 - k / q + k * k) / a0,
+    ];
+
+    [shelf, high_pass]
+}
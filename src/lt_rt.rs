@@ -0,0 +1,197 @@
+//! # Lt/Rt
+//!
+//! Module containing the matrix encoding of surround content into two-channel
+//! Lt/Rt (left total, right total), compatible with Dolby Surround decoders,
+//! and its passive decoding back into surround.
+
+use super::*;
+
+/// Gain of the center channel in each of the Lt and Rt channels.
+const CENTER: AccurateMath = std::f64::consts::FRAC_1_SQRT_2;
+
+/// Gain of each surround channel in the Lt/Rt channel on its own side.
+const SURROUND_NEAR: AccurateMath = 0.8716;
+
+/// Gain of each surround channel in the Lt/Rt channel on the opposite side.
+const SURROUND_FAR: AccurateMath = 0.4898;
+
+/// Coefficients of the all-pass sections of the in-phase and quadrature paths
+/// of the [`HilbertFilter`], designed by Olli Niemitalo.
+///
+/// [`HilbertFilter`]: struct.HilbertFilter.html
+const HILBERT_COEFFICIENTS: [[AccurateMath; 4]; 2] = [
+    [0.6923878, 0.9360654322959, 0.9882295226860, 0.9987488452737],
+    [
+        0.4021921162426,
+        0.8561710882420,
+        0.9722909545651,
+        0.9952884791278,
+    ],
+];
+
+/// Trait implementing a pair of filters whose outputs are 90 degrees apart in
+/// phase, used by the [`LtRtEncoder`] to shift the surround channels relative
+/// to the front channels.
+///
+/// [`LtRtEncoder`]: struct.LtRtEncoder.html
+pub trait QuadratureFilter {
+    /// Processes the next sample of the input. Returns a tuple of the in-phase
+    /// and quadrature outputs respectively, where the quadrature output lags
+    /// the in-phase output by 90 degrees.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - the next sample of the input.
+    fn process(&mut self, x: Sample) -> (Sample, Sample);
+
+    /// Clears the internal state of the filter.
+    fn reset(&mut self);
+}
+
+/// Quadrature filter approximating a Hilbert transformer with two parallel
+/// cascades of all-pass filters. The phase difference of the outputs is within
+/// a degree of 90 from 0.2% to 49.8% of the sample rate, while the magnitude of
+/// both is unity at all frequencies.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct HilbertFilter {
+    state: [[[AccurateMath; 4]; 4]; 2],
+    delayed: AccurateMath,
+}
+
+impl HilbertFilter {
+    /// Processes the input through the all-pass cascade of the given path.
+    fn path(&mut self, path: usize, x: AccurateMath) -> AccurateMath {
+        let mut x = x;
+
+        for (a, s) in HILBERT_COEFFICIENTS[path]
+            .iter()
+            .zip(self.state[path].iter_mut())
+        {
+            // The state holds x[n-1], x[n-2], y[n-1] and y[n-2]
+            let y = a * a * (x + s[3]) - s[1];
+            *s = [x, s[0], y, s[2]];
+            x = y;
+        }
+
+        x
+    }
+}
+
+impl QuadratureFilter for HilbertFilter {
+    fn process(&mut self, x: Sample) -> (Sample, Sample) {
+        let x = x.0 as AccurateMath;
+        let i = self.path(1, x);
+        let q = self.delayed;
+        self.delayed = self.path(0, x);
+
+        (Sample(i as FastMath), Sample(q as FastMath))
+    }
+
+    fn reset(&mut self) {
+        *self = HilbertFilter::default();
+    }
+}
+
+/// Quadrature filter that passes the input to both outputs unchanged,
+/// approximating the phase shift of the surround channels with a plain
+/// polarity inversion. This is cheaper and adds no latency, at the cost of
+/// surround content partly cancelling when the Lt/Rt channels are summed.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct NoPhaseShift;
+
+impl QuadratureFilter for NoPhaseShift {
+    fn process(&mut self, x: Sample) -> (Sample, Sample) {
+        (x, x)
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// Stateful matrix encoder of [`Surround51`] samples into two-channel Lt/Rt
+/// [`Stereo`] samples:
+///
+/// * `Lt = L + 0.707 C - j(0.8716 Ls + 0.4898 Rs)`
+/// * `Rt = R + 0.707 C + j(0.4898 Ls + 0.8716 Rs)`
+///
+/// where `j` is a 90 degree phase shift applied by the [`QuadratureFilter`]
+/// `Q`. The LFE channel is discarded, as it isn't carried by the matrix.
+///
+/// [`Surround51`]: ../surround51/struct.Surround51.html
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+/// [`QuadratureFilter`]: trait.QuadratureFilter.html
+#[derive(Debug, Clone, Default)]
+pub struct LtRtEncoder<Q = HilbertFilter> {
+    filters: [Q; 4],
+}
+
+impl<Q: QuadratureFilter + Clone> LtRtEncoder<Q> {
+    /// Creates a new LtRtEncoder using copies of the given filter to shift the
+    /// phase of the channels.
+    ///
+    /// # Parameters
+    ///
+    /// * `filter` - the filter shifting the surround channels.
+    pub fn new(filter: Q) -> Self {
+        LtRtEncoder {
+            filters: [filter.clone(), filter.clone(), filter.clone(), filter],
+        }
+    }
+
+    /// Encodes the next sample of the surround source.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next surround sample.
+    pub fn process(&mut self, s: Surround51) -> Stereo {
+        let c = CENTER * s.center.0 as AccurateMath;
+        let ls = s.surround_left.0 as AccurateMath;
+        let rs = s.surround_right.0 as AccurateMath;
+        let mut shift = |i: usize, x: AccurateMath| self.filters[i].process(Sample(x as FastMath));
+
+        let (l, _) = shift(0, s.front_left.0 as AccurateMath + c);
+        let (r, _) = shift(1, s.front_right.0 as AccurateMath + c);
+        let (_, sl) = shift(2, SURROUND_NEAR * ls + SURROUND_FAR * rs);
+        let (_, sr) = shift(3, SURROUND_FAR * ls + SURROUND_NEAR * rs);
+
+        Stereo {
+            left: Sample(l.0 - sl.0),
+            right: Sample(r.0 + sr.0),
+        }
+    }
+
+    /// Clears the internal state of the filters.
+    pub fn reset(&mut self) {
+        for f in self.filters.iter_mut() {
+            f.reset();
+        }
+    }
+}
+
+/// Passively decodes an Lt/Rt sample into a [`Surround51`] sample. The front
+/// channels are passed through, the center channel is recovered from the sum of
+/// the Lt/Rt channels and both surround channels from their difference:
+///
+/// * `C = 0.707 (Lt + Rt)`
+/// * `Ls = Rs = 0.707 (Rt - Lt)`
+///
+/// The phase shift of the surround channels isn't undone, and the LFE channel
+/// is left silent.
+///
+/// # Parameters
+///
+/// * `s` - the Lt/Rt sample.
+///
+/// [`Surround51`]: ../surround51/struct.Surround51.html
+pub fn decode_lt_rt(s: Stereo) -> Surround51 {
+    let (lt, rt) = (s.left.0 as AccurateMath, s.right.0 as AccurateMath);
+    let surround = Sample((CENTER * (rt - lt)) as FastMath);
+
+    Surround51 {
+        front_left: s.left,
+        front_right: s.right,
+        center: Sample((CENTER * (lt + rt)) as FastMath),
+        lfe: Sample(0.0),
+        surround_left: surround,
+        surround_right: surround,
+    }
+}
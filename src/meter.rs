@@ -0,0 +1,454 @@
+//! # Meter
+//!
+//! Module containing meters measuring the level of each channel, and the
+//! correlation between channels, of tracks or streams of samples, such as for
+//! display in a user interface.
+
+use super::*;
+
+/// Coefficients of the phases of the 4 times oversampling interpolation filter
+/// of the [`TruePeakMeter`], as given by ITU-R BS.1770.
+///
+/// [`TruePeakMeter`]: struct.TruePeakMeter.html
+const TRUE_PEAK_COEFFICIENTS: [[AccurateMath; 12]; 4] = [
+    [
+        0.0017089843750,
+        0.0109863281250,
+        -0.0196533203125,
+        0.0332031250000,
+        -0.0594482421875,
+        0.1373291015625,
+        0.9721679687500,
+        -0.1022949218750,
+        0.0476074218750,
+        -0.0266113281250,
+        0.0148925781250,
+        -0.0083007812500,
+    ],
+    [
+        -0.0291748046875,
+        0.0292968750000,
+        -0.0517578125000,
+        0.0891113281250,
+        -0.1665039062500,
+        0.4650878906250,
+        0.7797851562500,
+        -0.2003173828125,
+        0.1015625000000,
+        -0.0582275390625,
+        0.0330810546875,
+        -0.0189208984375,
+    ],
+    [
+        -0.0189208984375,
+        0.0330810546875,
+        -0.0582275390625,
+        0.1015625000000,
+        -0.2003173828125,
+        0.7797851562500,
+        0.4650878906250,
+        -0.1665039062500,
+        0.0891113281250,
+        -0.0517578125000,
+        0.0292968750000,
+        -0.0291748046875,
+    ],
+    [
+        -0.0083007812500,
+        0.0148925781250,
+        -0.0266113281250,
+        0.0476074218750,
+        -0.1022949218750,
+        0.9721679687500,
+        0.1373291015625,
+        -0.0594482421875,
+        0.0332031250000,
+        -0.0196533203125,
+        0.0109863281250,
+        0.0017089843750,
+    ],
+];
+
+/// Returns the absolute peak of each channel over the track. An empty track
+/// returns silence.
+///
+/// # Parameters
+///
+/// * `track` - the samples to measure.
+pub fn peak<F: SampleFormat + Copy>(track: &[F]) -> F {
+    track.iter().fold(F::SILENCE, |p, s| {
+        p.zip_with(s.abs(), |a, b| Sample(a.0.max(b.0)))
+    })
+}
+
+/// Stateful peak meter measuring the absolute peak of each channel as samples
+/// are fed to it, one at a time or in chunks.
+///
+/// Each new peak is held for the hold time, after which the level falls at the
+/// decay rate until a higher peak is reached, as done by the meters of mixing
+/// consoles.
+#[derive(Debug, Clone)]
+pub struct PeakMeter<F> {
+    level: F,
+    hold: usize,
+    held: Vec<usize>,
+    decay: FastMath,
+}
+
+impl<F: SampleFormat + Copy> PeakMeter<F> {
+    /// Creates a new PeakMeter for the given sample rate.
+    ///
+    /// # Parameters
+    ///
+    /// * `sample_rate` - the sample rate of the audio being measured in Hz.
+    /// * `hold` - the time a peak is held for in seconds.
+    /// * `decay` - the rate the level falls at after the hold time in decibels
+    /// per second. An infinite rate drops the level to the following samples
+    /// immediately.
+    pub fn new(sample_rate: Math, hold: Math, decay: Math) -> Self {
+        PeakMeter {
+            level: F::SILENCE,
+            hold: (hold.0 * sample_rate.0).round() as usize,
+            held: vec![0; F::CHANNEL_COUNT],
+            decay: bae_utils::db_to_linear(Math(-decay.0 / sample_rate.0)).0 as FastMath,
+        }
+    }
+
+    /// Measures the next sample. Returns the level of each channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next sample.
+    pub fn process(&mut self, s: F) -> F {
+        for ((l, x), h) in self
+            .level
+            .as_mut()
+            .iter_mut()
+            .zip(s.as_ref())
+            .zip(self.held.iter_mut())
+        {
+            let x = x.0.abs();
+            if x >= l.0 {
+                *l = Sample(x);
+                *h = 0;
+            } else if *h < self.hold {
+                *h += 1;
+            } else {
+                *l = Sample((l.0 * self.decay).max(x));
+            }
+        }
+
+        self.level
+    }
+
+    /// Measures every sample of the track in order. Returns the level of each
+    /// channel after the last sample.
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to measure.
+    pub fn process_track(&mut self, track: &[F]) -> F {
+        for s in track {
+            self.process(*s);
+        }
+
+        self.level
+    }
+
+    /// Returns the current level of each channel.
+    pub fn level(&self) -> F {
+        self.level
+    }
+
+    /// Drops the level of every channel to silence.
+    pub fn reset(&mut self) {
+        self.level = F::SILENCE;
+        self.held.iter_mut().for_each(|h| *h = 0);
+    }
+}
+
+/// Stateful meter measuring the RMS (root mean square) level of each channel
+/// over a sliding window of the most recent samples, as samples are fed to it
+/// one at a time or in chunks.
+///
+/// Until the window is full, the level is measured over the samples fed so
+/// far.
+#[derive(Debug, Clone)]
+pub struct RmsMeter<F> {
+    squares: Vec<F>,
+    sums: Vec<AccurateMath>,
+    index: usize,
+    filled: usize,
+}
+
+impl<F: SampleFormat + Copy> RmsMeter<F> {
+    /// Creates a new RmsMeter for the given sample rate.
+    ///
+    /// # Parameters
+    ///
+    /// * `sample_rate` - the sample rate of the audio being measured in Hz.
+    /// * `window` - the length of the window in seconds, rounded to a whole
+    /// number of samples of at least 1.
+    pub fn new(sample_rate: Math, window: Math) -> Self {
+        let len = ((window.0 * sample_rate.0).round() as usize).max(1);
+
+        RmsMeter {
+            squares: vec![F::SILENCE; len],
+            sums: vec![0.0; F::CHANNEL_COUNT],
+            index: 0,
+            filled: 0,
+        }
+    }
+
+    /// Measures the next sample. Returns the RMS level of each channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next sample.
+    pub fn process(&mut self, s: F) -> F {
+        let square = s.map(|x| Sample(x.0 * x.0));
+        let old = std::mem::replace(&mut self.squares[self.index], square);
+
+        for ((sum, n), o) in self.sums.iter_mut().zip(square.as_ref()).zip(old.as_ref()) {
+            *sum += n.0 as AccurateMath - o.0 as AccurateMath;
+        }
+
+        self.index = (self.index + 1) % self.squares.len();
+        self.filled = (self.filled + 1).min(self.squares.len());
+
+        self.level()
+    }
+
+    /// Measures every sample of the track in order. Returns the RMS level of
+    /// each channel after the last sample.
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to measure.
+    pub fn process_track(&mut self, track: &[F]) -> F {
+        for s in track {
+            self.process(*s);
+        }
+
+        self.level()
+    }
+
+    /// Returns the current RMS level of each channel.
+    pub fn level(&self) -> F {
+        let mut level = F::SILENCE;
+        if self.filled == 0 {
+            return level;
+        }
+
+        for (l, sum) in level.as_mut().iter_mut().zip(self.sums.iter()) {
+            // The running sum can drift slightly below zero from rounding.
+            *l = Sample((sum.max(0.0) / self.filled as AccurateMath).sqrt() as FastMath);
+        }
+
+        level
+    }
+
+    /// Empties the window.
+    pub fn reset(&mut self) {
+        self.squares.iter_mut().for_each(|s| *s = F::SILENCE);
+        self.sums.iter_mut().for_each(|s| *s = 0.0);
+        self.index = 0;
+        self.filled = 0;
+    }
+}
+
+/// Returns the true peak of each channel over the track, measured by a
+/// [`TruePeakMeter`]. An empty track returns silence.
+///
+/// # Parameters
+///
+/// * `track` - the samples to measure.
+///
+/// [`TruePeakMeter`]: struct.TruePeakMeter.html
+pub fn true_peak<F: SampleFormat + Copy>(track: &[F]) -> F {
+    let mut meter = TruePeakMeter::new();
+    meter.process_track(track);
+    meter.flush()
+}
+
+/// Stateful meter measuring the true peak of each channel, the highest
+/// absolute value of the continuous signal the samples represent, as samples
+/// are fed to it one at a time or in chunks.
+///
+/// The samples are oversampled 4 times as specified by ITU-R BS.1770, so that
+/// peaks falling between samples, which can clip once the audio is converted
+/// to analog or encoded by a lossy codec, are detected. The level is the
+/// highest peak measured since the meter was created or reset, and is never
+/// lower than the sample peak.
+#[derive(Debug, Clone)]
+pub struct TruePeakMeter<F> {
+    level: F,
+    history: [F; 12],
+    index: usize,
+}
+
+impl<F: SampleFormat + Copy> TruePeakMeter<F> {
+    /// Creates a new TruePeakMeter.
+    pub fn new() -> Self {
+        TruePeakMeter {
+            level: F::SILENCE,
+            history: [F::SILENCE; 12],
+            index: 0,
+        }
+    }
+
+    /// Measures the next sample. Returns the true peak of each channel.
+    ///
+    /// The interpolation filter delays the oversampled signal by 6 samples, so
+    /// peaks between the last few samples fed are only measured once later
+    /// samples or a [`flush`] follow them.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next sample.
+    ///
+    /// [`flush`]: #method.flush
+    pub fn process(&mut self, s: F) -> F {
+        self.history[self.index] = s;
+        self.index = (self.index + 1) % self.history.len();
+
+        for c in 0..F::CHANNEL_COUNT {
+            let mut peak = s[c].0.abs() as AccurateMath;
+            for phase in TRUE_PEAK_COEFFICIENTS.iter() {
+                let y: AccurateMath = phase
+                    .iter()
+                    .enumerate()
+                    .map(|(k, h)| {
+                        let i = (self.index + self.history.len() - 1 - k) % self.history.len();
+                        h * self.history[i][c].0 as AccurateMath
+                    })
+                    .sum();
+                peak = peak.max(y.abs());
+            }
+            self.level[c] = Sample(self.level[c].0.max(peak as FastMath));
+        }
+
+        self.level
+    }
+
+    /// Measures every sample of the track in order. Returns the true peak of
+    /// each channel after the last sample.
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to measure.
+    pub fn process_track(&mut self, track: &[F]) -> F {
+        for s in track {
+            self.process(*s);
+        }
+
+        self.level
+    }
+
+    /// Feeds silence through the interpolation filter so that the peaks
+    /// between the last samples fed are measured, as at the end of a track.
+    /// Returns the true peak of each channel.
+    pub fn flush(&mut self) -> F {
+        for _ in 0..self.history.len() {
+            self.process(F::SILENCE);
+        }
+
+        self.level
+    }
+
+    /// Returns the current true peak of each channel.
+    pub fn level(&self) -> F {
+        self.level
+    }
+
+    /// Drops the level of every channel to silence and clears the filter.
+    pub fn reset(&mut self) {
+        *self = TruePeakMeter::new();
+    }
+}
+
+impl<F: SampleFormat + Copy> Default for TruePeakMeter<F> {
+    fn default() -> Self {
+        TruePeakMeter::new()
+    }
+}
+
+/// Stateful meter measuring the phase correlation of the left and right
+/// channels of [`Stereo`] samples as they are fed to it, one at a time or in
+/// chunks, as used to check the mono compatibility of a mix.
+///
+/// The correlation ranges from -1, where the channels cancel out when summed to
+/// mono, through 0 for unrelated channels to 1 for identical channels. It is
+/// averaged exponentially over the integration time, so that it follows the
+/// audio as it changes. To measure a whole track at once, use
+/// [`Stereo::correlation`].
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+/// [`Stereo::correlation`]: ../stereo/struct.Stereo.html#method.correlation
+#[derive(Debug, Clone)]
+pub struct CorrelationMeter {
+    coefficient: AccurateMath,
+    sums: [AccurateMath; 3],
+}
+
+impl CorrelationMeter {
+    /// Creates a new CorrelationMeter for the given sample rate.
+    ///
+    /// # Parameters
+    ///
+    /// * `sample_rate` - the sample rate of the audio being measured in Hz.
+    /// * `time` - the integration time in seconds, after which the effect of a
+    /// sample has fallen to 37%.
+    pub fn new(sample_rate: Math, time: Math) -> Self {
+        CorrelationMeter {
+            coefficient: 1.0 - (-1.0 / (time.0 * sample_rate.0)).exp(),
+            sums: [0.0; 3],
+        }
+    }
+
+    /// Measures the next sample. Returns the current correlation.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next sample.
+    pub fn process(&mut self, s: Stereo) -> Math {
+        let (l, r) = (s.left.0 as AccurateMath, s.right.0 as AccurateMath);
+
+        for (sum, x) in self.sums.iter_mut().zip([l * r, l * l, r * r]) {
+            *sum += self.coefficient * (x - *sum);
+        }
+
+        self.correlation()
+    }
+
+    /// Measures every sample of the track in order. Returns the correlation
+    /// after the last sample.
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to measure.
+    pub fn process_track(&mut self, track: &[Stereo]) -> Math {
+        for s in track {
+            self.process(*s);
+        }
+
+        self.correlation()
+    }
+
+    /// Returns the current correlation. Returns 0 while either channel is
+    /// silent.
+    pub fn correlation(&self) -> Math {
+        let [lr, ll, rr] = self.sums;
+
+        if ll == 0.0 || rr == 0.0 {
+            Math(0.0)
+        } else {
+            Math((lr / (ll * rr).sqrt()).clamp(-1.0, 1.0))
+        }
+    }
+
+    /// Discards everything measured.
+    pub fn reset(&mut self) {
+        self.sums = [0.0; 3];
+    }
+}
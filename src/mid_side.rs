@@ -0,0 +1,506 @@
+//! # Mid/Side
+//!
+//! Module containing type for handling mid/side encoded stereo audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`MidSide`] samples
+///
+/// [`MidSide`]: struct.MidSide.html
+pub type MidSideTrackT = Vec<MidSide>;
+
+/// Struct representing a mid/side encoded stereo audio sample.
+///
+/// Conversions to and from [`Stereo`] are lossless, aside from floating point
+/// rounding.
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct MidSide {
+    /// Mid (sum) sample value.
+    pub mid: Sample,
+    /// Side (difference) sample value.
+    pub side: Sample,
+}
+
+impl MidSide {
+    /// Returns a new MidSide object with default <0,0> values.
+    pub const fn new() -> Self {
+        MidSide::SILENCE
+    }
+
+    /// Returns a new MidSide object created from individual mid and side audio
+    /// samples.
+    ///
+    /// # Parameters
+    ///
+    /// * `m` - the mid audio sample.
+    /// * `s` - the side audio sample.
+    pub const fn from(m: Sample, s: Sample) -> Self {
+        MidSide { mid: m, side: s }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 2] {
+        let s: [Sample; 2] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new MidSide object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 2]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+}
+
+impl SampleFormat for MidSide {
+    const CHANNEL_COUNT: usize = 2;
+
+    const SILENCE: Self = MidSide {
+        mid: Sample(0.0),
+        side: Sample(0.0),
+    };
+    const UNITY: Self = MidSide {
+        mid: Sample(1.0),
+        side: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        MidSide {
+            mid: x,
+            side: Sample(0.0),
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        self.mid
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[ChannelLabel::Mid, ChannelLabel::Side]
+    }
+}
+
+/// Encodes a stereo sample into mid/side, where the mid channel is half the
+/// sum of the left and right channels, and the side channel is half the
+/// difference.
+impl From<Stereo> for MidSide {
+    fn from(s: Stereo) -> Self {
+        MidSide {
+            mid: Sample((s.left.0 + s.right.0) * 0.5),
+            side: Sample((s.left.0 - s.right.0) * 0.5),
+        }
+    }
+}
+/// Decodes a mid/side sample into stereo, where the left channel is the sum of
+/// the mid and side channels, and the right channel is the difference.
+impl From<MidSide> for Stereo {
+    fn from(ms: MidSide) -> Self {
+        Stereo {
+            left: Sample(ms.mid.0 + ms.side.0),
+            right: Sample(ms.mid.0 - ms.side.0),
+        }
+    }
+}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for MidSide {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.mid,
+            1 => &self.side,
+            _ => panic!(
+                "ERROR: Index {} is out of range for MidSide with 2 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for MidSide {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.mid,
+            1 => &mut self.side,
+            _ => panic!(
+                "ERROR: Index {} is out of range for MidSide with 2 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for MidSide {
+    fn as_ref(&self) -> &[Sample] {
+        // MidSide is #[repr(C)] and made up solely of 2 Sample fields.
+        unsafe { channel::as_slice(self, 2) }
+    }
+}
+impl AsMut<[Sample]> for MidSide {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // MidSide is #[repr(C)] and made up solely of 2 Sample fields.
+        unsafe { channel::as_mut_slice(self, 2) }
+    }
+}
+
+impl std::ops::Neg for MidSide {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        MidSide {
+            mid: Sample(-self.mid.0),
+            side: Sample(-self.side.0),
+        }
+    }
+}
+
+impl std::ops::Add<MidSide> for MidSide {
+    type Output = Self;
+
+    fn add(self, rhs: MidSide) -> Self::Output {
+        MidSide {
+            mid: Sample(self.mid.0 + rhs.mid.0),
+            side: Sample(self.side.0 + rhs.side.0),
+        }
+    }
+}
+impl std::ops::AddAssign<MidSide> for MidSide {
+    fn add_assign(&mut self, rhs: MidSide) {
+        self.mid.0 += rhs.mid.0;
+        self.side.0 += rhs.side.0;
+    }
+}
+
+impl std::ops::Sub<MidSide> for MidSide {
+    type Output = Self;
+
+    fn sub(self, rhs: MidSide) -> Self {
+        MidSide {
+            mid: Sample(self.mid.0 - rhs.mid.0),
+            side: Sample(self.side.0 - rhs.side.0),
+        }
+    }
+}
+impl std::ops::SubAssign<MidSide> for MidSide {
+    fn sub_assign(&mut self, rhs: MidSide) {
+        self.mid.0 -= rhs.mid.0;
+        self.side.0 -= rhs.side.0;
+    }
+}
+
+impl std::ops::Mul<MidSide> for MidSide {
+    type Output = MidSide;
+
+    fn mul(self, rhs: MidSide) -> Self::Output {
+        MidSide {
+            mid: Sample(self.mid.0 * rhs.mid.0),
+            side: Sample(self.side.0 * rhs.side.0),
+        }
+    }
+}
+impl std::ops::MulAssign<MidSide> for MidSide {
+    fn mul_assign(&mut self, rhs: MidSide) {
+        self.mid.0 *= rhs.mid.0;
+        self.side.0 *= rhs.side.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for MidSide {
+    type Output = MidSide;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        MidSide {
+            mid: Sample(self.mid.0 * rhs.0),
+            side: Sample(self.side.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for MidSide {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.mid.0 *= rhs.0;
+        self.side.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for MidSide {
+    type Output = MidSide;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        MidSide {
+            mid: Sample((self.mid.0 as AccurateMath * rhs.0) as FastMath),
+            side: Sample((self.side.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for MidSide {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.mid.0 *= rhs.0 as FastMath;
+        self.side.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for MidSide {
+    type Output = MidSide;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for MidSide {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for MidSide {
+    type Output = MidSide;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for MidSide {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 2]> for MidSide {
+    type Output = MidSide;
+
+    fn mul(mut self, rhs: [Sample; 2]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 2]> for MidSide {
+    fn mul_assign(&mut self, rhs: [Sample; 2]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for MidSide {
+    fn sum<I: Iterator<Item = MidSide>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a MidSide> for MidSide {
+    fn sum<I: Iterator<Item = &'a MidSide>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for MidSide {
+    fn product<I: Iterator<Item = MidSide>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a MidSide> for MidSide {
+    fn product<I: Iterator<Item = &'a MidSide>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for MidSide {
+    fn from(s: Sample) -> Self {
+        MidSide::from_sample(s)
+    }
+}
+impl Into<Sample> for MidSide {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 2]> for MidSide {
+    fn from(a: [Sample; 2]) -> Self {
+        MidSide {
+            mid: a[0],
+            side: a[1],
+        }
+    }
+}
+impl Into<[Sample; 2]> for MidSide {
+    fn into(self) -> [Sample; 2] {
+        [self.mid, self.side]
+    }
+}
+
+impl From<(Sample, Sample)> for MidSide {
+    fn from(t: (Sample, Sample)) -> Self {
+        MidSide {
+            mid: t.0,
+            side: t.1,
+        }
+    }
+}
+impl Into<(Sample, Sample)> for MidSide {
+    fn into(self) -> (Sample, Sample) {
+        (self.mid, self.side)
+    }
+}
+
+impl TryFrom<&[u8]> for MidSide {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(MidSide {
+                mid: sample_from_u8(v[0]),
+                side: sample_from_u8(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for MidSide {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for MidSide {
+    fn into(self) -> Vec<u8> {
+        vec![sample_to_u8(self.mid), sample_to_u8(self.side)]
+    }
+}
+
+impl TryFrom<&[i16]> for MidSide {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(MidSide {
+                mid: sample_from_i16(v[0]),
+                side: sample_from_i16(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for MidSide {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for MidSide {
+    fn into(self) -> Vec<i16> {
+        vec![sample_to_i16(self.mid), sample_to_i16(self.side)]
+    }
+}
+
+impl TryFrom<&[i32]> for MidSide {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(MidSide {
+                mid: sample_from_i24(v[0]),
+                side: sample_from_i24(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for MidSide {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for MidSide {
+    fn into(self) -> Vec<i32> {
+        vec![sample_to_i24(self.mid), sample_to_i24(self.side)]
+    }
+}
+
+impl TryFrom<&[f32]> for MidSide {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(MidSide {
+                mid: sample_from_f32(v[0]),
+                side: sample_from_f32(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for MidSide {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for MidSide {
+    fn into(self) -> Vec<f32> {
+        vec![sample_to_f32(self.mid), sample_to_f32(self.side)]
+    }
+}
+
+impl TryFrom<&[f64]> for MidSide {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(MidSide {
+                mid: sample_from_f64(v[0]),
+                side: sample_from_f64(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for MidSide {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for MidSide {
+    fn into(self) -> Vec<f64> {
+        vec![sample_to_f64(self.mid), sample_to_f64(self.side)]
+    }
+}
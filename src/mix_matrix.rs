@@ -0,0 +1,175 @@
+//! # Mix Matrix
+//!
+//! Module containing a matrix of gains remixing the channels of one sample
+//! format into the channels of another.
+
+use super::*;
+
+/// Struct holding the linear gain from each of `IN` input channels to each of
+/// `OUT` output channels, expressing an arbitrary remix of the channels such
+/// as a downmix, a rotation or a re-route. Each output channel is the sum of
+/// the input channels multiplied by their gains:
+///
+/// `output[o] = gains[o][0] * input[0] + ... + gains[o][IN-1] * input[IN-1]`
+///
+/// Channels are indexed in the same order as the [`Index`] impls of the sample
+/// formats.
+///
+/// [`Index`]: https://doc.rust-lang.org/std/ops/trait.Index.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MixMatrix<const IN: usize, const OUT: usize> {
+    gains: [[AccurateMath; IN]; OUT],
+}
+
+impl<const IN: usize, const OUT: usize> MixMatrix<IN, OUT> {
+    /// Creates a new MixMatrix from the gains of each output channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `gains` - the gains of each input channel, for each output channel.
+    pub const fn new(gains: [[AccurateMath; IN]; OUT]) -> Self {
+        MixMatrix { gains }
+    }
+
+    /// Creates a MixMatrix routing each input channel to the output channel
+    /// with the same index at unity gain. Channels without a counterpart are
+    /// dropped or left silent.
+    pub fn identity() -> Self {
+        let mut gains = [[0.0; IN]; OUT];
+        for (i, row) in gains.iter_mut().enumerate().take(IN) {
+            row[i] = 1.0;
+        }
+
+        MixMatrix { gains }
+    }
+
+    /// Creates a MixMatrix performing the same conversion between the formats
+    /// `F` and `G` as [`ConvertFormat`], as a starting point for a custom
+    /// remix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F` doesn't have `IN` channels, or `G` doesn't have `OUT`
+    /// channels.
+    ///
+    /// [`ConvertFormat`]: ../trait.ConvertFormat.html
+    pub fn conversion<F: SampleFormat, G: SampleFormat>() -> Self {
+        MixMatrix::<IN, OUT>::check::<F, G>();
+
+        let mut gains = [[0.0; IN]; OUT];
+        for (i, label) in F::channel_labels().iter().enumerate() {
+            for (o, g) in routing::route(*label, G::channel_labels()).iter().flatten() {
+                gains[*o][i] += g;
+            }
+        }
+
+        MixMatrix { gains }
+    }
+
+    /// Returns the gains of each output channel.
+    pub fn gains(&self) -> &[[AccurateMath; IN]; OUT] {
+        &self.gains
+    }
+
+    /// Returns the gain from the given input channel to the given output
+    /// channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `output` - the index of the output channel.
+    /// * `input` - the index of the input channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of range.
+    pub fn gain(&self, output: usize, input: usize) -> AccurateMath {
+        self.gains[output][input]
+    }
+
+    /// Sets the gain from the given input channel to the given output channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `output` - the index of the output channel.
+    /// * `input` - the index of the input channel.
+    /// * `gain` - the linear gain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of range.
+    pub fn set_gain(&mut self, output: usize, input: usize, gain: AccurateMath) {
+        self.gains[output][input] = gain;
+    }
+
+    /// Returns the matrix applying this matrix followed by `next`.
+    ///
+    /// # Parameters
+    ///
+    /// * `next` - the matrix applied to the output of this matrix.
+    pub fn then<const N: usize>(&self, next: &MixMatrix<OUT, N>) -> MixMatrix<IN, N> {
+        let mut gains = [[0.0; IN]; N];
+        for (row, next_row) in gains.iter_mut().zip(next.gains.iter()) {
+            for (g, self_row) in next_row.iter().zip(self.gains.iter()) {
+                for (r, s) in row.iter_mut().zip(self_row.iter()) {
+                    *r += g * s;
+                }
+            }
+        }
+
+        MixMatrix { gains }
+    }
+
+    /// Remixes the channels of the given sample.
+    ///
+    /// # Parameters
+    ///
+    /// * `input` - the sample to remix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F` doesn't have `IN` channels, or `G` doesn't have `OUT`
+    /// channels.
+    pub fn apply<F: SampleFormat, G: SampleFormat>(&self, input: F) -> G {
+        MixMatrix::<IN, OUT>::check::<F, G>();
+
+        let mut output = G::SILENCE;
+        for (o, row) in output.as_mut().iter_mut().zip(self.gains.iter()) {
+            let sum: AccurateMath = row
+                .iter()
+                .zip(input.as_ref())
+                .map(|(g, s)| g * s.0 as AccurateMath)
+                .sum();
+            *o = Sample(sum as FastMath);
+        }
+
+        output
+    }
+
+    /// Remixes the channels of every sample of the given track.
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to remix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F` doesn't have `IN` channels, or `G` doesn't have `OUT`
+    /// channels.
+    pub fn apply_track<F: SampleFormat + Copy, G: SampleFormat>(&self, track: &[F]) -> Vec<G> {
+        track.iter().map(|s| self.apply(*s)).collect()
+    }
+
+    /// Checks that the formats have the number of channels of the matrix.
+    fn check<F: SampleFormat, G: SampleFormat>() {
+        assert_eq!(
+            F::CHANNEL_COUNT,
+            IN,
+            "input format has the wrong channel count"
+        );
+        assert_eq!(
+            G::CHANNEL_COUNT,
+            OUT,
+            "output format has the wrong channel count"
+        );
+    }
+}
@@ -20,17 +20,65 @@ pub struct Mono {
 
 impl Mono {
     /// Creates a new Mono object with a default value of 0.
-    pub fn new() -> Self {
-        Mono::default()
+    pub const fn new() -> Self {
+        Mono::SILENCE
     }
 
     /// Creates a new Mono object from the given sample value.
-    pub fn from(s: Sample) -> Self {
+    pub const fn from(s: Sample) -> Self {
         Mono { mono: s }
     }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 1] {
+        let s: [Sample; 1] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Mono object with each channel set to the positive linear
+    /// value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 1]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Pans a given sample as it would be panned into the sample format `F`,
+    /// returning a Mono sample with the same energy as the panned sample.
+    /// Unlike the [`Panner`] impls of Mono, which ignore the panning parameter,
+    /// this applies the attenuation of the pan law of `F`, so that a mono
+    /// render of a panned mix matches the loudness of the render in `F`, e.g.
+    /// `Mono::pan_attenuated::<Stereo, _>(s, (0.0, PanLaw::Linear))`.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the monophonic sample to pan.
+    /// * `g` - the panning parameter of `F`.
+    ///
+    /// [`Panner`]: ../trait.Panner.html
+    pub fn pan_attenuated<F: Panner<G>, G>(s: Sample, g: G) -> Self {
+        let gains = F::pan_gains(g);
+        let power: AccurateMath = gains
+            .as_ref()
+            .iter()
+            .map(|c| c.0 as AccurateMath * c.0 as AccurateMath)
+            .sum();
+
+        Mono {
+            mono: Sample((s.0 as AccurateMath * power.sqrt()) as FastMath),
+        }
+    }
 }
 
 impl SampleFormat for Mono {
+    const CHANNEL_COUNT: usize = 1;
+
+    const SILENCE: Self = Mono { mono: Sample(0.0) };
+    const UNITY: Self = Mono { mono: Sample(1.0) };
+
     fn from_sample(x: Sample) -> Self {
         Mono { mono: x }
     }
@@ -39,8 +87,8 @@ impl SampleFormat for Mono {
         self.mono
     }
 
-    fn num_samples() -> usize {
-        1
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[ChannelLabel::Center]
     }
 }
 
@@ -52,6 +100,44 @@ impl<T> Panner<T> for Mono {
 
 use std::ops::*;
 
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl Index<usize> for Mono {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.mono,
+            _ => panic!("ERROR: Index {} is out of range for Mono with 1 channel.", i),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl IndexMut<usize> for Mono {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.mono,
+            _ => panic!("ERROR: Index {} is out of range for Mono with 1 channel.", i),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Mono {
+    fn as_ref(&self) -> &[Sample] {
+        // Mono is #[repr(C)] and made up solely of 1 Sample fields.
+        unsafe { channel::as_slice(self, 1) }
+    }
+}
+impl AsMut<[Sample]> for Mono {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Mono is #[repr(C)] and made up solely of 1 Sample fields.
+        unsafe { channel::as_mut_slice(self, 1) }
+    }
+}
+
 impl Neg for Mono {
     type Output = Self;
 
@@ -135,6 +221,70 @@ impl MulAssign<Math> for Mono {
     }
 }
 
+impl Mul<f32> for Mono {
+    type Output = Mono;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl MulAssign<f32> for Mono {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl Mul<f64> for Mono {
+    type Output = Mono;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl MulAssign<f64> for Mono {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl Mul<[Sample; 1]> for Mono {
+    type Output = Mono;
+
+    fn mul(mut self, rhs: [Sample; 1]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl MulAssign<[Sample; 1]> for Mono {
+    fn mul_assign(&mut self, rhs: [Sample; 1]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Mono {
+    fn sum<I: Iterator<Item = Mono>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Mono> for Mono {
+    fn sum<I: Iterator<Item = &'a Mono>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Mono {
+    fn product<I: Iterator<Item = Mono>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Mono> for Mono {
+    fn product<I: Iterator<Item = &'a Mono>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
 impl From<Sample> for Mono {
     fn from(s: Sample) -> Self {
         Mono::from_sample(s)
@@ -146,15 +296,26 @@ impl Into<Sample> for Mono {
     }
 }
 
-impl TryFrom<Vec<u8>> for Mono {
-    type Error = String;
+impl From<[Sample; 1]> for Mono {
+    fn from(a: [Sample; 1]) -> Self {
+        Mono { mono: a[0] }
+    }
+}
+impl Into<[Sample; 1]> for Mono {
+    fn into(self) -> [Sample; 1] {
+        [self.mono]
+    }
+}
 
-    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+impl TryFrom<&[u8]> for Mono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
         if v.len() < 1 {
-            Err(format!(
-                "ERROR: Given vector was length {}. This function requires length 1.",
-                v.len()
-            ))
+            Err(SampleFormatError::WrongLength {
+                expected: 1,
+                actual: v.len(),
+            })
         } else {
             Ok(Mono {
                 mono: sample_from_u8(v[0]),
@@ -162,21 +323,28 @@ impl TryFrom<Vec<u8>> for Mono {
         }
     }
 }
+impl TryFrom<Vec<u8>> for Mono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
 impl Into<Vec<u8>> for Mono {
     fn into(self) -> Vec<u8> {
         vec![sample_to_u8(self.mono)]
     }
 }
 
-impl TryFrom<Vec<i16>> for Mono {
-    type Error = String;
+impl TryFrom<&[i16]> for Mono {
+    type Error = SampleFormatError;
 
-    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
         if v.len() < 1 {
-            Err(format!(
-                "ERROR: Given vector was length {}. This function requires length 1.",
-                v.len()
-            ))
+            Err(SampleFormatError::WrongLength {
+                expected: 1,
+                actual: v.len(),
+            })
         } else {
             Ok(Mono {
                 mono: sample_from_i16(v[0]),
@@ -184,21 +352,28 @@ impl TryFrom<Vec<i16>> for Mono {
         }
     }
 }
+impl TryFrom<Vec<i16>> for Mono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
 impl Into<Vec<i16>> for Mono {
     fn into(self) -> Vec<i16> {
         vec![sample_to_i16(self.mono)]
     }
 }
 
-impl TryFrom<Vec<i32>> for Mono {
-    type Error = String;
+impl TryFrom<&[i32]> for Mono {
+    type Error = SampleFormatError;
 
-    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
         if v.len() < 1 {
-            Err(format!(
-                "ERROR: Given vector was length {}. This function requires length 1.",
-                v.len()
-            ))
+            Err(SampleFormatError::WrongLength {
+                expected: 1,
+                actual: v.len(),
+            })
         } else {
             Ok(Mono {
                 mono: sample_from_i24(v[0]),
@@ -206,8 +381,73 @@ impl TryFrom<Vec<i32>> for Mono {
         }
     }
 }
+impl TryFrom<Vec<i32>> for Mono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
 impl Into<Vec<i32>> for Mono {
     fn into(self) -> Vec<i32> {
         vec![sample_to_i24(self.mono)]
     }
 }
+
+impl TryFrom<&[f32]> for Mono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(SampleFormatError::WrongLength {
+                expected: 1,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Mono {
+                mono: sample_from_f32(v[0]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Mono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Mono {
+    fn into(self) -> Vec<f32> {
+        vec![sample_to_f32(self.mono)]
+    }
+}
+
+impl TryFrom<&[f64]> for Mono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.is_empty() {
+            Err(SampleFormatError::WrongLength {
+                expected: 1,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Mono {
+                mono: sample_from_f64(v[0]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Mono {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Mono {
+    fn into(self) -> Vec<f64> {
+        vec![sample_to_f64(self.mono)]
+    }
+}
@@ -0,0 +1,461 @@
+//! # Multichannel
+//!
+//! Module containing type for handling audio data with an arbitrary, fixed
+//! number of channels.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`MultiChannel`] samples
+///
+/// [`MultiChannel`]: struct.MultiChannel.html
+pub type MultiChannelTrackT<const N: usize> = Vec<MultiChannel<N>>;
+
+/// Struct representing an audio sample with `N` channels, for speaker layouts
+/// that don't have a dedicated type. No meaning is given to the channels
+/// beyond their order.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct MultiChannel<const N: usize> {
+    /// The channels of the sample.
+    pub channels: [Sample; N],
+}
+
+impl<const N: usize> MultiChannel<N> {
+    /// Labels of the channels, numbered from 0.
+    const LABELS: [ChannelLabel; N] = discrete_labels::<N>();
+
+    /// Returns a new MultiChannel object with all channels set to 0.
+    pub const fn new() -> Self {
+        MultiChannel::SILENCE
+    }
+
+    /// Returns a new MultiChannel object created from the given channels.
+    ///
+    /// # Parameters
+    ///
+    /// * `channels` - the channels of the sample.
+    pub const fn from(channels: [Sample; N]) -> Self {
+        MultiChannel { channels }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; N] {
+        let s: [Sample; N] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new MultiChannel object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; N]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+}
+
+impl<const N: usize> Default for MultiChannel<N> {
+    fn default() -> Self {
+        MultiChannel {
+            channels: [Sample(0.0); N],
+        }
+    }
+}
+
+/// The monophonic sample is spread evenly across all channels with constant
+/// power, and is recovered by summing all channels with the inverse gain.
+impl<const N: usize> SampleFormat for MultiChannel<N> {
+    const CHANNEL_COUNT: usize = N;
+
+    const SILENCE: Self = MultiChannel {
+        channels: [Sample(0.0); N],
+    };
+    const UNITY: Self = MultiChannel {
+        channels: [Sample(1.0); N],
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        let g = 1.0 / (N as FastMath).sqrt();
+
+        MultiChannel {
+            channels: [Sample(x.0 * g); N],
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        let g = 1.0 / (N as FastMath).sqrt();
+
+        Sample(self.channels.iter().map(|c| c.0).sum::<FastMath>() * g)
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &Self::LABELS
+    }
+}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl<const N: usize> std::ops::Index<usize> for MultiChannel<N> {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.channels[i]
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl<const N: usize> std::ops::IndexMut<usize> for MultiChannel<N> {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.channels[i]
+    }
+}
+
+impl<const N: usize> AsRef<[Sample]> for MultiChannel<N> {
+    fn as_ref(&self) -> &[Sample] {
+        &self.channels
+    }
+}
+impl<const N: usize> AsMut<[Sample]> for MultiChannel<N> {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        &mut self.channels
+    }
+}
+
+impl<const N: usize> std::ops::Neg for MultiChannel<N> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        for c in self.channels.iter_mut() {
+            c.0 = -c.0;
+        }
+        self
+    }
+}
+
+impl<const N: usize> std::ops::Add<MultiChannel<N>> for MultiChannel<N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: MultiChannel<N>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+impl<const N: usize> std::ops::AddAssign<MultiChannel<N>> for MultiChannel<N> {
+    fn add_assign(&mut self, rhs: MultiChannel<N>) {
+        for (c, r) in self.channels.iter_mut().zip(rhs.channels.iter()) {
+            c.0 += r.0;
+        }
+    }
+}
+
+impl<const N: usize> std::ops::Sub<MultiChannel<N>> for MultiChannel<N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: MultiChannel<N>) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<const N: usize> std::ops::SubAssign<MultiChannel<N>> for MultiChannel<N> {
+    fn sub_assign(&mut self, rhs: MultiChannel<N>) {
+        for (c, r) in self.channels.iter_mut().zip(rhs.channels.iter()) {
+            c.0 -= r.0;
+        }
+    }
+}
+
+impl<const N: usize> std::ops::Mul<MultiChannel<N>> for MultiChannel<N> {
+    type Output = MultiChannel<N>;
+
+    fn mul(mut self, rhs: MultiChannel<N>) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl<const N: usize> std::ops::MulAssign<MultiChannel<N>> for MultiChannel<N> {
+    fn mul_assign(&mut self, rhs: MultiChannel<N>) {
+        for (c, r) in self.channels.iter_mut().zip(rhs.channels.iter()) {
+            c.0 *= r.0;
+        }
+    }
+}
+
+impl<const N: usize> std::ops::Mul<Sample> for MultiChannel<N> {
+    type Output = MultiChannel<N>;
+
+    fn mul(mut self, rhs: Sample) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl<const N: usize> std::ops::MulAssign<Sample> for MultiChannel<N> {
+    fn mul_assign(&mut self, rhs: Sample) {
+        for c in self.channels.iter_mut() {
+            c.0 *= rhs.0;
+        }
+    }
+}
+
+impl<const N: usize> std::ops::Mul<Math> for MultiChannel<N> {
+    type Output = MultiChannel<N>;
+
+    fn mul(mut self, rhs: Math) -> Self::Output {
+        for c in self.channels.iter_mut() {
+            c.0 = (c.0 as AccurateMath * rhs.0) as FastMath;
+        }
+        self
+    }
+}
+impl<const N: usize> std::ops::MulAssign<Math> for MultiChannel<N> {
+    fn mul_assign(&mut self, rhs: Math) {
+        for c in self.channels.iter_mut() {
+            c.0 *= rhs.0 as FastMath;
+        }
+    }
+}
+
+impl<const N: usize> std::ops::Mul<f32> for MultiChannel<N> {
+    type Output = MultiChannel<N>;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl<const N: usize> std::ops::MulAssign<f32> for MultiChannel<N> {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl<const N: usize> std::ops::Mul<f64> for MultiChannel<N> {
+    type Output = MultiChannel<N>;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl<const N: usize> std::ops::MulAssign<f64> for MultiChannel<N> {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl<const N: usize> std::ops::Mul<[Sample; N]> for MultiChannel<N> {
+    type Output = MultiChannel<N>;
+
+    fn mul(mut self, rhs: [Sample; N]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl<const N: usize> std::ops::MulAssign<[Sample; N]> for MultiChannel<N> {
+    fn mul_assign(&mut self, rhs: [Sample; N]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl<const N: usize> std::iter::Sum for MultiChannel<N> {
+    fn sum<I: Iterator<Item = MultiChannel<N>>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a, const N: usize> std::iter::Sum<&'a MultiChannel<N>> for MultiChannel<N> {
+    fn sum<I: Iterator<Item = &'a MultiChannel<N>>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl<const N: usize> std::iter::Product for MultiChannel<N> {
+    fn product<I: Iterator<Item = MultiChannel<N>>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a, const N: usize> std::iter::Product<&'a MultiChannel<N>> for MultiChannel<N> {
+    fn product<I: Iterator<Item = &'a MultiChannel<N>>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl<const N: usize> From<Sample> for MultiChannel<N> {
+    fn from(s: Sample) -> Self {
+        MultiChannel::from_sample(s)
+    }
+}
+impl<const N: usize> Into<Sample> for MultiChannel<N> {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl<const N: usize> From<[Sample; N]> for MultiChannel<N> {
+    fn from(a: [Sample; N]) -> Self {
+        MultiChannel { channels: a }
+    }
+}
+impl<const N: usize> Into<[Sample; N]> for MultiChannel<N> {
+    fn into(self) -> [Sample; N] {
+        self.channels
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for MultiChannel<N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < N {
+            Err(SampleFormatError::WrongLength {
+                expected: N,
+                actual: v.len(),
+            })
+        } else {
+            let mut m = MultiChannel::new();
+            for (c, x) in m.channels.iter_mut().zip(v) {
+                *c = sample_from_u8(*x);
+            }
+            Ok(m)
+        }
+    }
+}
+impl<const N: usize> TryFrom<Vec<u8>> for MultiChannel<N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl<const N: usize> Into<Vec<u8>> for MultiChannel<N> {
+    fn into(self) -> Vec<u8> {
+        self.channels.iter().map(|c| sample_to_u8(*c)).collect()
+    }
+}
+
+impl<const N: usize> TryFrom<&[i16]> for MultiChannel<N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < N {
+            Err(SampleFormatError::WrongLength {
+                expected: N,
+                actual: v.len(),
+            })
+        } else {
+            let mut m = MultiChannel::new();
+            for (c, x) in m.channels.iter_mut().zip(v) {
+                *c = sample_from_i16(*x);
+            }
+            Ok(m)
+        }
+    }
+}
+impl<const N: usize> TryFrom<Vec<i16>> for MultiChannel<N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl<const N: usize> Into<Vec<i16>> for MultiChannel<N> {
+    fn into(self) -> Vec<i16> {
+        self.channels.iter().map(|c| sample_to_i16(*c)).collect()
+    }
+}
+
+impl<const N: usize> TryFrom<&[i32]> for MultiChannel<N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < N {
+            Err(SampleFormatError::WrongLength {
+                expected: N,
+                actual: v.len(),
+            })
+        } else {
+            let mut m = MultiChannel::new();
+            for (c, x) in m.channels.iter_mut().zip(v) {
+                *c = sample_from_i24(*x);
+            }
+            Ok(m)
+        }
+    }
+}
+impl<const N: usize> TryFrom<Vec<i32>> for MultiChannel<N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl<const N: usize> Into<Vec<i32>> for MultiChannel<N> {
+    fn into(self) -> Vec<i32> {
+        self.channels.iter().map(|c| sample_to_i24(*c)).collect()
+    }
+}
+
+impl<const N: usize> TryFrom<&[f32]> for MultiChannel<N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < N {
+            Err(SampleFormatError::WrongLength {
+                expected: N,
+                actual: v.len(),
+            })
+        } else {
+            let mut m = MultiChannel::new();
+            for (c, x) in m.channels.iter_mut().zip(v) {
+                *c = sample_from_f32(*x);
+            }
+            Ok(m)
+        }
+    }
+}
+impl<const N: usize> TryFrom<Vec<f32>> for MultiChannel<N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl<const N: usize> Into<Vec<f32>> for MultiChannel<N> {
+    fn into(self) -> Vec<f32> {
+        self.channels.iter().map(|c| sample_to_f32(*c)).collect()
+    }
+}
+
+impl<const N: usize> TryFrom<&[f64]> for MultiChannel<N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < N {
+            Err(SampleFormatError::WrongLength {
+                expected: N,
+                actual: v.len(),
+            })
+        } else {
+            let mut m = MultiChannel::new();
+            for (c, x) in m.channels.iter_mut().zip(v) {
+                *c = sample_from_f64(*x);
+            }
+            Ok(m)
+        }
+    }
+}
+impl<const N: usize> TryFrom<Vec<f64>> for MultiChannel<N> {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl<const N: usize> Into<Vec<f64>> for MultiChannel<N> {
+    fn into(self) -> Vec<f64> {
+        self.channels.iter().map(|c| sample_to_f64(*c)).collect()
+    }
+}
@@ -0,0 +1,54 @@
+//! # Normalize
+//!
+//! Module containing the normalization of whole tracks, measuring their level
+//! and applying the gain that brings it to a target in a single call.
+
+use super::*;
+
+/// Applies the gain that brings the highest sample peak of any channel of the
+/// track to the given level. Returns the gain applied in decibels. A silent
+/// track is left unchanged, returning a gain of 0.
+///
+/// # Parameters
+///
+/// * `track` - the track to normalize.
+/// * `target_dbfs` - the level of the highest peak in decibels relative to
+/// full scale.
+pub fn normalize_peak<F: SampleFormat + Copy>(track: &mut Track<F>, target_dbfs: Math) -> Math {
+    let level = peak(track).channel_max().0 as AccurateMath;
+
+    apply(track, target_dbfs.0 - 20.0 * level.log10())
+}
+
+/// Applies the gain that brings the integrated loudness of the track to the
+/// given level. Returns the gain applied in decibels. A track that is silent
+/// or too quiet to pass the gates of the loudness measurement is left
+/// unchanged, returning a gain of 0.
+///
+/// The gain isn't limited, so normalizing quiet tracks to a high target can
+/// clip their peaks.
+///
+/// # Parameters
+///
+/// * `track` - the track to normalize.
+/// * `target_lufs` - the integrated loudness in LUFS.
+/// * `sample_rate` - the sample rate of the track in Hz.
+pub fn normalize_lufs<F: SampleFormat + Copy>(
+    track: &mut Track<F>,
+    target_lufs: Math,
+    sample_rate: Math,
+) -> Math {
+    let loudness = integrated_loudness(track, sample_rate).0;
+
+    apply(track, target_lufs.0 - loudness)
+}
+
+/// Applies the gain in decibels to the track if it is finite.
+fn apply<F: SampleFormat + Copy>(track: &mut Track<F>, db: AccurateMath) -> Math {
+    if !db.is_finite() {
+        return Math(0.0);
+    }
+
+    track.gain(Math(db));
+    Math(db)
+}
@@ -0,0 +1,118 @@
+//! # Pan Automation
+//!
+//! Module containing a time-varying panning parameter, described by
+//! breakpoints at given sample indices.
+
+use super::*;
+
+/// Enum of the ways in which a [`PanAutomation`] moves between two
+/// breakpoints.
+///
+/// [`PanAutomation`]: struct.PanAutomation.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum AutomationCurve {
+    /// The value jumps to that of each breakpoint when it is reached.
+    Step,
+    /// The value moves linearly between breakpoints.
+    #[default]
+    Linear,
+    /// The value moves between breakpoints along a raised cosine, easing in
+    /// and out of each breakpoint.
+    SCurve,
+}
+
+/// Struct describing a panning parameter that changes over the course of a
+/// track. The parameter is given by breakpoints of `(sample_index, pan)` pairs,
+/// and is held at the value of the first and last breakpoints before and after
+/// them respectively.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PanAutomation {
+    points: Vec<(usize, AccurateMath)>,
+    curve: AutomationCurve,
+}
+
+impl PanAutomation {
+    /// Creates a new automation without any breakpoints, using the given curve
+    /// between breakpoints.
+    ///
+    /// # Parameters
+    ///
+    /// * `curve` - the curve between breakpoints.
+    pub fn new(curve: AutomationCurve) -> Self {
+        PanAutomation {
+            points: Vec::new(),
+            curve,
+        }
+    }
+
+    /// Adds a breakpoint to the automation. If a breakpoint already exists at
+    /// the given index it is replaced.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - the index of the sample at which the breakpoint is reached.
+    /// * `pan` - the value of the panning parameter at the breakpoint.
+    pub fn add_point(&mut self, index: usize, pan: AccurateMath) {
+        match self.points.binary_search_by_key(&index, |p| p.0) {
+            Ok(i) => self.points[i].1 = pan,
+            Err(i) => self.points.insert(i, (index, pan)),
+        }
+    }
+
+    /// Returns the breakpoints of the automation, sorted by index.
+    pub fn points(&self) -> &[(usize, AccurateMath)] {
+        &self.points
+    }
+
+    /// Returns the curve used between breakpoints.
+    pub fn curve(&self) -> AutomationCurve {
+        self.curve
+    }
+
+    /// Returns the value of the panning parameter at the given sample index.
+    /// An automation without any breakpoints has a value of 0.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - the index of the sample.
+    pub fn value_at(&self, index: usize) -> AccurateMath {
+        let next = match self.points.binary_search_by_key(&index, |p| p.0) {
+            Ok(i) => return self.points[i].1,
+            Err(i) => i,
+        };
+
+        if next == 0 {
+            return self.points.first().map_or(0.0, |p| p.1);
+        }
+        if next == self.points.len() {
+            return self.points[next - 1].1;
+        }
+
+        let (i0, v0) = self.points[next - 1];
+        let (i1, v1) = self.points[next];
+        let t = (index - i0) as AccurateMath / (i1 - i0) as AccurateMath;
+
+        match self.curve {
+            AutomationCurve::Step => v0,
+            AutomationCurve::Linear => v0 + (v1 - v0) * t,
+            AutomationCurve::SCurve => {
+                let t = (1.0 - (t * std::f64::consts::PI).cos()) / 2.0;
+                v0 + (v1 - v0) * t
+            }
+        }
+    }
+
+    /// Pans every sample of the given monophonic track with the value of the
+    /// automation at its index.
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the monophonic samples to pan.
+    pub fn apply<F: Panner<f64>>(&self, track: &[Sample]) -> Vec<F> {
+        track
+            .iter()
+            .enumerate()
+            .map(|(i, s)| F::to_sample_format(*s, self.value_at(i)))
+            .collect()
+    }
+}
@@ -0,0 +1,103 @@
+//! # Pan Law
+//!
+//! Module containing the conventions used to distribute a monophonic sample
+//! between a pair of speakers.
+
+use super::*;
+
+/// Enum of the conventions for the level of a sample panned between a pair of
+/// speakers. Each law is named for the attenuation applied to both speakers
+/// when the sample is panned to the center.
+///
+/// The law can be given to the [`Stereo`] panner alongside the panning
+/// parameter, e.g. `Stereo::to_sample_format(s, (0.5, PanLaw::default()))`.
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PanLaw {
+    /// The gain of each speaker is interpolated in decibels from 0 dB when
+    /// panned fully towards it, to -3 dB at the center, to -120 dB when panned
+    /// fully away from it. This is the law used when none is given, kept as the
+    /// original curve of the [`Stereo`] panner. It matches [`ConstantPower3dB`]
+    /// at the center and the edges, but loses power in between.
+    ///
+    /// [`Stereo`]: ../stereo/struct.Stereo.html
+    /// [`ConstantPower3dB`]: #variant.ConstantPower3dB
+    #[default]
+    DecibelTaper3dB,
+    /// The gains of the speakers are the cosine and sine of the panning
+    /// parameter mapped to a quarter turn, such that the sum of their squares
+    /// is always 1 and the sample is attenuated by 3 dB at the center. This
+    /// keeps the power of the sample constant as it moves.
+    ConstantPower3dB,
+    /// The gains of the speakers are the geometric mean of those of the
+    /// [`ConstantPower3dB`] and [`Linear`] laws, such that the sample is
+    /// attenuated by 4.5 dB at the center. This is a compromise between the
+    /// constant power of the -3 dB law and the mono compatibility of the -6 dB
+    /// law, and is the default of several DAWs.
+    ///
+    /// [`ConstantPower3dB`]: #variant.ConstantPower3dB
+    /// [`Linear`]: #variant.Linear
+    Compromise4_5dB,
+    /// The gains of the speakers are `(1-g)/2` and `(1+g)/2` respectively,
+    /// such that they always sum to 1 and the sample is attenuated by 6 dB at
+    /// the center. A centered sample then sums to its original level when the
+    /// channels are downmixed to mono. This is the -6 dB law, and the law
+    /// commonly expected by game engines.
+    Linear,
+}
+
+impl PanLaw {
+    /// Calculates the linear gains of the left and right speakers for the
+    /// given panning parameter. Returns a tuple of the left and right gains
+    /// respectively.
+    ///
+    /// # Parameters
+    ///
+    /// * `g` - the panning parameter of the range \[-1,1\], where -1 is panned
+    /// full left and 1 is panned full right. Values outside of the range are
+    /// clamped to it.
+    pub(crate) fn gains(self, g: AccurateMath) -> (AccurateMath, AccurateMath) {
+        let g = g.clamp(-1.0, 1.0);
+
+        match self {
+            PanLaw::DecibelTaper3dB => (db_taper(-g), db_taper(g)),
+            PanLaw::ConstantPower3dB => sin_cos_taper(g),
+            PanLaw::Compromise4_5dB => {
+                let (sl, sr) = sin_cos_taper(g);
+                let (ll, lr) = linear_taper(g);
+                ((sl * ll).sqrt(), (sr * lr).sqrt())
+            }
+            PanLaw::Linear => linear_taper(g),
+        }
+    }
+}
+
+/// Calculates the linear gain of a speaker for a sample panned towards it by
+/// `g`, interpolating in decibels between 0 dB at 1, -3 dB at 0 and -120 dB at
+/// -1.
+fn db_taper(g: AccurateMath) -> AccurateMath {
+    let db = if g >= 0.0 {
+        bae_utils::clerp(g, 0.0, 1.0, -3.0, 0.0)
+    } else {
+        bae_utils::clerp(g, -1.0, 0.0, -120.0, -3.0)
+    };
+
+    bae_utils::db_to_linear(Math(db)).0
+}
+
+/// Calculates the linear gains of the left and right speakers for a sample
+/// panned by `g` with constant power, as the cosine and sine of `g` mapped from
+/// \[-1,1\] to \[0,π/2\].
+fn sin_cos_taper(g: AccurateMath) -> (AccurateMath, AccurateMath) {
+    let (r, l) = ((g + 1.0) * std::f64::consts::FRAC_PI_4).sin_cos();
+
+    (l, r)
+}
+
+/// Calculates the linear gains of the left and right speakers for a sample
+/// panned by `g`, as `(1-g)/2` and `(1+g)/2` respectively.
+fn linear_taper(g: AccurateMath) -> (AccurateMath, AccurateMath) {
+    ((1.0 - g) / 2.0, (1.0 + g) / 2.0)
+}
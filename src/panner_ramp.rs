@@ -0,0 +1,99 @@
+//! # Panner Ramp
+//!
+//! Module containing a stateful wrapper around the panners that smooths changes
+//! of the panning parameter over time.
+
+use super::*;
+
+/// Stateful panner that interpolates the gains of a [`Panner`] over a number of
+/// samples whenever the panning parameter changes. Jumping directly between
+/// two sets of gains causes an audible click, and changing them every sample
+/// causes zipper noise, both of which are avoided by ramping between them.
+///
+/// The gains of each channel are found with [`Panner::pan_gains`], and are
+/// interpolated linearly from the gains in effect when the parameter changed
+/// to those of the new parameter.
+///
+/// [`Panner`]: ../trait.Panner.html
+/// [`Panner::pan_gains`]: ../trait.Panner.html#method.pan_gains
+#[derive(Debug, Clone)]
+pub struct PannerRamp<F, G> {
+    length: usize,
+    position: usize,
+    target: G,
+    from: F,
+    to: F,
+}
+
+impl<F, G> PannerRamp<F, G>
+where
+    F: Panner<G> + Copy,
+    G: Copy + PartialEq,
+{
+    /// Creates a new PannerRamp starting at the given panning parameter.
+    ///
+    /// # Parameters
+    ///
+    /// * `g` - the initial panning parameter.
+    /// * `length` - the number of samples over which changes are ramped. A
+    /// length of 0 applies changes immediately.
+    pub fn new(g: G, length: usize) -> Self {
+        let gains = F::pan_gains(g);
+
+        PannerRamp {
+            length,
+            position: length,
+            target: g,
+            from: gains,
+            to: gains,
+        }
+    }
+
+    /// Processes the next sample of the source, panning it with the current
+    /// gains of the ramp. If `g` differs from the previous panning parameter a
+    /// new ramp is started from the current gains towards those of `g`.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next monophonic sample of the source.
+    /// * `g` - the panning parameter.
+    pub fn process(&mut self, s: Sample, g: G) -> F {
+        if g != self.target {
+            self.from = self.gains();
+            self.to = F::pan_gains(g);
+            self.target = g;
+            self.position = 0;
+        }
+
+        let gains = self.gains();
+        self.position = usize::min(self.position + 1, self.length);
+
+        gains * s
+    }
+
+    /// Returns the gains currently applied to each channel.
+    pub fn gains(&self) -> F {
+        if self.position >= self.length {
+            self.to
+        } else {
+            let t = self.position as AccurateMath / self.length as AccurateMath;
+            self.from.lerp(self.to, Math(t))
+        }
+    }
+
+    /// Returns true if the ramp hasn't yet reached the gains of the current
+    /// panning parameter.
+    pub fn is_ramping(&self) -> bool {
+        self.position < self.length
+    }
+
+    /// Jumps directly to the gains of the given panning parameter without
+    /// ramping.
+    ///
+    /// # Parameters
+    ///
+    /// * `g` - the panning parameter.
+    pub fn reset(&mut self, g: G) {
+        *self = PannerRamp::new(g, self.length);
+    }
+}
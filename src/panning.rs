@@ -0,0 +1,174 @@
+//! # Panning
+//!
+//! Crate-private helpers shared by the panners of the multichannel formats.
+//!
+//! Angles are given in degrees. Azimuths are measured clockwise from the front
+//! of the listener, such that -90 is directly left and 90 is directly right,
+//! matching the direction of the [`Stereo`] panner. Elevations are measured
+//! upwards from the horizontal plane.
+//!
+//! The speaker angles of every layout with a panner are defined here, following
+//! the standard of each layout. They can differ from the nominal [`position`]
+//! of the channel labels, which is shared by all layouts and is only used to
+//! route channels between formats.
+//!
+//! [`Stereo`]: ../stereo/struct.Stereo.html
+//! [`position`]: ../channel/enum.ChannelLabel.html#method.position
+
+use super::*;
+
+/// Maximum angle in degrees between the virtual sources used to spread a
+/// source across an arc.
+const SPREAD_STEP: AccurateMath = 15.0;
+
+/// Azimuths of the speakers of a quadraphonic layout, in the order front left,
+/// front right, rear left, and rear right.
+pub(crate) const QUAD_AZIMUTHS: [AccurateMath; 4] = [-45.0, 45.0, -135.0, 135.0];
+
+/// Azimuths of the speakers of a 5.1 layout following ITU-R BS.775, excluding
+/// the LFE, in the order front left, front right, center, surround left, and
+/// surround right.
+pub(crate) const SURROUND51_AZIMUTHS: [AccurateMath; 5] = [-30.0, 30.0, 0.0, -110.0, 110.0];
+
+/// Azimuths of the speakers of a 7.1 layout, excluding the LFE, in the order
+/// front left, front right, center, side left, side right, rear left, and rear
+/// right. These are also the ear-level speakers of a 7.1.4 layout.
+pub(crate) const SURROUND71_AZIMUTHS: [AccurateMath; 7] =
+    [-30.0, 30.0, 0.0, -90.0, 90.0, -135.0, 135.0];
+
+/// Azimuths of the height speakers of a 7.1.4 layout, in the order top front
+/// left, top front right, top rear left, and top rear right.
+pub(crate) const SURROUND714_TOP_AZIMUTHS: [AccurateMath; 4] = [-45.0, 45.0, -135.0, 135.0];
+
+/// Elevation of the height speakers of a 7.1.4 layout.
+pub(crate) const SURROUND714_TOP_ELEVATION: AccurateMath = 45.0;
+
+/// Wraps the given angle in degrees to the range \[0,360).
+pub(crate) fn wrap_degrees(a: AccurateMath) -> AccurateMath {
+    a.rem_euclid(360.0)
+}
+
+/// Finds the two speakers of a horizontal ring adjacent to the given azimuth.
+/// Returns a tuple of the nearest speaker counter-clockwise from the source and
+/// the nearest speaker clockwise from it, each as its index and its angular
+/// distance from the source in degrees. Both are the same speaker if the ring
+/// only contains one, or if the source is exactly at a speaker.
+///
+/// # Parameters
+///
+/// * `azimuth` - the azimuth of the source in degrees.
+/// * `speakers` - the azimuths of the speakers in the ring, in degrees. Must
+/// not be empty.
+pub(crate) fn adjacent_speakers(
+    azimuth: AccurateMath,
+    speakers: &[AccurateMath],
+) -> ((usize, AccurateMath), (usize, AccurateMath)) {
+    let mut prev = (0, AccurateMath::INFINITY);
+    let mut next = (0, AccurateMath::INFINITY);
+
+    for (i, a) in speakers.iter().enumerate() {
+        let to_source = wrap_degrees(azimuth - a);
+        let to_speaker = wrap_degrees(a - azimuth);
+
+        if to_source < prev.1 {
+            prev = (i, to_source);
+        }
+        if to_speaker < next.1 {
+            next = (i, to_speaker);
+        }
+    }
+
+    (prev, next)
+}
+
+/// Calculates constant-power gains for a horizontal ring of speakers. The
+/// source is panned between the two speakers adjacent to the given azimuth, all
+/// other speakers receive a gain of 0.
+///
+/// # Parameters
+///
+/// * `azimuth` - the azimuth of the source in degrees.
+/// * `speakers` - the azimuths of the speakers in the ring, in degrees.
+/// * `gains` - the output gains, one for each speaker in `speakers`.
+pub(crate) fn ring_gains(
+    azimuth: AccurateMath,
+    speakers: &[AccurateMath],
+    gains: &mut [AccurateMath],
+) {
+    for g in gains.iter_mut() {
+        *g = 0.0;
+    }
+
+    if speakers.is_empty() {
+        return;
+    }
+
+    let (prev, next) = adjacent_speakers(azimuth, speakers);
+    let span = prev.1 + next.1;
+
+    if prev.0 == next.0 || span <= 0.0 {
+        gains[prev.0] = 1.0;
+    } else {
+        let f = prev.1 / span * std::f64::consts::FRAC_PI_2;
+
+        gains[prev.0] = f.cos();
+        gains[next.0] = f.sin();
+    }
+}
+
+/// Calculates constant-power gains splitting a source between a horizontal
+/// layer and an elevated layer of speakers. Returns a tuple of the gains for
+/// the horizontal and elevated layers respectively.
+///
+/// # Parameters
+///
+/// * `elevation` - the elevation of the source in degrees. Values below 0 are
+/// treated as 0, and values above `layer` as `layer`.
+/// * `layer` - the elevation of the elevated layer in degrees.
+pub(crate) fn layer_gains(
+    elevation: AccurateMath,
+    layer: AccurateMath,
+) -> (AccurateMath, AccurateMath) {
+    let f = (elevation / layer).clamp(0.0, 1.0) * std::f64::consts::FRAC_PI_2;
+
+    (f.cos(), f.sin())
+}
+
+/// Calculates the gains of a source spread across an arc of the horizontal
+/// plane centered on the given position. The source is split into virtual
+/// sources evenly spaced across the arc, whose gains are summed and then
+/// normalized to constant power.
+///
+/// # Parameters
+///
+/// * `position` - the position of the center of the source.
+/// * `spread` - the width of the arc in degrees. Values are clamped to the
+/// range \[0,360\], where 0 is a point source.
+pub(crate) fn spread_gains<F: Panner<SphericalPosition>>(
+    position: SphericalPosition,
+    spread: AccurateMath,
+) -> F {
+    let spread = spread.clamp(0.0, 360.0);
+    let n = (spread / SPREAD_STEP).ceil() as usize + 1;
+
+    if n == 1 {
+        return F::pan_gains(position);
+    }
+
+    let mut gains = F::SILENCE;
+    for i in 0..n {
+        let offset = spread * ((i as AccurateMath + 0.5) / n as AccurateMath - 0.5);
+
+        gains += F::pan_gains(SphericalPosition::new(
+            position.azimuth + offset,
+            position.elevation,
+        ));
+    }
+
+    let power: FastMath = gains.as_ref().iter().map(|g| g.0 * g.0).sum();
+    if power > 0.0 {
+        gains * Sample(1.0 / power.sqrt())
+    } else {
+        gains
+    }
+}
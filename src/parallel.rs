@@ -0,0 +1,100 @@
+//! # Parallel
+//!
+//! Module containing processing of whole tracks spread over all cores with
+//! [rayon], for large offline jobs such as format conversions, gain changes
+//! and dithering. Requires the `rayon` feature.
+//!
+//! [rayon]: https://docs.rs/rayon/1/rayon/
+
+use super::*;
+use rayon::prelude::*;
+
+/// Number of samples dithered with the same generator by [`par_dither`].
+///
+/// [`par_dither`]: fn.par_dither.html
+const DITHER_CHUNK: usize = 4096;
+
+/// Applies the function to every sample of the track in parallel, returning a
+/// new track of the results in the same order.
+///
+/// # Parameters
+///
+/// * `track` - the samples to process.
+/// * `f` - the function applied to each sample.
+pub fn par_map<F, G, M>(track: &[F], f: M) -> Track<G>
+where
+    F: Copy + Sync,
+    G: Send,
+    M: Fn(F) -> G + Sync + Send,
+{
+    let samples: Vec<G> = track.par_iter().map(|s| f(*s)).collect();
+
+    samples.into()
+}
+
+/// Applies the function to every sample of the track in place, in parallel.
+///
+/// # Parameters
+///
+/// * `track` - the samples to process.
+/// * `f` - the function applied to each sample.
+pub fn par_map_in_place<F, M>(track: &mut [F], f: M)
+where
+    F: Copy + Send,
+    M: Fn(F) -> F + Sync + Send,
+{
+    track.par_iter_mut().for_each(|s| *s = f(*s));
+}
+
+/// Converts every sample of the track to the format `G` in parallel, as done by
+/// [`ConvertFormat`].
+///
+/// # Parameters
+///
+/// * `track` - the samples to convert.
+///
+/// [`ConvertFormat`]: ../trait.ConvertFormat.html
+pub fn par_convert<F, G>(track: &[F]) -> Track<G>
+where
+    F: ConvertFormat<G> + Copy + Sync,
+    G: SampleFormat + Send,
+{
+    par_map(track, |s| s.convert_format())
+}
+
+/// Applies the given gain in decibels to every sample of the track in
+/// parallel.
+///
+/// # Parameters
+///
+/// * `track` - the samples to process.
+/// * `db` - the gain in decibels.
+pub fn par_gain<F: SampleFormat + Copy + Send>(track: &mut [F], db: Math) {
+    let gain = bae_utils::db_to_linear(db).0;
+
+    par_map_in_place(track, |s| s * gain);
+}
+
+/// Dithers every sample of the track in place for the given bit depth in
+/// parallel, as done by [`TpdfDither`].
+///
+/// The track is split into chunks each dithered by its own generator, seeded
+/// from the given seed and the position of the chunk, so the result doesn't
+/// depend on the number of cores.
+///
+/// # Parameters
+///
+/// * `track` - the samples to dither.
+/// * `bit_depth` - the bit depth the track will be converted to.
+/// * `seed` - the seed of the generators.
+///
+/// [`TpdfDither`]: ../dither/struct.TpdfDither.html
+pub fn par_dither<F: SampleFormat + Copy + Send>(track: &mut [F], bit_depth: BitDepth, seed: u64) {
+    track
+        .par_chunks_mut(DITHER_CHUNK)
+        .enumerate()
+        .for_each(|(i, chunk)| {
+            let seed = seed.wrapping_add((i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+            TpdfDither::new(seed).dither_track(chunk, bit_depth);
+        });
+}
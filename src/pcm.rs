@@ -0,0 +1,163 @@
+//! # PCM
+//!
+//! Module containing bulk conversions of whole tracks of samples to and from
+//! interleaved PCM bytes, as stored by audio files and sent to audio devices.
+
+use super::*;
+
+/// Encodes every sample of the track as interleaved PCM bytes of the given bit
+/// depth and byte order, in a single allocation. Integer values outside of the
+/// range -1 to 1 are clipped.
+///
+/// # Parameters
+///
+/// * `track` - the samples to encode.
+/// * `bit_depth` - the encoding of each sample value.
+/// * `endianness` - the byte order of multi-byte sample values.
+pub fn encode_track<F: SampleFormat>(
+    track: &[F],
+    bit_depth: BitDepth,
+    endianness: Endianness,
+) -> Vec<u8> {
+    encode_track_clipped(track, bit_depth, endianness, ClipMode::Hard)
+}
+
+/// Encodes every sample of the track as interleaved PCM bytes as done by
+/// [`encode_track`], clipping integer values with the given mode. Floating
+/// point values are written unchanged.
+///
+/// # Parameters
+///
+/// * `track` - the samples to encode.
+/// * `bit_depth` - the encoding of each sample value.
+/// * `endianness` - the byte order of multi-byte sample values.
+/// * `clip` - the clipping applied to integer values.
+///
+/// [`encode_track`]: fn.encode_track.html
+pub fn encode_track_clipped<F: SampleFormat>(
+    track: &[F],
+    bit_depth: BitDepth,
+    endianness: Endianness,
+    clip: ClipMode,
+) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(track.len() * F::CHANNEL_COUNT * bit_depth.bytes());
+
+    match bit_depth {
+        BitDepth::U8 => encode(track, &mut bytes, |s| {
+            [bae_utils::sample_to_u8(clip.apply(s))]
+        }),
+        BitDepth::I16 => encode(track, &mut bytes, |s| {
+            sample_to_i16_bytes(clip.apply(s), endianness)
+        }),
+        BitDepth::I24 => encode(track, &mut bytes, |s| {
+            sample_to_i24_bytes(clip.apply(s), endianness)
+        }),
+        BitDepth::I32 => encode(track, &mut bytes, |s| {
+            sample_to_i32_bytes(clip.apply(s), endianness)
+        }),
+        BitDepth::F32 => encode(track, &mut bytes, |s| sample_to_f32_bytes(s, endianness)),
+    }
+
+    bytes
+}
+
+/// Encodes every sample of the track as interleaved PCM bytes as done by
+/// [`encode_track`], first applying the given dither for integer bit depths.
+///
+/// # Parameters
+///
+/// * `track` - the samples to encode.
+/// * `bit_depth` - the encoding of each sample value.
+/// * `endianness` - the byte order of multi-byte sample values.
+/// * `dither` - the dither applied to each sample.
+///
+/// [`encode_track`]: fn.encode_track.html
+pub fn encode_track_dithered<F: SampleFormat + Copy>(
+    track: &[F],
+    bit_depth: BitDepth,
+    endianness: Endianness,
+    dither: &mut TpdfDither,
+) -> Vec<u8> {
+    let dithered: Vec<F> = track.iter().map(|s| dither.dither(*s, bit_depth)).collect();
+
+    encode_track(&dithered, bit_depth, endianness)
+}
+
+/// Decodes interleaved PCM bytes of the given bit depth and byte order into a
+/// track of samples, in a single allocation. Returns [`UnalignedLength`] if
+/// the bytes aren't a whole number of frames, with `channels` holding the size
+/// of a frame in bytes.
+///
+/// # Parameters
+///
+/// * `bytes` - the interleaved frames, with a length that is a multiple of
+/// the size of a frame in bytes.
+/// * `bit_depth` - the encoding of each sample value.
+/// * `endianness` - the byte order of multi-byte sample values.
+///
+/// [`UnalignedLength`]: ../error/enum.SampleFormatError.html#variant.UnalignedLength
+pub fn decode_track<F: SampleFormat>(
+    bytes: &[u8],
+    bit_depth: BitDepth,
+    endianness: Endianness,
+) -> Result<Vec<F>, SampleFormatError> {
+    Ok(samples_from_bytes_interleaved(bytes, bit_depth, endianness)?.collect())
+}
+
+/// Returns an iterator decoding the samples of interleaved PCM bytes of the
+/// given bit depth and byte order one at a time, without allocating. Returns
+/// [`UnalignedLength`] if the bytes aren't a whole number of frames, with
+/// `channels` holding the size of a frame in bytes.
+///
+/// # Parameters
+///
+/// * `bytes` - the interleaved frames, with a length that is a multiple of
+/// the size of a frame in bytes.
+/// * `bit_depth` - the encoding of each sample value.
+/// * `endianness` - the byte order of multi-byte sample values.
+///
+/// [`UnalignedLength`]: ../error/enum.SampleFormatError.html#variant.UnalignedLength
+pub fn samples_from_bytes_interleaved<F: SampleFormat>(
+    bytes: &[u8],
+    bit_depth: BitDepth,
+    endianness: Endianness,
+) -> Result<impl Iterator<Item = F> + '_, SampleFormatError> {
+    let width = bit_depth.bytes();
+    let frame = F::CHANNEL_COUNT * width;
+    if frame == 0 || bytes.len() % frame != 0 {
+        return Err(SampleFormatError::UnalignedLength {
+            channels: frame,
+            actual: bytes.len(),
+        });
+    }
+
+    let read: fn(&[u8], Endianness) -> Sample = match bit_depth {
+        BitDepth::U8 => |b, _| bae_utils::sample_from_u8(b[0]),
+        BitDepth::I16 => sample_from_i16_bytes,
+        BitDepth::I24 => sample_from_i24_bytes,
+        BitDepth::I32 => sample_from_i32_bytes,
+        BitDepth::F32 => sample_from_f32_bytes,
+    };
+
+    Ok(bytes.chunks_exact(frame).map(move |b| {
+        let mut s = F::SILENCE;
+        for (c, v) in s.as_mut().iter_mut().zip(b.chunks_exact(width)) {
+            *c = read(v, endianness);
+        }
+        s
+    }))
+}
+
+/// Appends every channel of every sample of the track to the bytes, each
+/// encoded with the given function.
+fn encode<F: SampleFormat, const W: usize>(
+    track: &[F],
+    bytes: &mut Vec<u8>,
+    write: impl Fn(Sample) -> [u8; W],
+) {
+    for s in track {
+        for c in s.as_ref() {
+            bytes.extend_from_slice(&write(*c));
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! # Pod
+//!
+//! Module implementing the [`bytemuck`] traits for the fixed-size sample
+//! formats, allowing slices of samples to be reinterpreted as slices of bytes
+//! or floats without copying.
+//!
+//! [`bytemuck`]: https://docs.rs/bytemuck
+
+use super::*;
+use bytemuck::{Pod, Zeroable};
+
+// The formats are `#[repr(C)]` structs made up solely of `Sample` values,
+// which in turn hold a single float. These checks ensure `Sample` adds no
+// padding, so that the formats have no padding and every bit pattern is valid.
+const _: () = assert!(std::mem::size_of::<Sample>() == std::mem::size_of::<FastMath>());
+const _: () = assert!(std::mem::align_of::<Sample>() == std::mem::align_of::<FastMath>());
+
+macro_rules! impl_pod {
+    ($($t:ty),* $(,)?) => {
+        $(
+            unsafe impl Zeroable for $t {}
+            unsafe impl Pod for $t {}
+        )*
+    };
+}
+
+impl_pod!(
+    AmbisonicB,
+    Binaural,
+    DualMono,
+    Lcr,
+    MidSide,
+    Mono,
+    Quad,
+    Stereo,
+    Surround21,
+    Surround222,
+    Surround51,
+    Surround512,
+    Surround61,
+    Surround71,
+    Surround714,
+);
+
+unsafe impl<const N: usize> Zeroable for MultiChannel<N> {}
+unsafe impl<const N: usize> Pod for MultiChannel<N> {}
+
+unsafe impl<const ORDER: usize, const N: usize> Zeroable for Hoa<ORDER, N> {}
+unsafe impl<const ORDER: usize, const N: usize> Pod for Hoa<ORDER, N> {}
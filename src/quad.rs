@@ -0,0 +1,652 @@
+//! # Quad
+//!
+//! Module containing type for handling quadraphonic (4.0) audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Quad`] samples
+///
+/// [`Quad`]: struct.Quad.html
+pub type QuadTrackT = Vec<Quad>;
+
+/// Struct representing a quadraphonic audio sample.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Quad {
+    /// Front left sample value.
+    pub front_left: Sample,
+    /// Front right sample value.
+    pub front_right: Sample,
+    /// Rear left sample value.
+    pub rear_left: Sample,
+    /// Rear right sample value.
+    pub rear_right: Sample,
+}
+
+impl Quad {
+    /// Returns a new Quad object with default <0,0,0,0> values.
+    pub const fn new() -> Self {
+        Quad::SILENCE
+    }
+
+    /// Returns a new Quad object created from the individual audio samples of
+    /// each channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `fl` - the front left audio sample.
+    /// * `fr` - the front right audio sample.
+    /// * `rl` - the rear left audio sample.
+    /// * `rr` - the rear right audio sample.
+    pub const fn from(fl: Sample, fr: Sample, rl: Sample, rr: Sample) -> Self {
+        Quad {
+            front_left: fl,
+            front_right: fr,
+            rear_left: rl,
+            rear_right: rr,
+        }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 4] {
+        let s: [Sample; 4] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Quad object with each channel set to the positive linear
+    /// value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 4]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Pans the given sample to the given azimuth in degrees. This is the
+    /// implementation shared by the [`Panner`] impls.
+    ///
+    /// [`Panner`]: ../trait.Panner.html
+    fn pan(s: Sample, azimuth: AccurateMath) -> Self {
+        let mut gains = [0.0; 4];
+
+        panning::ring_gains(azimuth, &panning::QUAD_AZIMUTHS, &mut gains);
+
+        let g = |i: usize| Sample((s.0 as AccurateMath * gains[i]) as FastMath);
+
+        Quad {
+            front_left: g(0),
+            front_right: g(1),
+            rear_left: g(2),
+            rear_right: g(3),
+        }
+    }
+}
+
+impl SampleFormat for Quad {
+    const CHANNEL_COUNT: usize = 4;
+
+    const SILENCE: Self = Quad {
+        front_left: Sample(0.0),
+        front_right: Sample(0.0),
+        rear_left: Sample(0.0),
+        rear_right: Sample(0.0),
+    };
+    const UNITY: Self = Quad {
+        front_left: Sample(1.0),
+        front_right: Sample(1.0),
+        rear_left: Sample(1.0),
+        rear_right: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        Quad {
+            front_left: Sample(x.0 * 0.5),
+            front_right: Sample(x.0 * 0.5),
+            rear_left: Sample(x.0 * 0.5),
+            rear_right: Sample(x.0 * 0.5),
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        Sample(
+            (self.front_left.0 + self.front_right.0 + self.rear_left.0 + self.rear_right.0) * 0.5,
+        )
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[
+            ChannelLabel::FrontLeft,
+            ChannelLabel::FrontRight,
+            ChannelLabel::RearLeft,
+            ChannelLabel::RearRight,
+        ]
+    }
+}
+
+/// Pans a given sample pairwise between the four speakers. The panning
+/// parameter `g` is a tuple of floating point values `(x, y)` of the range
+/// \[-1,1\]. `x` pans between the left and right speakers of both the front and
+/// rear pairs in the same way as the [`Stereo`] panner, where -1 is panned full
+/// left and 1 is panned full right. `y` then pans between the rear and front
+/// pairs using the same law, where -1 is panned full rear and 1 is panned full
+/// front. Values outside of the range are clamped to it.
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+impl Panner<(f32, f32)> for Quad {
+    fn to_sample_format(s: Sample, g: (f32, f32)) -> Self {
+        let lr = Stereo::to_sample_format(s, g.0);
+        let rf = Stereo::to_sample_format(Sample(1.0), g.1);
+
+        Quad {
+            front_left: Sample(lr.left.0 * rf.right.0),
+            front_right: Sample(lr.right.0 * rf.right.0),
+            rear_left: Sample(lr.left.0 * rf.left.0),
+            rear_right: Sample(lr.right.0 * rf.left.0),
+        }
+    }
+}
+impl Panner<(f64, f64)> for Quad {
+    fn to_sample_format(s: Sample, g: (f64, f64)) -> Self {
+        let lr = Stereo::to_sample_format(s, g.0);
+        let rf = Stereo::to_sample_format(Sample(1.0), g.1);
+
+        Quad {
+            front_left: Sample(lr.left.0 * rf.right.0),
+            front_right: Sample(lr.right.0 * rf.right.0),
+            rear_left: Sample(lr.left.0 * rf.left.0),
+            rear_right: Sample(lr.right.0 * rf.left.0),
+        }
+    }
+}
+
+/// Pans a given sample pairwise around the four speakers, placed at azimuths
+/// of -45, 45, -135 and 135 degrees, as done by classic quadraphonic pan pots.
+/// The sample is panned with constant power between the adjacent pair of
+/// speakers, being the front, right, rear or left pair depending on its
+/// azimuth. As the layout has no height speakers the elevation is ignored.
+impl Panner<SphericalPosition> for Quad {
+    fn to_sample_format(s: Sample, g: SphericalPosition) -> Self {
+        Quad::pan(s, g.azimuth)
+    }
+}
+
+impl From<Stereo> for Quad {
+    fn from(s: Stereo) -> Self {
+        s.convert_format()
+    }
+}
+
+impl Balancer for Quad {}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Quad {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.front_left,
+            1 => &self.front_right,
+            2 => &self.rear_left,
+            3 => &self.rear_right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Quad with 4 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Quad {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.front_left,
+            1 => &mut self.front_right,
+            2 => &mut self.rear_left,
+            3 => &mut self.rear_right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Quad with 4 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Quad {
+    fn as_ref(&self) -> &[Sample] {
+        // Quad is #[repr(C)] and made up solely of 4 Sample fields.
+        unsafe { channel::as_slice(self, 4) }
+    }
+}
+impl AsMut<[Sample]> for Quad {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Quad is #[repr(C)] and made up solely of 4 Sample fields.
+        unsafe { channel::as_mut_slice(self, 4) }
+    }
+}
+
+impl std::ops::Neg for Quad {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Quad {
+            front_left: Sample(-self.front_left.0),
+            front_right: Sample(-self.front_right.0),
+            rear_left: Sample(-self.rear_left.0),
+            rear_right: Sample(-self.rear_right.0),
+        }
+    }
+}
+
+impl std::ops::Add<Quad> for Quad {
+    type Output = Self;
+
+    fn add(self, rhs: Quad) -> Self::Output {
+        Quad {
+            front_left: Sample(self.front_left.0 + rhs.front_left.0),
+            front_right: Sample(self.front_right.0 + rhs.front_right.0),
+            rear_left: Sample(self.rear_left.0 + rhs.rear_left.0),
+            rear_right: Sample(self.rear_right.0 + rhs.rear_right.0),
+        }
+    }
+}
+impl std::ops::AddAssign<Quad> for Quad {
+    fn add_assign(&mut self, rhs: Quad) {
+        self.front_left.0 += rhs.front_left.0;
+        self.front_right.0 += rhs.front_right.0;
+        self.rear_left.0 += rhs.rear_left.0;
+        self.rear_right.0 += rhs.rear_right.0;
+    }
+}
+
+impl std::ops::Sub<Quad> for Quad {
+    type Output = Self;
+
+    fn sub(self, rhs: Quad) -> Self {
+        Quad {
+            front_left: Sample(self.front_left.0 - rhs.front_left.0),
+            front_right: Sample(self.front_right.0 - rhs.front_right.0),
+            rear_left: Sample(self.rear_left.0 - rhs.rear_left.0),
+            rear_right: Sample(self.rear_right.0 - rhs.rear_right.0),
+        }
+    }
+}
+impl std::ops::SubAssign<Quad> for Quad {
+    fn sub_assign(&mut self, rhs: Quad) {
+        self.front_left.0 -= rhs.front_left.0;
+        self.front_right.0 -= rhs.front_right.0;
+        self.rear_left.0 -= rhs.rear_left.0;
+        self.rear_right.0 -= rhs.rear_right.0;
+    }
+}
+
+impl std::ops::Mul<Quad> for Quad {
+    type Output = Quad;
+
+    fn mul(self, rhs: Quad) -> Self::Output {
+        Quad {
+            front_left: Sample(self.front_left.0 * rhs.front_left.0),
+            front_right: Sample(self.front_right.0 * rhs.front_right.0),
+            rear_left: Sample(self.rear_left.0 * rhs.rear_left.0),
+            rear_right: Sample(self.rear_right.0 * rhs.rear_right.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Quad> for Quad {
+    fn mul_assign(&mut self, rhs: Quad) {
+        self.front_left.0 *= rhs.front_left.0;
+        self.front_right.0 *= rhs.front_right.0;
+        self.rear_left.0 *= rhs.rear_left.0;
+        self.rear_right.0 *= rhs.rear_right.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for Quad {
+    type Output = Quad;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        Quad {
+            front_left: Sample(self.front_left.0 * rhs.0),
+            front_right: Sample(self.front_right.0 * rhs.0),
+            rear_left: Sample(self.rear_left.0 * rhs.0),
+            rear_right: Sample(self.rear_right.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for Quad {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.front_left.0 *= rhs.0;
+        self.front_right.0 *= rhs.0;
+        self.rear_left.0 *= rhs.0;
+        self.rear_right.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for Quad {
+    type Output = Quad;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        Quad {
+            front_left: Sample((self.front_left.0 as AccurateMath * rhs.0) as FastMath),
+            front_right: Sample((self.front_right.0 as AccurateMath * rhs.0) as FastMath),
+            rear_left: Sample((self.rear_left.0 as AccurateMath * rhs.0) as FastMath),
+            rear_right: Sample((self.rear_right.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for Quad {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.front_left.0 *= rhs.0 as FastMath;
+        self.front_right.0 *= rhs.0 as FastMath;
+        self.rear_left.0 *= rhs.0 as FastMath;
+        self.rear_right.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for Quad {
+    type Output = Quad;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Quad {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Quad {
+    type Output = Quad;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Quad {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 4]> for Quad {
+    type Output = Quad;
+
+    fn mul(mut self, rhs: [Sample; 4]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 4]> for Quad {
+    fn mul_assign(&mut self, rhs: [Sample; 4]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Quad {
+    fn sum<I: Iterator<Item = Quad>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Quad> for Quad {
+    fn sum<I: Iterator<Item = &'a Quad>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Quad {
+    fn product<I: Iterator<Item = Quad>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Quad> for Quad {
+    fn product<I: Iterator<Item = &'a Quad>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for Quad {
+    fn from(s: Sample) -> Self {
+        Quad::from_sample(s)
+    }
+}
+impl Into<Sample> for Quad {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 4]> for Quad {
+    fn from(a: [Sample; 4]) -> Self {
+        Quad {
+            front_left: a[0],
+            front_right: a[1],
+            rear_left: a[2],
+            rear_right: a[3],
+        }
+    }
+}
+impl Into<[Sample; 4]> for Quad {
+    fn into(self) -> [Sample; 4] {
+        [
+            self.front_left,
+            self.front_right,
+            self.rear_left,
+            self.rear_right,
+        ]
+    }
+}
+
+impl From<(Sample, Sample, Sample, Sample)> for Quad {
+    fn from(t: (Sample, Sample, Sample, Sample)) -> Self {
+        Quad {
+            front_left: t.0,
+            front_right: t.1,
+            rear_left: t.2,
+            rear_right: t.3,
+        }
+    }
+}
+impl Into<(Sample, Sample, Sample, Sample)> for Quad {
+    fn into(self) -> (Sample, Sample, Sample, Sample) {
+        (
+            self.front_left,
+            self.front_right,
+            self.rear_left,
+            self.rear_right,
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for Quad {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 4 {
+            Err(SampleFormatError::WrongLength {
+                expected: 4,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Quad {
+                front_left: sample_from_u8(v[0]),
+                front_right: sample_from_u8(v[1]),
+                rear_left: sample_from_u8(v[2]),
+                rear_right: sample_from_u8(v[3]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for Quad {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for Quad {
+    fn into(self) -> Vec<u8> {
+        vec![
+            sample_to_u8(self.front_left),
+            sample_to_u8(self.front_right),
+            sample_to_u8(self.rear_left),
+            sample_to_u8(self.rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[i16]> for Quad {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 4 {
+            Err(SampleFormatError::WrongLength {
+                expected: 4,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Quad {
+                front_left: sample_from_i16(v[0]),
+                front_right: sample_from_i16(v[1]),
+                rear_left: sample_from_i16(v[2]),
+                rear_right: sample_from_i16(v[3]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for Quad {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for Quad {
+    fn into(self) -> Vec<i16> {
+        vec![
+            sample_to_i16(self.front_left),
+            sample_to_i16(self.front_right),
+            sample_to_i16(self.rear_left),
+            sample_to_i16(self.rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[i32]> for Quad {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 4 {
+            Err(SampleFormatError::WrongLength {
+                expected: 4,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Quad {
+                front_left: sample_from_i24(v[0]),
+                front_right: sample_from_i24(v[1]),
+                rear_left: sample_from_i24(v[2]),
+                rear_right: sample_from_i24(v[3]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for Quad {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for Quad {
+    fn into(self) -> Vec<i32> {
+        vec![
+            sample_to_i24(self.front_left),
+            sample_to_i24(self.front_right),
+            sample_to_i24(self.rear_left),
+            sample_to_i24(self.rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[f32]> for Quad {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 4 {
+            Err(SampleFormatError::WrongLength {
+                expected: 4,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Quad {
+                front_left: sample_from_f32(v[0]),
+                front_right: sample_from_f32(v[1]),
+                rear_left: sample_from_f32(v[2]),
+                rear_right: sample_from_f32(v[3]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Quad {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Quad {
+    fn into(self) -> Vec<f32> {
+        vec![
+            sample_to_f32(self.front_left),
+            sample_to_f32(self.front_right),
+            sample_to_f32(self.rear_left),
+            sample_to_f32(self.rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[f64]> for Quad {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 4 {
+            Err(SampleFormatError::WrongLength {
+                expected: 4,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Quad {
+                front_left: sample_from_f64(v[0]),
+                front_right: sample_from_f64(v[1]),
+                rear_left: sample_from_f64(v[2]),
+                rear_right: sample_from_f64(v[3]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Quad {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Quad {
+    fn into(self) -> Vec<f64> {
+        vec![
+            sample_to_f64(self.front_left),
+            sample_to_f64(self.front_right),
+            sample_to_f64(self.rear_left),
+            sample_to_f64(self.rear_right),
+        ]
+    }
+}
@@ -0,0 +1,272 @@
+//! # Resample
+//!
+//! Module containing sample-rate conversion of tracks and streams of samples,
+//! with resamplers keeping their state between chunks of a stream.
+
+use super::*;
+
+/// Number of zero crossings on each side of the kernel of the
+/// [`SincResampler`] used by [`resample_sinc`].
+///
+/// [`SincResampler`]: struct.SincResampler.html
+/// [`resample_sinc`]: fn.resample_sinc.html
+pub const DEFAULT_SINC_TAPS: usize = 16;
+
+/// Trait implementing a stateful sample-rate converter, fed a stream of samples
+/// in chunks of any length.
+pub trait Resampler<F: SampleFormat + Copy> {
+    /// Returns the ratio of the output sample rate to the input sample rate.
+    fn ratio(&self) -> AccurateMath;
+
+    /// Resamples the next chunk of the input, appending every output sample
+    /// that can be computed from the input so far to `output`. Output samples
+    /// needing input that hasn't been fed yet are produced by later calls.
+    ///
+    /// # Parameters
+    ///
+    /// * `input` - the next chunk of the input.
+    /// * `output` - the buffer the output samples are appended to.
+    fn process(&mut self, input: &[F], output: &mut Vec<F>);
+
+    /// Appends the output samples still held back waiting for more input, as
+    /// at the end of a stream. The resampler should be reset before being fed
+    /// a new stream.
+    ///
+    /// # Parameters
+    ///
+    /// * `output` - the buffer the output samples are appended to.
+    fn flush(&mut self, output: &mut Vec<F>);
+
+    /// Clears the internal state of the resampler.
+    fn reset(&mut self);
+
+    /// Resets the resampler and resamples the whole track, returning a track
+    /// of the length of the input scaled by the [`ratio`], rounded up.
+    ///
+    /// # Parameters
+    ///
+    /// * `track` - the samples to resample.
+    ///
+    /// [`ratio`]: #tymethod.ratio
+    fn resample_track(&mut self, track: &[F]) -> Track<F> {
+        let len = (track.len() as AccurateMath * self.ratio()).ceil() as usize;
+        let mut output = Vec::with_capacity(len + 1);
+
+        self.reset();
+        self.process(track, &mut output);
+        self.flush(&mut output);
+        self.reset();
+
+        output.truncate(len);
+        output.into()
+    }
+}
+
+/// Resampler interpolating linearly between neighboring input samples.
+///
+/// It is fast and adds no latency, but doesn't filter the signal, so
+/// downsampling aliases the frequencies above the new Nyquist frequency and
+/// upsampling leaves images of the spectrum above the old one.
+#[derive(Debug, Clone)]
+pub struct LinearResampler<F> {
+    step: AccurateMath,
+    position: AccurateMath,
+    buffer: Vec<F>,
+}
+
+impl<F: SampleFormat + Copy> LinearResampler<F> {
+    /// Creates a new LinearResampler between the given sample rates.
+    ///
+    /// # Parameters
+    ///
+    /// * `from` - the sample rate of the input in Hz.
+    /// * `to` - the sample rate of the output in Hz.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either sample rate isn't positive.
+    pub fn new(from: Math, to: Math) -> Self {
+        LinearResampler {
+            step: step(from, to),
+            position: 0.0,
+            buffer: Vec::new(),
+        }
+    }
+}
+
+impl<F: SampleFormat + Copy> Resampler<F> for LinearResampler<F> {
+    fn ratio(&self) -> AccurateMath {
+        1.0 / self.step
+    }
+
+    fn process(&mut self, input: &[F], output: &mut Vec<F>) {
+        self.buffer.extend_from_slice(input);
+
+        while (self.position as usize) + 1 < self.buffer.len() {
+            let i = self.position as usize;
+            let t = self.position - i as AccurateMath;
+            output.push(self.buffer[i].lerp(self.buffer[i + 1], Math(t)));
+            self.position += self.step;
+        }
+
+        let consumed = (self.position as usize).min(self.buffer.len());
+        self.buffer.drain(..consumed);
+        self.position -= consumed as AccurateMath;
+    }
+
+    fn flush(&mut self, output: &mut Vec<F>) {
+        // Holding the last sample interpolates up to the end of the input.
+        if let Some(last) = self.buffer.last().copied() {
+            self.process(&[last], output);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.position = 0.0;
+        self.buffer.clear();
+    }
+}
+
+/// Resampler convolving the input with a Blackman-windowed sinc kernel, an
+/// approximation of ideal band-limited interpolation.
+///
+/// When downsampling, the cutoff of the kernel is lowered to the new Nyquist
+/// frequency and its width grows to match, so that frequencies which can't be
+/// represented are filtered out rather than aliased. The output is aligned
+/// with the input, but is held back by the half width of the kernel until
+/// enough input has been fed.
+#[derive(Debug, Clone)]
+pub struct SincResampler<F> {
+    step: AccurateMath,
+    cutoff: AccurateMath,
+    width: usize,
+    position: AccurateMath,
+    buffer: Vec<F>,
+}
+
+impl<F: SampleFormat + Copy> SincResampler<F> {
+    /// Creates a new SincResampler between the given sample rates.
+    ///
+    /// # Parameters
+    ///
+    /// * `from` - the sample rate of the input in Hz.
+    /// * `to` - the sample rate of the output in Hz.
+    /// * `taps` - the number of zero crossings of the kernel on each side of
+    /// its center, of at least 1. More taps filter more sharply at a higher
+    /// cost.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either sample rate isn't positive, or `taps` is 0.
+    pub fn new(from: Math, to: Math, taps: usize) -> Self {
+        assert!(taps > 0, "the kernel needs at least 1 tap");
+
+        let step = step(from, to);
+        let cutoff = (1.0 / step).min(1.0);
+        let width = (taps as AccurateMath / cutoff).ceil() as usize;
+
+        SincResampler {
+            step,
+            cutoff,
+            width,
+            position: width as AccurateMath,
+            buffer: vec![F::SILENCE; width],
+        }
+    }
+
+    /// Returns the weight of the input sample `x` samples away from the
+    /// output sample.
+    fn kernel(&self, x: AccurateMath) -> AccurateMath {
+        use std::f64::consts::PI;
+
+        let u = x / self.width as AccurateMath;
+        if u.abs() >= 1.0 {
+            return 0.0;
+        }
+
+        let window = 0.42 + 0.5 * (PI * u).cos() + 0.08 * (2.0 * PI * u).cos();
+        let arg = PI * self.cutoff * x;
+        let sinc = if arg == 0.0 { 1.0 } else { arg.sin() / arg };
+
+        self.cutoff * sinc * window
+    }
+}
+
+impl<F: SampleFormat + Copy> Resampler<F> for SincResampler<F> {
+    fn ratio(&self) -> AccurateMath {
+        1.0 / self.step
+    }
+
+    fn process(&mut self, input: &[F], output: &mut Vec<F>) {
+        self.buffer.extend_from_slice(input);
+
+        while (self.position as usize) + self.width < self.buffer.len() {
+            let center = self.position as usize;
+            let mut s = F::SILENCE;
+            for k in center + 1 - self.width..=center + self.width {
+                s += self.buffer[k] * self.kernel(self.position - k as AccurateMath);
+            }
+            output.push(s);
+            self.position += self.step;
+        }
+
+        let consumed =
+            ((self.position as usize + 1).saturating_sub(self.width)).min(self.buffer.len());
+        self.buffer.drain(..consumed);
+        self.position -= consumed as AccurateMath;
+    }
+
+    fn flush(&mut self, output: &mut Vec<F>) {
+        self.process(&vec![F::SILENCE; self.width], output);
+    }
+
+    fn reset(&mut self) {
+        self.position = self.width as AccurateMath;
+        self.buffer.clear();
+        self.buffer.resize(self.width, F::SILENCE);
+    }
+}
+
+/// Resamples the track between the given sample rates with a
+/// [`LinearResampler`].
+///
+/// # Parameters
+///
+/// * `track` - the samples to resample.
+/// * `from` - the sample rate of the track in Hz.
+/// * `to` - the sample rate of the result in Hz.
+///
+/// # Panics
+///
+/// Panics if either sample rate isn't positive.
+///
+/// [`LinearResampler`]: struct.LinearResampler.html
+pub fn resample_linear<F: SampleFormat + Copy>(track: &Track<F>, from: Math, to: Math) -> Track<F> {
+    LinearResampler::new(from, to).resample_track(track)
+}
+
+/// Resamples the track between the given sample rates with a
+/// [`SincResampler`] of [`DEFAULT_SINC_TAPS`] taps.
+///
+/// # Parameters
+///
+/// * `track` - the samples to resample.
+/// * `from` - the sample rate of the track in Hz.
+/// * `to` - the sample rate of the result in Hz.
+///
+/// # Panics
+///
+/// Panics if either sample rate isn't positive.
+///
+/// [`SincResampler`]: struct.SincResampler.html
+/// [`DEFAULT_SINC_TAPS`]: constant.DEFAULT_SINC_TAPS.html
+pub fn resample_sinc<F: SampleFormat + Copy>(track: &Track<F>, from: Math, to: Math) -> Track<F> {
+    SincResampler::new(from, to, DEFAULT_SINC_TAPS).resample_track(track)
+}
+
+/// Returns the distance between output samples in input samples.
+fn step(from: Math, to: Math) -> AccurateMath {
+    assert!(from.0 > 0.0 && to.0 > 0.0, "sample rates must be positive");
+
+    from.0 / to.0
+}
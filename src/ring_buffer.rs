@@ -0,0 +1,381 @@
+//! # Ring Buffer
+//!
+//! Module containing a fixed-capacity ring buffer of samples, as used for
+//! delay lines and for handing audio between a producer and a consumer.
+
+use super::*;
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Enum of the ways a [`RingBuffer`] handles writes when it is full.
+///
+/// [`RingBuffer`]: struct.RingBuffer.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RingBufferPolicy {
+    /// The oldest sample is discarded to make room, as for delay lines, which
+    /// always hold the most recent samples.
+    Overwrite,
+    /// The write is blocked, leaving the buffer unchanged, as for producers
+    /// that must not drop samples the consumer hasn't read yet.
+    Block,
+}
+
+/// Fixed-capacity first-in first-out buffer of samples of the format `F`.
+///
+/// The capacity is a power of two, so that positions wrap with a mask rather
+/// than a division, and the storage is allocated once on creation. Samples are
+/// written with [`push`] and read in the order they were written with
+/// [`pop`], while [`tap`] reads a sample a given delay back without removing
+/// it, as for delay lines.
+///
+/// The buffer is owned by a single thread at a time. To hand audio from one
+/// thread to another without locking, such as to or from a real-time audio
+/// thread, it can be [`split`] into a [`Producer`] and a [`Consumer`].
+///
+/// [`push`]: #method.push
+/// [`pop`]: #method.pop
+/// [`tap`]: #method.tap
+/// [`split`]: #method.split
+/// [`Producer`]: struct.Producer.html
+/// [`Consumer`]: struct.Consumer.html
+#[derive(Debug, Clone)]
+pub struct RingBuffer<F> {
+    buffer: Vec<F>,
+    mask: usize,
+    read: usize,
+    len: usize,
+    policy: RingBufferPolicy,
+}
+
+impl<F: SampleFormat + Copy> RingBuffer<F> {
+    /// Creates a new empty RingBuffer.
+    ///
+    /// # Parameters
+    ///
+    /// * `capacity` - the least number of samples held, rounded up to the next
+    /// power of two.
+    /// * `policy` - the handling of writes when the buffer is full.
+    pub fn new(capacity: usize, policy: RingBufferPolicy) -> Self {
+        let capacity = capacity.next_power_of_two();
+
+        RingBuffer {
+            buffer: vec![F::SILENCE; capacity],
+            mask: capacity - 1,
+            read: 0,
+            len: 0,
+            policy,
+        }
+    }
+
+    /// Returns the number of samples the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the number of samples in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the buffer holds no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether the buffer holds as many samples as it can.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Returns the handling of writes when the buffer is full.
+    pub fn policy(&self) -> RingBufferPolicy {
+        self.policy
+    }
+
+    /// Writes a sample to the buffer. Returns whether it was written, which
+    /// is only false for a full buffer with the [`Block`] policy.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the sample to write.
+    ///
+    /// [`Block`]: enum.RingBufferPolicy.html#variant.Block
+    pub fn push(&mut self, s: F) -> bool {
+        if self.is_full() {
+            match self.policy {
+                RingBufferPolicy::Overwrite => {
+                    self.read = (self.read + 1) & self.mask;
+                    self.len -= 1;
+                }
+                RingBufferPolicy::Block => return false,
+            }
+        }
+
+        self.buffer[(self.read + self.len) & self.mask] = s;
+        self.len += 1;
+        true
+    }
+
+    /// Writes the samples to the buffer in order. Returns the number of
+    /// samples written, which is less than given only for the [`Block`]
+    /// policy, once the buffer is full.
+    ///
+    /// # Parameters
+    ///
+    /// * `samples` - the samples to write.
+    ///
+    /// [`Block`]: enum.RingBufferPolicy.html#variant.Block
+    pub fn push_slice(&mut self, samples: &[F]) -> usize {
+        samples.iter().take_while(|s| self.push(**s)).count()
+    }
+
+    /// Removes and returns the oldest sample in the buffer, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<F> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let s = self.buffer[self.read];
+        self.read = (self.read + 1) & self.mask;
+        self.len -= 1;
+        Some(s)
+    }
+
+    /// Removes the oldest samples from the buffer into the given slice, in
+    /// order. Returns the number of samples read, which is less than the
+    /// length of the slice if the buffer runs out.
+    ///
+    /// # Parameters
+    ///
+    /// * `samples` - the slice the samples are read into.
+    pub fn pop_slice(&mut self, samples: &mut [F]) -> usize {
+        let n = samples.len().min(self.len);
+        for s in samples.iter_mut().take(n) {
+            *s = self.buffer[self.read];
+            self.read = (self.read + 1) & self.mask;
+        }
+
+        self.len -= n;
+        n
+    }
+
+    /// Returns the sample written the given number of writes before the most
+    /// recent one, without removing it. Returns silence if the buffer doesn't
+    /// hold a sample that far back.
+    ///
+    /// # Parameters
+    ///
+    /// * `delay` - the number of samples back, where 0 is the most recent.
+    pub fn tap(&self, delay: usize) -> F {
+        if delay >= self.len {
+            return F::SILENCE;
+        }
+
+        self.buffer[(self.read + self.len - 1 - delay) & self.mask]
+    }
+
+    /// Removes every sample from the buffer.
+    pub fn clear(&mut self) {
+        self.read = 0;
+        self.len = 0;
+    }
+
+    /// Splits the buffer into a [`Producer`] writing samples and a
+    /// [`Consumer`] reading them, which can be sent to different threads and
+    /// share the storage of the buffer without locking. The samples in the
+    /// buffer are kept.
+    ///
+    /// The halves always block writes when the buffer is full, whatever the
+    /// policy of the buffer, as discarding the oldest sample would race with
+    /// the consumer reading it.
+    ///
+    /// [`Producer`]: struct.Producer.html
+    /// [`Consumer`]: struct.Consumer.html
+    pub fn split(self) -> (Producer<F>, Consumer<F>) {
+        let shared = Arc::new(Shared {
+            buffer: self.buffer.into_iter().map(UnsafeCell::new).collect(),
+            mask: self.mask,
+            read: AtomicUsize::new(self.read),
+            write: AtomicUsize::new(self.read + self.len),
+        });
+
+        (
+            Producer {
+                shared: Arc::clone(&shared),
+            },
+            Consumer { shared },
+        )
+    }
+}
+
+/// Storage shared by the [`Producer`] and [`Consumer`] of a split
+/// [`RingBuffer`].
+///
+/// The read and write positions count every sample read and written, wrapping
+/// on overflow, and are masked to index the storage. Their difference is the
+/// number of samples in the buffer. Each position is only advanced by its own
+/// half, after the slot it passes is read or written.
+///
+/// [`Producer`]: struct.Producer.html
+/// [`Consumer`]: struct.Consumer.html
+/// [`RingBuffer`]: struct.RingBuffer.html
+#[derive(Debug)]
+struct Shared<F> {
+    buffer: Box<[UnsafeCell<F>]>,
+    mask: usize,
+    read: AtomicUsize,
+    write: AtomicUsize,
+}
+
+// The slots between the read and write positions are only accessed by the
+// consumer, and the others only by the producer, so no slot is accessed by
+// both threads at once.
+unsafe impl<F: Send> Sync for Shared<F> {}
+
+impl<F> Shared<F> {
+    fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn len(&self) -> usize {
+        let write = self.write.load(Ordering::Acquire);
+        let read = self.read.load(Ordering::Acquire);
+
+        write.wrapping_sub(read)
+    }
+}
+
+/// Writing half of a [`RingBuffer`] split with [`split`], which can be sent to
+/// another thread than its [`Consumer`].
+///
+/// [`RingBuffer`]: struct.RingBuffer.html
+/// [`split`]: struct.RingBuffer.html#method.split
+/// [`Consumer`]: struct.Consumer.html
+#[derive(Debug)]
+pub struct Producer<F> {
+    shared: Arc<Shared<F>>,
+}
+
+impl<F: SampleFormat + Copy> Producer<F> {
+    /// Returns the number of samples the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity()
+    }
+
+    /// Returns the number of samples in the buffer. The consumer may read
+    /// samples concurrently, so the buffer holds at most this many.
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    /// Returns whether the buffer holds no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the buffer holds as many samples as it can.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Writes a sample to the buffer. Returns whether it was written, which
+    /// is only false if the buffer is full.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the sample to write.
+    pub fn push(&mut self, s: F) -> bool {
+        let shared = &*self.shared;
+        let write = shared.write.load(Ordering::Relaxed);
+        let read = shared.read.load(Ordering::Acquire);
+
+        if write.wrapping_sub(read) == shared.capacity() {
+            return false;
+        }
+
+        // The slot at the write position isn't read by the consumer until the
+        // write position is advanced past it.
+        unsafe { *shared.buffer[write & shared.mask].get() = s };
+        shared.write.store(write.wrapping_add(1), Ordering::Release);
+        true
+    }
+
+    /// Writes the samples to the buffer in order. Returns the number of
+    /// samples written, which is less than given once the buffer is full.
+    ///
+    /// # Parameters
+    ///
+    /// * `samples` - the samples to write.
+    pub fn push_slice(&mut self, samples: &[F]) -> usize {
+        samples.iter().take_while(|s| self.push(**s)).count()
+    }
+}
+
+/// Reading half of a [`RingBuffer`] split with [`split`], which can be sent to
+/// another thread than its [`Producer`].
+///
+/// [`RingBuffer`]: struct.RingBuffer.html
+/// [`split`]: struct.RingBuffer.html#method.split
+/// [`Producer`]: struct.Producer.html
+#[derive(Debug)]
+pub struct Consumer<F> {
+    shared: Arc<Shared<F>>,
+}
+
+impl<F: SampleFormat + Copy> Consumer<F> {
+    /// Returns the number of samples the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity()
+    }
+
+    /// Returns the number of samples in the buffer. The producer may write
+    /// samples concurrently, so the buffer holds at least this many.
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    /// Returns whether the buffer holds no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes and returns the oldest sample in the buffer, or `None` if it is
+    /// empty.
+    pub fn pop(&mut self) -> Option<F> {
+        let shared = &*self.shared;
+        let read = shared.read.load(Ordering::Relaxed);
+        let write = shared.write.load(Ordering::Acquire);
+
+        if read == write {
+            return None;
+        }
+
+        // The slot at the read position isn't written by the producer until
+        // the read position is advanced past it.
+        let s = unsafe { *shared.buffer[read & shared.mask].get() };
+        shared.read.store(read.wrapping_add(1), Ordering::Release);
+        Some(s)
+    }
+
+    /// Removes the oldest samples from the buffer into the given slice, in
+    /// order. Returns the number of samples read, which is less than the
+    /// length of the slice if the buffer runs out.
+    ///
+    /// # Parameters
+    ///
+    /// * `samples` - the slice the samples are read into.
+    pub fn pop_slice(&mut self, samples: &mut [F]) -> usize {
+        let mut n = 0;
+        for s in samples.iter_mut() {
+            match self.pop() {
+                Some(x) => *s = x,
+                None => break,
+            }
+            n += 1;
+        }
+
+        n
+    }
+}
@@ -0,0 +1,119 @@
+//! # Routing
+//!
+//! Crate-private helpers deciding how the channels of one sample format are
+//! routed to the channels of another, shared by the format conversions.
+
+use super::*;
+use std::f64::consts::FRAC_1_SQRT_2;
+
+/// The channels a single source channel is routed to, as the index of each
+/// target channel and the linear gain applied on the way.
+pub(crate) type Route = [Option<(usize, AccurateMath)>; 2];
+
+/// Decides which channels of the target layout a channel with the given label
+/// is routed to.
+///
+/// A channel is routed at unity gain to a channel with the same label. If there
+/// is none, speaker channels are folded into the nearest speaker on the same
+/// side of the listener at -3 dB, and center speakers into the nearest center
+/// speaker or split between the nearest left and right pair at -3 dB each,
+/// whichever is closer. Mid/side channels are decoded into the front pair and
+/// encoded from it. The LFE, Ambisonic and discrete channels are only routed
+/// to channels with the same label, and are otherwise dropped.
+///
+/// # Parameters
+///
+/// * `label` - the label of the source channel.
+/// * `to` - the labels of the channels of the target format.
+pub(crate) fn route(label: ChannelLabel, to: &[ChannelLabel]) -> Route {
+    let find = |l: ChannelLabel| to.iter().position(|t| *t == l);
+
+    if let Some(i) = find(label) {
+        return [Some((i, 1.0)), None];
+    }
+
+    let position = match label {
+        ChannelLabel::Lfe2 => return [find(ChannelLabel::Lfe).map(|i| (i, 1.0)), None],
+        ChannelLabel::Mid | ChannelLabel::Side => return decode_mid_side(label, to),
+        _ => match label.position() {
+            Some(p) => Vector3::from_direction(p),
+            None => return [None, None],
+        },
+    };
+
+    if let (Some(m), Some(s)) = (find(ChannelLabel::Mid), find(ChannelLabel::Side)) {
+        return if label.is_left() {
+            [Some((m, 0.5)), Some((s, 0.5))]
+        } else if label.is_right() {
+            [Some((m, 0.5)), Some((s, -0.5))]
+        } else {
+            [Some((m, 1.0)), None]
+        };
+    }
+
+    let center = nearest(position, to, is_center);
+    let left = nearest(position, to, ChannelLabel::is_left);
+    let right = nearest(position, to, ChannelLabel::is_right);
+
+    let target = if label.is_left() {
+        left
+    } else if label.is_right() {
+        right
+    } else {
+        match (center, left.zip(right)) {
+            (Some(c), Some((l, _))) if l.1 <= c.1 => center,
+            (_, Some((l, r))) => return [Some((l.0, FRAC_1_SQRT_2)), Some((r.0, FRAC_1_SQRT_2))],
+            _ => center,
+        }
+    };
+
+    [target.or(center).map(|(i, _)| (i, FRAC_1_SQRT_2)), None]
+}
+
+/// Routes a mid or side channel into the front pair of the target layout,
+/// where the left channel is the sum of the mid and side channels and the
+/// right channel is the difference. Without a pair, the mid channel is routed
+/// as a center channel and the side channel is dropped.
+fn decode_mid_side(label: ChannelLabel, to: &[ChannelLabel]) -> Route {
+    let front = Vector3::from_direction(SphericalPosition::default());
+    let left = nearest(front, to, ChannelLabel::is_left);
+    let right = nearest(front, to, ChannelLabel::is_right);
+    let sign = if label == ChannelLabel::Side {
+        -1.0
+    } else {
+        1.0
+    };
+
+    match (left, right) {
+        (Some(l), Some(r)) => [Some((l.0, 1.0)), Some((r.0, sign))],
+        _ if label == ChannelLabel::Mid => route(ChannelLabel::Center, to),
+        _ => [None, None],
+    }
+}
+
+/// Returns true if the channel is a speaker in the median plane of the
+/// listener, neither to the left nor to the right.
+fn is_center(label: ChannelLabel) -> bool {
+    label.position().is_some() && !label.is_left() && !label.is_right()
+}
+
+/// Finds the speaker of the target layout matching the given predicate that is
+/// nearest to the given direction. Returns its index and the cosine of its
+/// angle from the direction.
+fn nearest(
+    direction: Vector3,
+    to: &[ChannelLabel],
+    predicate: impl Fn(ChannelLabel) -> bool,
+) -> Option<(usize, AccurateMath)> {
+    to.iter()
+        .enumerate()
+        .filter(|(_, l)| predicate(**l))
+        .filter_map(|(i, l)| {
+            l.position()
+                .map(|p| (i, Vector3::from_direction(p).dot(direction)))
+        })
+        .fold(None, |best: Option<(usize, AccurateMath)>, c| match best {
+            Some(b) if b.1 >= c.1 => Some(b),
+            _ => Some(c),
+        })
+}
@@ -0,0 +1,125 @@
+//! # Serialize
+//!
+//! Module implementing the [`serde`] traits for the sample formats. Each sample
+//! is represented as a sequence of its channel values, in the same order as
+//! the labels given by [`channel_labels`].
+//!
+//! [`serde`]: https://docs.rs/serde
+//! [`channel_labels`]: ../trait.SampleFormat.html#tymethod.channel_labels
+
+use super::*;
+use serde::de::{self, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeTuple};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Serializes a sample format as a tuple of its channel values.
+fn serialize<F: SampleFormat, S: Serializer>(s: &F, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut t = serializer.serialize_tuple(F::CHANNEL_COUNT)?;
+    for i in 0..F::CHANNEL_COUNT {
+        t.serialize_element(&s[i].0)?;
+    }
+    t.end()
+}
+
+/// Deserializes a sample format from a tuple of its channel values.
+fn deserialize<'de, F: SampleFormat, D: Deserializer<'de>>(d: D) -> Result<F, D::Error> {
+    d.deserialize_tuple(F::CHANNEL_COUNT, FormatVisitor(PhantomData))
+}
+
+/// Visitor reading the channel values of a sample format from a sequence.
+struct FormatVisitor<F>(PhantomData<F>);
+
+impl<'de, F: SampleFormat> Visitor<'de> for FormatVisitor<F> {
+    type Value = F;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of {} channel values", F::CHANNEL_COUNT)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<F, A::Error> {
+        let mut s = F::default();
+        for i in 0..F::CHANNEL_COUNT {
+            let x: FastMath = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            s[i] = Sample(x);
+        }
+        Ok(s)
+    }
+}
+
+macro_rules! impl_serde {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Serialize for $t {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize(self, serializer)
+                }
+            }
+            impl<'de> Deserialize<'de> for $t {
+                fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                    deserialize(d)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde!(
+    AmbisonicB,
+    Binaural,
+    DualMono,
+    Lcr,
+    MidSide,
+    Mono,
+    Quad,
+    Stereo,
+    Surround21,
+    Surround222,
+    Surround51,
+    Surround512,
+    Surround61,
+    Surround71,
+    Surround714,
+);
+
+impl<const N: usize> Serialize for MultiChannel<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+impl<'de, const N: usize> Deserialize<'de> for MultiChannel<N> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        deserialize(d)
+    }
+}
+
+impl<const ORDER: usize, const N: usize> Serialize for Hoa<ORDER, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+impl<'de, const ORDER: usize, const N: usize> Deserialize<'de> for Hoa<ORDER, N> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        deserialize(d)
+    }
+}
+
+/// The channel count of a deserialized sample is the length of the sequence.
+impl Serialize for DynFormat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.channels.len()))?;
+        for c in self.channels.iter() {
+            seq.serialize_element(&c.0)?;
+        }
+        seq.end()
+    }
+}
+impl<'de> Deserialize<'de> for DynFormat {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let v = Vec::<FastMath>::deserialize(d)?;
+        Ok(DynFormat::from(v.into_iter().map(Sample).collect()))
+    }
+}
@@ -0,0 +1,219 @@
+//! # Spatial
+//!
+//! Module containing a listener and emitter model of a 3D scene, rendering
+//! monophonic emitters into any sample format with a positional panner.
+//!
+//! Positions are given in a right-handed coordinate system with x to the
+//! right, y to the front and z upwards, in any unit as long as the same unit
+//! is used for the rolloff distances of the emitters. Orientations are given as
+//! the [`SphericalPosition`] faced, such that a listener facing an azimuth of
+//! 0 and an elevation of 0 faces along the y axis.
+//!
+//! [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+
+use super::*;
+
+/// Struct representing a point or direction in 3D space.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Vector3 {
+    /// Offset to the right.
+    pub x: AccurateMath,
+    /// Offset to the front.
+    pub y: AccurateMath,
+    /// Offset upwards.
+    pub z: AccurateMath,
+}
+
+impl Vector3 {
+    /// Returns a new Vector3 object from the given coordinates.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - the offset to the right.
+    /// * `y` - the offset to the front.
+    /// * `z` - the offset upwards.
+    pub const fn new(x: AccurateMath, y: AccurateMath, z: AccurateMath) -> Self {
+        Vector3 { x, y, z }
+    }
+
+    /// Returns the unit vector pointing in the given direction.
+    ///
+    /// # Parameters
+    ///
+    /// * `direction` - the direction of the vector.
+    pub fn from_direction(direction: SphericalPosition) -> Self {
+        let a = direction.azimuth.to_radians();
+        let e = direction.elevation.to_radians();
+
+        Vector3::new(a.sin() * e.cos(), a.cos() * e.cos(), e.sin())
+    }
+
+    /// Returns the length of the vector.
+    pub fn length(self) -> AccurateMath {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the dot product of the vector with `other`.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the other vector.
+    pub fn dot(self, other: Vector3) -> AccurateMath {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the direction the vector points in. The zero vector points to
+    /// the front.
+    pub fn direction(self) -> SphericalPosition {
+        if self.length() == 0.0 {
+            return SphericalPosition::default();
+        }
+
+        SphericalPosition::new(
+            self.x.atan2(self.y).to_degrees(),
+            self.z.atan2(self.x.hypot(self.y)).to_degrees(),
+        )
+    }
+}
+
+impl Sub<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, rhs: Vector3) -> Self::Output {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// Struct representing the listener of a scene, from whose point of view the
+/// emitters are rendered.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Listener {
+    /// The position of the listener.
+    pub position: Vector3,
+    /// The direction the listener faces.
+    pub orientation: SphericalPosition,
+}
+
+impl Listener {
+    /// Returns a new listener at the given position, facing along the y axis.
+    ///
+    /// # Parameters
+    ///
+    /// * `position` - the position of the listener.
+    pub fn new(position: Vector3) -> Self {
+        Listener {
+            position,
+            orientation: SphericalPosition::default(),
+        }
+    }
+
+    /// Returns the direction of the given point relative to the orientation of
+    /// the listener, and its distance from the listener.
+    ///
+    /// # Parameters
+    ///
+    /// * `point` - the point to locate.
+    pub fn locate(&self, point: Vector3) -> (SphericalPosition, AccurateMath) {
+        let d = point - self.position;
+        let yaw = self.orientation.azimuth.to_radians();
+        let pitch = self.orientation.elevation.to_radians();
+
+        // Undoes the yaw around the z axis, then the pitch around the x axis
+        let x = d.x * yaw.cos() - d.y * yaw.sin();
+        let y = d.x * yaw.sin() + d.y * yaw.cos();
+        let relative = Vector3::new(
+            x,
+            y * pitch.cos() + d.z * pitch.sin(),
+            d.z * pitch.cos() - y * pitch.sin(),
+        );
+
+        (relative.direction(), d.length())
+    }
+}
+
+/// Struct representing a monophonic sound emitter within a scene.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Emitter {
+    /// The position of the emitter.
+    pub position: Vector3,
+    /// The direction the emitter faces.
+    pub orientation: SphericalPosition,
+    /// How directional the emitter is, from 0 for an omnidirectional emitter to
+    /// 1 for a cardioid emitter that is silent directly behind. Values outside
+    /// of the range \[0,1\] are clamped to it.
+    pub directivity: AccurateMath,
+    /// The width in degrees of the arc of the horizontal plane covered by the
+    /// emitter, as heard by the listener. 0 is a point source and 360
+    /// surrounds the listener.
+    pub spread: AccurateMath,
+    /// The law used to attenuate the emitter with its distance.
+    pub rolloff: Rolloff,
+}
+
+impl Emitter {
+    /// Returns a new omnidirectional point emitter at the given position, with
+    /// an [`InverseSquare`] rolloff from a reference distance of 1.
+    ///
+    /// # Parameters
+    ///
+    /// * `position` - the position of the emitter.
+    ///
+    /// [`InverseSquare`]: ../spatial_source/enum.Rolloff.html#variant.InverseSquare
+    pub fn new(position: Vector3) -> Self {
+        Emitter {
+            position,
+            orientation: SphericalPosition::default(),
+            directivity: 0.0,
+            spread: 0.0,
+            rolloff: Rolloff::InverseSquare { reference: 1.0 },
+        }
+    }
+
+    /// Returns the linear gain of the emitter as heard by the given listener,
+    /// from its distance and its directivity.
+    ///
+    /// # Parameters
+    ///
+    /// * `listener` - the listener of the scene.
+    pub fn gain(&self, listener: &Listener) -> AccurateMath {
+        let to_listener = listener.position - self.position;
+        let distance = to_listener.length();
+        let d = self.directivity.clamp(0.0, 1.0);
+
+        let facing = if distance > 0.0 {
+            Vector3::from_direction(self.orientation).dot(to_listener) / distance
+        } else {
+            1.0
+        };
+
+        self.rolloff.gain(distance) * (1.0 - d + d * (1.0 + facing) / 2.0)
+    }
+}
+
+/// Trait implementing the ability to render a monophonic emitter into a
+/// polyphonic sample, from the point of view of a listener. It is implemented
+/// by [`Listener`] for every format that can pan a monophonic sample to a
+/// [`SphericalPosition`], unifying the panners of those formats under a single
+/// API.
+///
+/// [`Listener`]: struct.Listener.html
+/// [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+pub trait Spatialize<F: SampleFormat> {
+    /// Renders the given sample of the emitter.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the monophonic sample of the emitter.
+    /// * `emitter` - the emitter producing the sample.
+    fn spatialize(&self, s: Sample, emitter: &Emitter) -> F;
+}
+
+impl<F: Panner<SphericalPosition>> Spatialize<F> for Listener {
+    fn spatialize(&self, s: Sample, emitter: &Emitter) -> F {
+        let (position, _) = self.locate(emitter.position);
+        let gain = emitter.gain(self);
+
+        panning::spread_gains::<F>(position, emitter.spread)
+            * Sample((s.0 as AccurateMath * gain) as FastMath)
+    }
+}
@@ -0,0 +1,113 @@
+//! # Spatial Source
+//!
+//! Module containing a description of a positioned source including its
+//! distance from the listener and its width, as used by the positional
+//! panners.
+
+use super::*;
+
+/// Enum of the laws used to attenuate a source with its distance from the
+/// listener. Distances are given in any unit, as long as the same unit is used
+/// for the distance of the source.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Rolloff {
+    /// The source isn't attenuated with distance.
+    None,
+    /// The physical inverse-square law, under which the intensity of a source
+    /// falls with the square of its distance, attenuating its amplitude by
+    /// 6 dB for every doubling of the distance beyond the reference distance.
+    InverseSquare {
+        /// The distance at and within which the source has unity gain.
+        reference: AccurateMath,
+    },
+    /// Inverse law with a configurable rate, with a gain of
+    /// `reference / (reference + factor * (distance - reference))` beyond the
+    /// reference distance. A factor of 1 is the same as [`InverseSquare`],
+    /// and smaller factors make the source fall off more slowly.
+    ///
+    /// [`InverseSquare`]: #variant.InverseSquare
+    Inverse {
+        /// The distance at and within which the source has unity gain.
+        reference: AccurateMath,
+        /// The rate of the rolloff.
+        factor: AccurateMath,
+    },
+    /// The gain falls linearly from unity at the reference distance to 0 at
+    /// the maximum distance.
+    Linear {
+        /// The distance at and within which the source has unity gain.
+        reference: AccurateMath,
+        /// The distance at and beyond which the source is silent.
+        max: AccurateMath,
+    },
+}
+
+impl Rolloff {
+    /// Calculates the linear gain of a source at the given distance.
+    ///
+    /// # Parameters
+    ///
+    /// * `distance` - the distance of the source from the listener.
+    pub fn gain(self, distance: AccurateMath) -> AccurateMath {
+        match self {
+            Rolloff::None => 1.0,
+            Rolloff::InverseSquare { reference } => Rolloff::Inverse {
+                reference,
+                factor: 1.0,
+            }
+            .gain(distance),
+            Rolloff::Inverse { reference, factor } => {
+                if distance <= reference {
+                    1.0
+                } else {
+                    reference / (reference + factor * (distance - reference))
+                }
+            }
+            Rolloff::Linear { reference, max } => {
+                bae_utils::clerp(distance, reference, max, 1.0, 0.0)
+            }
+        }
+    }
+}
+
+/// Struct describing a monophonic source positioned relative to the listener,
+/// given to the positional panners to set both the level and width of the
+/// source in a single call.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpatialSource {
+    /// The direction of the source.
+    pub position: SphericalPosition,
+    /// The distance of the source from the listener.
+    pub distance: AccurateMath,
+    /// The width in degrees of the arc of the horizontal plane covered by the
+    /// source, centered on its position. 0 is a point source and 360
+    /// surrounds the listener.
+    pub spread: AccurateMath,
+    /// The law used to attenuate the source with its distance.
+    pub rolloff: Rolloff,
+}
+
+impl SpatialSource {
+    /// Returns a new point source at the given position, at a distance of 1
+    /// with an [`InverseSquare`] rolloff from a reference distance of 1, such
+    /// that it has unity gain.
+    ///
+    /// # Parameters
+    ///
+    /// * `position` - the direction of the source.
+    ///
+    /// [`InverseSquare`]: enum.Rolloff.html#variant.InverseSquare
+    pub fn new(position: SphericalPosition) -> Self {
+        SpatialSource {
+            position,
+            distance: 1.0,
+            spread: 0.0,
+            rolloff: Rolloff::InverseSquare { reference: 1.0 },
+        }
+    }
+
+    /// Returns the linear gain of the source due to its distance.
+    pub fn gain(&self) -> AccurateMath {
+        self.rolloff.gain(self.distance)
+    }
+}
@@ -0,0 +1,59 @@
+//! # Spherical Position
+//!
+//! Module containing a type describing the direction of a source relative to
+//! the listener, as used by the positional panners.
+
+use super::*;
+
+/// Struct representing the direction of a source relative to the listener, with
+/// both angles given in degrees.
+///
+/// The azimuth is measured clockwise from the front of the listener, such that
+/// -90 is directly left and 90 is directly right, matching the direction of the
+/// [`Stereo`] panner. The elevation is measured upwards from the horizontal
+/// plane, such that 90 is directly above the listener.
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct SphericalPosition {
+    /// Azimuth in degrees.
+    pub azimuth: AccurateMath,
+    /// Elevation in degrees.
+    pub elevation: AccurateMath,
+}
+
+impl SphericalPosition {
+    /// Returns a new SphericalPosition object from the given angles.
+    ///
+    /// # Parameters
+    ///
+    /// * `azimuth` - the azimuth in degrees.
+    /// * `elevation` - the elevation in degrees.
+    pub const fn new(azimuth: AccurateMath, elevation: AccurateMath) -> Self {
+        SphericalPosition { azimuth, elevation }
+    }
+
+    /// Returns a new SphericalPosition object on the horizontal plane at the
+    /// given azimuth.
+    ///
+    /// # Parameters
+    ///
+    /// * `azimuth` - the azimuth in degrees.
+    pub const fn horizontal(azimuth: AccurateMath) -> Self {
+        SphericalPosition {
+            azimuth,
+            elevation: 0.0,
+        }
+    }
+}
+
+impl From<(f32, f32)> for SphericalPosition {
+    fn from(p: (f32, f32)) -> Self {
+        SphericalPosition::new(p.0 as AccurateMath, p.1 as AccurateMath)
+    }
+}
+impl From<(f64, f64)> for SphericalPosition {
+    fn from(p: (f64, f64)) -> Self {
+        SphericalPosition::new(p.0 as AccurateMath, p.1 as AccurateMath)
+    }
+}
@@ -22,8 +22,8 @@ pub struct Stereo {
 
 impl Stereo {
     /// Returns a new Stereo object with default <0,0> values.
-    pub fn new() -> Self {
-        Stereo::default()
+    pub const fn new() -> Self {
+        Stereo::SILENCE
     }
 
     /// Returns a new Stereo object created from individual left and right
@@ -33,12 +33,162 @@ impl Stereo {
     ///
     /// * `l` - the left audio sample.
     /// * `r` - the right audio sample.
-    pub fn from(l: Sample, r: Sample) -> Self {
+    pub const fn from(l: Sample, r: Sample) -> Self {
         Stereo { left: l, right: r }
     }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 2] {
+        let s: [Sample; 2] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Stereo object with each channel set to the positive linear
+    /// value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 2]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Returns the sample with its left and right channels exchanged.
+    pub fn swapped(self) -> Self {
+        Stereo {
+            left: self.right,
+            right: self.left,
+        }
+    }
+
+    /// Applies a balance control to the sample. Unlike panning, balance
+    /// doesn't redistribute a signal between the channels, it only attenuates
+    /// the side opposite to the direction of the control. The parameter `b` is
+    /// a floating point value of the range \[-1,1\], where -1 silences the
+    /// right channel, 0 leaves the sample unchanged, and 1 silences the left
+    /// channel. If the given value is not within this range, it is clamped to
+    /// it.
+    ///
+    /// # Parameters
+    ///
+    /// * `b` - the balance control value.
+    pub fn balance(self, b: FastMath) -> Self {
+        let b = b.clamp(-1.0, 1.0);
+
+        Stereo {
+            left: Sample(self.left.0 * FastMath::min(1.0, 1.0 - b)),
+            right: Sample(self.right.0 * FastMath::min(1.0, 1.0 + b)),
+        }
+    }
+
+    /// Returns the stereo width of the sample, calculated as the ratio of the
+    /// side (L-R) to the mid (L+R) component. A value of 0 means the sample is
+    /// mono, 1 means one channel is silent, and values above 1 mean the
+    /// channels are out of phase, with a sample that has no mid component
+    /// returning infinity. A silent sample returns 0.
+    pub fn width(self) -> Math {
+        let l = self.left.0 as AccurateMath;
+        let r = self.right.0 as AccurateMath;
+        let mid = (l + r).abs();
+        let side = (l - r).abs();
+
+        if side == 0.0 {
+            Math(0.0)
+        } else {
+            Math(side / mid)
+        }
+    }
+
+    /// Calculates the correlation between the left and right channels of a
+    /// window of samples, as displayed by a phase correlation meter. A value
+    /// of 1 means the channels are identical and the window is fully mono
+    /// compatible, 0 means the channels are unrelated, and -1 means the
+    /// channels are inverted and cancel out when summed to mono. Returns 0 if
+    /// either channel is silent over the window.
+    ///
+    /// # Parameters
+    ///
+    /// * `window` - the samples to measure.
+    pub fn correlation(window: &[Stereo]) -> Math {
+        let (lr, ll, rr) = window.iter().fold((0.0, 0.0, 0.0), |(lr, ll, rr), s| {
+            let l = s.left.0 as AccurateMath;
+            let r = s.right.0 as AccurateMath;
+            (lr + l * r, ll + l * l, rr + r * r)
+        });
+
+        if ll == 0.0 || rr == 0.0 {
+            Math(0.0)
+        } else {
+            Math((lr / (ll * rr).sqrt()).clamp(-1.0, 1.0))
+        }
+    }
+}
+
+/// Encodes a stereo sample into mid/side, returning a tuple of the mid and side
+/// samples respectively. The mid sample is half the sum of the left and right
+/// channels, and the side sample is half the difference, as for [`MidSide`].
+///
+/// # Parameters
+///
+/// * `s` - the stereo sample to encode.
+///
+/// [`MidSide`]: ../mid_side/struct.MidSide.html
+pub fn to_mid_side(s: Stereo) -> (Sample, Sample) {
+    let ms: MidSide = s.into();
+    ms.into()
+}
+
+/// Decodes a mid/side pair into a stereo sample, where the left channel is the
+/// sum of the mid and side samples, and the right channel is the difference.
+/// This is the inverse of [`to_mid_side`].
+///
+/// # Parameters
+///
+/// * `mid` - the mid sample.
+/// * `side` - the side sample.
+///
+/// [`to_mid_side`]: fn.to_mid_side.html
+pub fn from_mid_side(mid: Sample, side: Sample) -> Stereo {
+    MidSide::from(mid, side).into()
+}
+
+/// Encodes a track of stereo samples into mid/side, as done by
+/// [`to_mid_side`] for each sample.
+///
+/// # Parameters
+///
+/// * `track` - the stereo samples to encode.
+///
+/// [`to_mid_side`]: fn.to_mid_side.html
+pub fn to_mid_side_track(track: &[Stereo]) -> MidSideTrackT {
+    track.iter().map(|&s| s.into()).collect()
+}
+
+/// Decodes a track of mid/side samples into stereo, as done by
+/// [`from_mid_side`] for each sample.
+///
+/// # Parameters
+///
+/// * `track` - the mid/side samples to decode.
+///
+/// [`from_mid_side`]: fn.from_mid_side.html
+pub fn from_mid_side_track(track: &[MidSide]) -> StereoTrackT {
+    track.iter().map(|&ms| ms.into()).collect()
 }
 
 impl SampleFormat for Stereo {
+    const CHANNEL_COUNT: usize = 2;
+
+    const SILENCE: Self = Stereo {
+        left: Sample(0.0),
+        right: Sample(0.0),
+    };
+    const UNITY: Self = Stereo {
+        left: Sample(1.0),
+        right: Sample(1.0),
+    };
+
     fn from_sample(x: Sample) -> Self {
         Stereo {
             left: Sample(x.0 * FastMath::sqrt(0.5)),
@@ -50,66 +200,109 @@ impl SampleFormat for Stereo {
         Sample((self.left.0 + self.right.0) / FastMath::sqrt(0.5))
     }
 
-    fn num_samples() -> usize {
-        2
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[ChannelLabel::FrontLeft, ChannelLabel::FrontRight]
     }
 }
 
 /// Pans a given sample between the left and right channels. The panning
-/// parameter `g` is a floating point value of the rang \[-1,1\], where -1 is
+/// parameter `g` is a floating point value of the range \[-1,1\], where -1 is
 /// panned full left and 1 is panned full right. If the given value is not
-/// within this range, it is clamped to it.
+/// within this range, it is clamped to it. The [`DecibelTaper3dB`] pan law is
+/// used.
+///
+/// [`DecibelTaper3dB`]: ../pan_law/enum.PanLaw.html#variant.DecibelTaper3dB
 impl Panner<f32> for Stereo {
     fn to_sample_format(s: Sample, g: f32) -> Self {
-        let l_lerp = if g <= 0.0 {
-            clerp(g as AccurateMath, -1.0, 0.0, -3.0, -120.0)
-        } else {
-            clerp(g as AccurateMath, 0.0, 1.0, 0.0, -3.0)
-        };
-        let r_lerp = if g >= 0.0 {
-            clerp(g as AccurateMath, 0.0, 1.0, -3.0, -120.0)
-        } else {
-            clerp(g as AccurateMath, -1.0, 0.0, 0.0, -3.0)
-        };
-
-        Stereo {
-            left: Sample(
-                (
-                    db_to_linear(
-                        Math(l_lerp)
-                    ).0 * s.0 as AccurateMath
-                ) as FastMath
-            ),
-            right: Sample(
-                (
-                    db_to_linear(
-                        Math(r_lerp)
-                    ).0 * s.0 as AccurateMath
-                ) as FastMath
-            ),
-        }
+        Stereo::to_sample_format(s, (g, PanLaw::default()))
     }
 }
 impl Panner<f64> for Stereo {
     fn to_sample_format(s: Sample, g: f64) -> Self {
-        let l_lerp = if g <= 0.0 {
-            clerp(g as AccurateMath, -1.0, 0.0, -3.0, -120.0)
-        } else {
-            clerp(g as AccurateMath, 0.0, 1.0, 0.0, -3.0)
-        };
-        let r_lerp = if g >= 0.0 {
-            clerp(g as AccurateMath, 0.0, 1.0, -3.0, -120.0)
-        } else {
-            clerp(g as AccurateMath, -1.0, 0.0, 0.0, -3.0)
-        };
+        Stereo::to_sample_format(s, (g, PanLaw::default()))
+    }
+}
+
+/// Pans a given sample between the left and right channels using the given
+/// pan law. The panning parameter is the first value of the tuple, in the
+/// same range as for the panner taking only a floating point value.
+impl Panner<(f32, PanLaw)> for Stereo {
+    fn to_sample_format(s: Sample, g: (f32, PanLaw)) -> Self {
+        Stereo::to_sample_format(s, (g.0 as f64, g.1))
+    }
+}
+impl Panner<(f64, PanLaw)> for Stereo {
+    fn to_sample_format(s: Sample, g: (f64, PanLaw)) -> Self {
+        let (l, r) = g.1.gains(g.0 as AccurateMath);
 
         Stereo {
-            left: Sample((db_to_linear(Math(l_lerp)).0 * s.0 as AccurateMath) as FastMath),
-            right: Sample((db_to_linear(Math(r_lerp)).0 * s.0 as AccurateMath) as FastMath),
+            left: Sample((l * s.0 as AccurateMath) as FastMath),
+            right: Sample((r * s.0 as AccurateMath) as FastMath),
         }
     }
 }
 
+/// Pans a given sample to the given direction, as heard by a listener facing
+/// the front of the speaker pair. The panning parameter is the sine of the
+/// azimuth, such that -90 is panned full left, 90 is panned full right, and
+/// sources behind the listener are mirrored to the front. The elevation is
+/// ignored. The [`DecibelTaper3dB`] pan law is used.
+///
+/// [`DecibelTaper3dB`]: ../pan_law/enum.PanLaw.html#variant.DecibelTaper3dB
+impl Panner<SphericalPosition> for Stereo {
+    fn to_sample_format(s: Sample, g: SphericalPosition) -> Self {
+        Stereo::to_sample_format(s, g.azimuth.to_radians().sin())
+    }
+}
+
+impl From<Mono> for Stereo {
+    fn from(s: Mono) -> Self {
+        s.convert_format()
+    }
+}
+
+impl Balancer for Stereo {}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Stereo {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.left,
+            1 => &self.right,
+            _ => panic!("ERROR: Index {} is out of range for Stereo with 2 channels.", i),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Stereo {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.left,
+            1 => &mut self.right,
+            _ => panic!("ERROR: Index {} is out of range for Stereo with 2 channels.", i),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Stereo {
+    fn as_ref(&self) -> &[Sample] {
+        // Stereo is #[repr(C)] and made up solely of 2 Sample fields.
+        unsafe { channel::as_slice(self, 2) }
+    }
+}
+impl AsMut<[Sample]> for Stereo {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Stereo is #[repr(C)] and made up solely of 2 Sample fields.
+        unsafe { channel::as_mut_slice(self, 2) }
+    }
+}
+
 impl std::ops::Neg for Stereo {
     type Output = Self;
 
@@ -206,6 +399,70 @@ impl std::ops::MulAssign<Math> for Stereo {
     }
 }
 
+impl std::ops::Mul<f32> for Stereo {
+    type Output = Stereo;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Stereo {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Stereo {
+    type Output = Stereo;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Stereo {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 2]> for Stereo {
+    type Output = Stereo;
+
+    fn mul(mut self, rhs: [Sample; 2]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 2]> for Stereo {
+    fn mul_assign(&mut self, rhs: [Sample; 2]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Stereo {
+    fn sum<I: Iterator<Item = Stereo>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Stereo> for Stereo {
+    fn sum<I: Iterator<Item = &'a Stereo>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Stereo {
+    fn product<I: Iterator<Item = Stereo>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Stereo> for Stereo {
+    fn product<I: Iterator<Item = &'a Stereo>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
 impl From<Sample> for Stereo {
     fn from(s: Sample) -> Self {
         Stereo::from_sample(s)
@@ -217,15 +474,43 @@ impl Into<Sample> for Stereo {
     }
 }
 
-impl TryFrom<Vec<u8>> for Stereo {
-    type Error = String;
+impl From<[Sample; 2]> for Stereo {
+    fn from(a: [Sample; 2]) -> Self {
+        Stereo {
+            left: a[0],
+            right: a[1],
+        }
+    }
+}
+impl Into<[Sample; 2]> for Stereo {
+    fn into(self) -> [Sample; 2] {
+        [self.left, self.right]
+    }
+}
 
-    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+impl From<(Sample, Sample)> for Stereo {
+    fn from(t: (Sample, Sample)) -> Self {
+        Stereo {
+            left: t.0,
+            right: t.1,
+        }
+    }
+}
+impl Into<(Sample, Sample)> for Stereo {
+    fn into(self) -> (Sample, Sample) {
+        (self.left, self.right)
+    }
+}
+
+impl TryFrom<&[u8]> for Stereo {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
         if v.len() < 2 {
-            Err(format!(
-                "ERROR: Given vector was length {}. This function requires length 2.",
-                v.len()
-            ))
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
         } else {
             Ok(Stereo {
                 left: sample_from_u8(v[0]),
@@ -234,21 +519,28 @@ impl TryFrom<Vec<u8>> for Stereo {
         }
     }
 }
+impl TryFrom<Vec<u8>> for Stereo {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
 impl Into<Vec<u8>> for Stereo {
     fn into(self) -> Vec<u8> {
         vec![sample_to_u8(self.left), sample_to_u8(self.right)]
     }
 }
 
-impl TryFrom<Vec<i16>> for Stereo {
-    type Error = String;
+impl TryFrom<&[i16]> for Stereo {
+    type Error = SampleFormatError;
 
-    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
         if v.len() < 2 {
-            Err(format!(
-                "ERROR: Given vector was length {}. This function requires length 2.",
-                v.len()
-            ))
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
         } else {
             Ok(Stereo {
                 left: sample_from_i16(v[0]),
@@ -257,21 +549,28 @@ impl TryFrom<Vec<i16>> for Stereo {
         }
     }
 }
+impl TryFrom<Vec<i16>> for Stereo {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
 impl Into<Vec<i16>> for Stereo {
     fn into(self) -> Vec<i16> {
         vec![sample_to_i16(self.left), sample_to_i16(self.right)]
     }
 }
 
-impl TryFrom<Vec<i32>> for Stereo {
-    type Error = String;
+impl TryFrom<&[i32]> for Stereo {
+    type Error = SampleFormatError;
 
-    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
         if v.len() < 2 {
-            Err(format!(
-                "ERROR: Given vector was length {}. This function requires length 2.",
-                v.len()
-            ))
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
         } else {
             Ok(Stereo {
                 left: sample_from_i24(v[0]),
@@ -280,8 +579,75 @@ impl TryFrom<Vec<i32>> for Stereo {
         }
     }
 }
+impl TryFrom<Vec<i32>> for Stereo {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
 impl Into<Vec<i32>> for Stereo {
     fn into(self) -> Vec<i32> {
         vec![sample_to_i24(self.left), sample_to_i24(self.right)]
     }
 }
+
+impl TryFrom<&[f32]> for Stereo {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Stereo {
+                left: sample_from_f32(v[0]),
+                right: sample_from_f32(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Stereo {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Stereo {
+    fn into(self) -> Vec<f32> {
+        vec![sample_to_f32(self.left), sample_to_f32(self.right)]
+    }
+}
+
+impl TryFrom<&[f64]> for Stereo {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 2 {
+            Err(SampleFormatError::WrongLength {
+                expected: 2,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Stereo {
+                left: sample_from_f64(v[0]),
+                right: sample_from_f64(v[1]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Stereo {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Stereo {
+    fn into(self) -> Vec<f64> {
+        vec![sample_to_f64(self.left), sample_to_f64(self.right)]
+    }
+}
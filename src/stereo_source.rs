@@ -0,0 +1,50 @@
+//! # Stereo Source
+//!
+//! Module containing a description of a positioned stereophonic source, as
+//! used to place stereo material within the surround formats.
+
+use super::*;
+
+/// Struct describing a stereophonic source positioned relative to the
+/// listener. The left and right channels of the source are placed either side
+/// of its position, spanning an arc of the horizontal plane that preserves
+/// the width of the stereo image.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StereoSource {
+    /// The direction of the center of the source.
+    pub position: SphericalPosition,
+    /// The angle in degrees between the left and right channels of the
+    /// source. A standard stereo speaker pair spans 60 degrees, while 0
+    /// collapses the source to a point.
+    pub width: AccurateMath,
+}
+
+impl StereoSource {
+    /// The angle in degrees between the speakers of a standard stereo pair.
+    pub const STANDARD_WIDTH: AccurateMath = 60.0;
+
+    /// Returns a new source at the given position with the width of a standard
+    /// stereo speaker pair, such that a source straight ahead is reproduced by
+    /// the front left and right speakers.
+    ///
+    /// # Parameters
+    ///
+    /// * `position` - the direction of the center of the source.
+    pub fn new(position: SphericalPosition) -> Self {
+        StereoSource {
+            position,
+            width: StereoSource::STANDARD_WIDTH,
+        }
+    }
+
+    /// Returns the positions of the left and right channels of the source.
+    pub fn channel_positions(&self) -> (SphericalPosition, SphericalPosition) {
+        let half = self.width / 2.0;
+        let p = self.position;
+
+        (
+            SphericalPosition::new(p.azimuth - half, p.elevation),
+            SphericalPosition::new(p.azimuth + half, p.elevation),
+        )
+    }
+}
@@ -0,0 +1,597 @@
+//! # Surround 2.1
+//!
+//! Module containing type for handling 2.1 (stereo with LFE) audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Surround21`] samples
+///
+/// [`Surround21`]: struct.Surround21.html
+pub type Surround21TrackT = Vec<Surround21>;
+
+/// Struct representing a 2.1 audio sample, that being a stereo sample with an
+/// added low-frequency effects channel.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Surround21 {
+    /// Left sample value.
+    pub left: Sample,
+    /// Right sample value.
+    pub right: Sample,
+    /// Low-frequency effects sample value.
+    pub lfe: Sample,
+}
+
+impl Surround21 {
+    /// Returns a new Surround21 object with default <0,0,0> values.
+    pub const fn new() -> Self {
+        Surround21::SILENCE
+    }
+
+    /// Returns a new Surround21 object created from the individual audio
+    /// samples of each channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `l` - the left audio sample.
+    /// * `r` - the right audio sample.
+    /// * `lfe` - the low-frequency effects audio sample.
+    pub const fn from(l: Sample, r: Sample, lfe: Sample) -> Self {
+        Surround21 {
+            left: l,
+            right: r,
+            lfe,
+        }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 3] {
+        let s: [Sample; 3] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Surround21 object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 3]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+}
+
+impl SampleFormat for Surround21 {
+    const CHANNEL_COUNT: usize = 3;
+
+    const SILENCE: Self = Surround21 {
+        left: Sample(0.0),
+        right: Sample(0.0),
+        lfe: Sample(0.0),
+    };
+    const UNITY: Self = Surround21 {
+        left: Sample(1.0),
+        right: Sample(1.0),
+        lfe: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        let s = Stereo::from_sample(x);
+
+        Surround21 {
+            left: s.left,
+            right: s.right,
+            lfe: Sample(0.0),
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        Stereo::from(self.left, self.right).into_sample()
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[
+            ChannelLabel::FrontLeft,
+            ChannelLabel::FrontRight,
+            ChannelLabel::Lfe,
+        ]
+    }
+}
+
+/// Pans a given sample between the left and right channels in the same way as
+/// the [`Stereo`] panner, with the panning parameter `g` in the range \[-1,1\].
+/// The unfiltered sample is also routed to the LFE channel at unity gain. It is
+/// assumed that the LFE channel is band-limited further down the signal chain,
+/// as is done by the crossover of common bass management systems.
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+impl Panner<f32> for Surround21 {
+    fn to_sample_format(s: Sample, g: f32) -> Self {
+        let st = Stereo::to_sample_format(s, g);
+
+        Surround21 {
+            left: st.left,
+            right: st.right,
+            lfe: s,
+        }
+    }
+}
+impl Panner<f64> for Surround21 {
+    fn to_sample_format(s: Sample, g: f64) -> Self {
+        let st = Stereo::to_sample_format(s, g);
+
+        Surround21 {
+            left: st.left,
+            right: st.right,
+            lfe: s,
+        }
+    }
+}
+
+/// Pans a given sample between the left and right channels in the same way as
+/// the [`Stereo`] panner, sending the sample to the LFE channel with the given
+/// [`LfeSend`] instead of at unity gain.
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+/// [`LfeSend`]: ../lfe_send/struct.LfeSend.html
+impl Panner<(f32, LfeSend)> for Surround21 {
+    fn to_sample_format(s: Sample, g: (f32, LfeSend)) -> Self {
+        Surround21::to_sample_format(s, (g.0 as f64, g.1))
+    }
+}
+impl Panner<(f64, LfeSend)> for Surround21 {
+    fn to_sample_format(s: Sample, g: (f64, LfeSend)) -> Self {
+        let st = Stereo::to_sample_format(s, g.0);
+
+        Surround21 {
+            left: st.left,
+            right: st.right,
+            lfe: g.1.apply(s),
+        }
+    }
+}
+
+impl From<Stereo> for Surround21 {
+    fn from(s: Stereo) -> Self {
+        s.convert_format()
+    }
+}
+
+impl Balancer for Surround21 {}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Surround21 {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.left,
+            1 => &self.right,
+            2 => &self.lfe,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround21 with 3 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Surround21 {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.left,
+            1 => &mut self.right,
+            2 => &mut self.lfe,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround21 with 3 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Surround21 {
+    fn as_ref(&self) -> &[Sample] {
+        // Surround21 is #[repr(C)] and made up solely of 3 Sample fields.
+        unsafe { channel::as_slice(self, 3) }
+    }
+}
+impl AsMut<[Sample]> for Surround21 {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Surround21 is #[repr(C)] and made up solely of 3 Sample fields.
+        unsafe { channel::as_mut_slice(self, 3) }
+    }
+}
+
+impl std::ops::Neg for Surround21 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Surround21 {
+            left: Sample(-self.left.0),
+            right: Sample(-self.right.0),
+            lfe: Sample(-self.lfe.0),
+        }
+    }
+}
+
+impl std::ops::Add<Surround21> for Surround21 {
+    type Output = Self;
+
+    fn add(self, rhs: Surround21) -> Self::Output {
+        Surround21 {
+            left: Sample(self.left.0 + rhs.left.0),
+            right: Sample(self.right.0 + rhs.right.0),
+            lfe: Sample(self.lfe.0 + rhs.lfe.0),
+        }
+    }
+}
+impl std::ops::AddAssign<Surround21> for Surround21 {
+    fn add_assign(&mut self, rhs: Surround21) {
+        self.left.0 += rhs.left.0;
+        self.right.0 += rhs.right.0;
+        self.lfe.0 += rhs.lfe.0;
+    }
+}
+
+impl std::ops::Sub<Surround21> for Surround21 {
+    type Output = Self;
+
+    fn sub(self, rhs: Surround21) -> Self {
+        Surround21 {
+            left: Sample(self.left.0 - rhs.left.0),
+            right: Sample(self.right.0 - rhs.right.0),
+            lfe: Sample(self.lfe.0 - rhs.lfe.0),
+        }
+    }
+}
+impl std::ops::SubAssign<Surround21> for Surround21 {
+    fn sub_assign(&mut self, rhs: Surround21) {
+        self.left.0 -= rhs.left.0;
+        self.right.0 -= rhs.right.0;
+        self.lfe.0 -= rhs.lfe.0;
+    }
+}
+
+impl std::ops::Mul<Surround21> for Surround21 {
+    type Output = Surround21;
+
+    fn mul(self, rhs: Surround21) -> Self::Output {
+        Surround21 {
+            left: Sample(self.left.0 * rhs.left.0),
+            right: Sample(self.right.0 * rhs.right.0),
+            lfe: Sample(self.lfe.0 * rhs.lfe.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Surround21> for Surround21 {
+    fn mul_assign(&mut self, rhs: Surround21) {
+        self.left.0 *= rhs.left.0;
+        self.right.0 *= rhs.right.0;
+        self.lfe.0 *= rhs.lfe.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for Surround21 {
+    type Output = Surround21;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        Surround21 {
+            left: Sample(self.left.0 * rhs.0),
+            right: Sample(self.right.0 * rhs.0),
+            lfe: Sample(self.lfe.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for Surround21 {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.left.0 *= rhs.0;
+        self.right.0 *= rhs.0;
+        self.lfe.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for Surround21 {
+    type Output = Surround21;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        Surround21 {
+            left: Sample((self.left.0 as AccurateMath * rhs.0) as FastMath),
+            right: Sample((self.right.0 as AccurateMath * rhs.0) as FastMath),
+            lfe: Sample((self.lfe.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for Surround21 {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.left.0 *= rhs.0 as FastMath;
+        self.right.0 *= rhs.0 as FastMath;
+        self.lfe.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for Surround21 {
+    type Output = Surround21;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Surround21 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Surround21 {
+    type Output = Surround21;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Surround21 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 3]> for Surround21 {
+    type Output = Surround21;
+
+    fn mul(mut self, rhs: [Sample; 3]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 3]> for Surround21 {
+    fn mul_assign(&mut self, rhs: [Sample; 3]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Surround21 {
+    fn sum<I: Iterator<Item = Surround21>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Surround21> for Surround21 {
+    fn sum<I: Iterator<Item = &'a Surround21>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Surround21 {
+    fn product<I: Iterator<Item = Surround21>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Surround21> for Surround21 {
+    fn product<I: Iterator<Item = &'a Surround21>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for Surround21 {
+    fn from(s: Sample) -> Self {
+        Surround21::from_sample(s)
+    }
+}
+impl Into<Sample> for Surround21 {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 3]> for Surround21 {
+    fn from(a: [Sample; 3]) -> Self {
+        Surround21 {
+            left: a[0],
+            right: a[1],
+            lfe: a[2],
+        }
+    }
+}
+impl Into<[Sample; 3]> for Surround21 {
+    fn into(self) -> [Sample; 3] {
+        [self.left, self.right, self.lfe]
+    }
+}
+
+impl From<(Sample, Sample, Sample)> for Surround21 {
+    fn from(t: (Sample, Sample, Sample)) -> Self {
+        Surround21 {
+            left: t.0,
+            right: t.1,
+            lfe: t.2,
+        }
+    }
+}
+impl Into<(Sample, Sample, Sample)> for Surround21 {
+    fn into(self) -> (Sample, Sample, Sample) {
+        (self.left, self.right, self.lfe)
+    }
+}
+
+impl TryFrom<&[u8]> for Surround21 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 3 {
+            Err(SampleFormatError::WrongLength {
+                expected: 3,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround21 {
+                left: sample_from_u8(v[0]),
+                right: sample_from_u8(v[1]),
+                lfe: sample_from_u8(v[2]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for Surround21 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for Surround21 {
+    fn into(self) -> Vec<u8> {
+        vec![
+            sample_to_u8(self.left),
+            sample_to_u8(self.right),
+            sample_to_u8(self.lfe),
+        ]
+    }
+}
+
+impl TryFrom<&[i16]> for Surround21 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 3 {
+            Err(SampleFormatError::WrongLength {
+                expected: 3,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround21 {
+                left: sample_from_i16(v[0]),
+                right: sample_from_i16(v[1]),
+                lfe: sample_from_i16(v[2]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for Surround21 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for Surround21 {
+    fn into(self) -> Vec<i16> {
+        vec![
+            sample_to_i16(self.left),
+            sample_to_i16(self.right),
+            sample_to_i16(self.lfe),
+        ]
+    }
+}
+
+impl TryFrom<&[i32]> for Surround21 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 3 {
+            Err(SampleFormatError::WrongLength {
+                expected: 3,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround21 {
+                left: sample_from_i24(v[0]),
+                right: sample_from_i24(v[1]),
+                lfe: sample_from_i24(v[2]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for Surround21 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for Surround21 {
+    fn into(self) -> Vec<i32> {
+        vec![
+            sample_to_i24(self.left),
+            sample_to_i24(self.right),
+            sample_to_i24(self.lfe),
+        ]
+    }
+}
+
+impl TryFrom<&[f32]> for Surround21 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 3 {
+            Err(SampleFormatError::WrongLength {
+                expected: 3,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround21 {
+                left: sample_from_f32(v[0]),
+                right: sample_from_f32(v[1]),
+                lfe: sample_from_f32(v[2]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Surround21 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Surround21 {
+    fn into(self) -> Vec<f32> {
+        vec![
+            sample_to_f32(self.left),
+            sample_to_f32(self.right),
+            sample_to_f32(self.lfe),
+        ]
+    }
+}
+
+impl TryFrom<&[f64]> for Surround21 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 3 {
+            Err(SampleFormatError::WrongLength {
+                expected: 3,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround21 {
+                left: sample_from_f64(v[0]),
+                right: sample_from_f64(v[1]),
+                lfe: sample_from_f64(v[2]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Surround21 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Surround21 {
+    fn into(self) -> Vec<f64> {
+        vec![
+            sample_to_f64(self.left),
+            sample_to_f64(self.right),
+            sample_to_f64(self.lfe),
+        ]
+    }
+}
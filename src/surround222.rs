@@ -0,0 +1,537 @@
+//! # Surround 22.2
+//!
+//! Module containing type for handling 22.2 (NHK Super Hi-Vision) surround
+//! audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Surround222`] samples
+///
+/// [`Surround222`]: struct.Surround222.html
+pub type Surround222TrackT = Vec<Surround222>;
+
+/// Struct representing a 22.2 surround audio sample.
+///
+/// The 24 channels are stored in the order defined by SMPTE ST 2036-2, and
+/// each channel can be accessed by name through the accessor methods.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Surround222 {
+    /// The channels of the sample in SMPTE ST 2036-2 order.
+    pub channels: [Sample; 24],
+}
+
+/// Generates the named getter and mutable getter of each channel.
+macro_rules! channel_accessors {
+    ($($i:expr, $name:ident, $name_mut:ident, $desc:expr;)*) => {
+        $(
+            #[doc = concat!("Returns the ", $desc, " sample value.")]
+            pub fn $name(&self) -> Sample {
+                self.channels[$i]
+            }
+
+            #[doc = concat!("Returns a mutable reference to the ", $desc, " sample value.")]
+            pub fn $name_mut(&mut self) -> &mut Sample {
+                &mut self.channels[$i]
+            }
+        )*
+    };
+}
+
+impl Surround222 {
+    /// Returns a new Surround222 object with all channels set to 0.
+    pub const fn new() -> Self {
+        Surround222::SILENCE
+    }
+
+    /// Returns a new Surround222 object created from the given channels in
+    /// SMPTE ST 2036-2 order.
+    ///
+    /// # Parameters
+    ///
+    /// * `channels` - the channels of the sample.
+    pub const fn from(channels: [Sample; 24]) -> Self {
+        Surround222 { channels }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 24] {
+        let s: [Sample; 24] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Surround222 object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 24]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    channel_accessors! {
+        0, front_left, front_left_mut, "front left";
+        1, front_right, front_right_mut, "front right";
+        2, front_center, front_center_mut, "front center";
+        3, lfe1, lfe1_mut, "first low-frequency effects";
+        4, back_left, back_left_mut, "back left";
+        5, back_right, back_right_mut, "back right";
+        6, front_left_center, front_left_center_mut, "front left center";
+        7, front_right_center, front_right_center_mut, "front right center";
+        8, back_center, back_center_mut, "back center";
+        9, lfe2, lfe2_mut, "second low-frequency effects";
+        10, side_left, side_left_mut, "side left";
+        11, side_right, side_right_mut, "side right";
+        12, top_front_left, top_front_left_mut, "top front left";
+        13, top_front_right, top_front_right_mut, "top front right";
+        14, top_front_center, top_front_center_mut, "top front center";
+        15, top_center, top_center_mut, "top center";
+        16, top_back_left, top_back_left_mut, "top back left";
+        17, top_back_right, top_back_right_mut, "top back right";
+        18, top_side_left, top_side_left_mut, "top side left";
+        19, top_side_right, top_side_right_mut, "top side right";
+        20, top_back_center, top_back_center_mut, "top back center";
+        21, bottom_front_center, bottom_front_center_mut, "bottom front center";
+        22, bottom_front_left, bottom_front_left_mut, "bottom front left";
+        23, bottom_front_right, bottom_front_right_mut, "bottom front right";
+    }
+}
+
+impl SampleFormat for Surround222 {
+    const CHANNEL_COUNT: usize = 24;
+
+    const SILENCE: Self = Surround222 {
+        channels: [Sample(0.0); 24],
+    };
+    const UNITY: Self = Surround222 {
+        channels: [Sample(1.0); 24],
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        let mut s = Surround222::new();
+        *s.front_center_mut() = x;
+        s
+    }
+
+    fn into_sample(self) -> Sample {
+        let others = self
+            .channels
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != 2 && *i != 3 && *i != 9)
+            .map(|(_, c)| c.0)
+            .sum::<FastMath>();
+
+        Sample(self.front_center().0 + others * FastMath::sqrt(0.5))
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[
+            ChannelLabel::FrontLeft,
+            ChannelLabel::FrontRight,
+            ChannelLabel::Center,
+            ChannelLabel::Lfe,
+            ChannelLabel::RearLeft,
+            ChannelLabel::RearRight,
+            ChannelLabel::FrontLeftCenter,
+            ChannelLabel::FrontRightCenter,
+            ChannelLabel::RearCenter,
+            ChannelLabel::Lfe2,
+            ChannelLabel::SideLeft,
+            ChannelLabel::SideRight,
+            ChannelLabel::TopFrontLeft,
+            ChannelLabel::TopFrontRight,
+            ChannelLabel::TopFrontCenter,
+            ChannelLabel::TopCenter,
+            ChannelLabel::TopRearLeft,
+            ChannelLabel::TopRearRight,
+            ChannelLabel::TopSideLeft,
+            ChannelLabel::TopSideRight,
+            ChannelLabel::TopRearCenter,
+            ChannelLabel::BottomFrontCenter,
+            ChannelLabel::BottomFrontLeft,
+            ChannelLabel::BottomFrontRight,
+        ]
+    }
+}
+
+impl From<Surround51> for Surround222 {
+    fn from(s: Surround51) -> Self {
+        s.convert_format()
+    }
+}
+
+impl From<Surround71> for Surround222 {
+    fn from(s: Surround71) -> Self {
+        s.convert_format()
+    }
+}
+
+impl Balancer for Surround222 {}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Surround222 {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.channels[i]
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Surround222 {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.channels[i]
+    }
+}
+
+impl AsRef<[Sample]> for Surround222 {
+    fn as_ref(&self) -> &[Sample] {
+        &self.channels
+    }
+}
+impl AsMut<[Sample]> for Surround222 {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        &mut self.channels
+    }
+}
+
+impl std::ops::Neg for Surround222 {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        for c in self.channels.iter_mut() {
+            c.0 = -c.0;
+        }
+        self
+    }
+}
+
+impl std::ops::Add<Surround222> for Surround222 {
+    type Output = Self;
+
+    fn add(mut self, rhs: Surround222) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+impl std::ops::AddAssign<Surround222> for Surround222 {
+    fn add_assign(&mut self, rhs: Surround222) {
+        for (c, r) in self.channels.iter_mut().zip(rhs.channels.iter()) {
+            c.0 += r.0;
+        }
+    }
+}
+
+impl std::ops::Sub<Surround222> for Surround222 {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Surround222) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl std::ops::SubAssign<Surround222> for Surround222 {
+    fn sub_assign(&mut self, rhs: Surround222) {
+        for (c, r) in self.channels.iter_mut().zip(rhs.channels.iter()) {
+            c.0 -= r.0;
+        }
+    }
+}
+
+impl std::ops::Mul<Surround222> for Surround222 {
+    type Output = Surround222;
+
+    fn mul(mut self, rhs: Surround222) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<Surround222> for Surround222 {
+    fn mul_assign(&mut self, rhs: Surround222) {
+        for (c, r) in self.channels.iter_mut().zip(rhs.channels.iter()) {
+            c.0 *= r.0;
+        }
+    }
+}
+
+impl std::ops::Mul<Sample> for Surround222 {
+    type Output = Surround222;
+
+    fn mul(mut self, rhs: Sample) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<Sample> for Surround222 {
+    fn mul_assign(&mut self, rhs: Sample) {
+        for c in self.channels.iter_mut() {
+            c.0 *= rhs.0;
+        }
+    }
+}
+
+impl std::ops::Mul<Math> for Surround222 {
+    type Output = Surround222;
+
+    fn mul(mut self, rhs: Math) -> Self::Output {
+        for c in self.channels.iter_mut() {
+            c.0 = (c.0 as AccurateMath * rhs.0) as FastMath;
+        }
+        self
+    }
+}
+impl std::ops::MulAssign<Math> for Surround222 {
+    fn mul_assign(&mut self, rhs: Math) {
+        for c in self.channels.iter_mut() {
+            c.0 *= rhs.0 as FastMath;
+        }
+    }
+}
+
+impl std::ops::Mul<f32> for Surround222 {
+    type Output = Surround222;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Surround222 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Surround222 {
+    type Output = Surround222;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Surround222 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 24]> for Surround222 {
+    type Output = Surround222;
+
+    fn mul(mut self, rhs: [Sample; 24]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 24]> for Surround222 {
+    fn mul_assign(&mut self, rhs: [Sample; 24]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Surround222 {
+    fn sum<I: Iterator<Item = Surround222>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Surround222> for Surround222 {
+    fn sum<I: Iterator<Item = &'a Surround222>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Surround222 {
+    fn product<I: Iterator<Item = Surround222>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Surround222> for Surround222 {
+    fn product<I: Iterator<Item = &'a Surround222>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for Surround222 {
+    fn from(s: Sample) -> Self {
+        Surround222::from_sample(s)
+    }
+}
+impl Into<Sample> for Surround222 {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 24]> for Surround222 {
+    fn from(a: [Sample; 24]) -> Self {
+        Surround222 { channels: a }
+    }
+}
+impl Into<[Sample; 24]> for Surround222 {
+    fn into(self) -> [Sample; 24] {
+        self.channels
+    }
+}
+
+impl TryFrom<&[u8]> for Surround222 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 24 {
+            Err(SampleFormatError::WrongLength {
+                expected: 24,
+                actual: v.len(),
+            })
+        } else {
+            let mut s = Surround222::new();
+            for (c, x) in s.channels.iter_mut().zip(v) {
+                *c = sample_from_u8(*x);
+            }
+            Ok(s)
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for Surround222 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for Surround222 {
+    fn into(self) -> Vec<u8> {
+        self.channels.iter().map(|c| sample_to_u8(*c)).collect()
+    }
+}
+
+impl TryFrom<&[i16]> for Surround222 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 24 {
+            Err(SampleFormatError::WrongLength {
+                expected: 24,
+                actual: v.len(),
+            })
+        } else {
+            let mut s = Surround222::new();
+            for (c, x) in s.channels.iter_mut().zip(v) {
+                *c = sample_from_i16(*x);
+            }
+            Ok(s)
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for Surround222 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for Surround222 {
+    fn into(self) -> Vec<i16> {
+        self.channels.iter().map(|c| sample_to_i16(*c)).collect()
+    }
+}
+
+impl TryFrom<&[i32]> for Surround222 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 24 {
+            Err(SampleFormatError::WrongLength {
+                expected: 24,
+                actual: v.len(),
+            })
+        } else {
+            let mut s = Surround222::new();
+            for (c, x) in s.channels.iter_mut().zip(v) {
+                *c = sample_from_i24(*x);
+            }
+            Ok(s)
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for Surround222 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for Surround222 {
+    fn into(self) -> Vec<i32> {
+        self.channels.iter().map(|c| sample_to_i24(*c)).collect()
+    }
+}
+
+impl TryFrom<&[f32]> for Surround222 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 24 {
+            Err(SampleFormatError::WrongLength {
+                expected: 24,
+                actual: v.len(),
+            })
+        } else {
+            let mut s = Surround222::new();
+            for (c, x) in s.channels.iter_mut().zip(v) {
+                *c = sample_from_f32(*x);
+            }
+            Ok(s)
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Surround222 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Surround222 {
+    fn into(self) -> Vec<f32> {
+        self.channels.iter().map(|c| sample_to_f32(*c)).collect()
+    }
+}
+
+impl TryFrom<&[f64]> for Surround222 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 24 {
+            Err(SampleFormatError::WrongLength {
+                expected: 24,
+                actual: v.len(),
+            })
+        } else {
+            let mut s = Surround222::new();
+            for (c, x) in s.channels.iter_mut().zip(v) {
+                *c = sample_from_f64(*x);
+            }
+            Ok(s)
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Surround222 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Surround222 {
+    fn into(self) -> Vec<f64> {
+        self.channels.iter().map(|c| sample_to_f64(*c)).collect()
+    }
+}
@@ -0,0 +1,839 @@
+//! # Surround 5.1
+//!
+//! Module containing type for handling 5.1 surround audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Surround51`] samples
+///
+/// [`Surround51`]: struct.Surround51.html
+pub type Surround51TrackT = Vec<Surround51>;
+
+/// Struct representing a 5.1 surround audio sample.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Surround51 {
+    /// Front left sample value.
+    pub front_left: Sample,
+    /// Front right sample value.
+    pub front_right: Sample,
+    /// Center sample value.
+    pub center: Sample,
+    /// Low-frequency effects sample value.
+    pub lfe: Sample,
+    /// Surround left sample value.
+    pub surround_left: Sample,
+    /// Surround right sample value.
+    pub surround_right: Sample,
+}
+
+impl Surround51 {
+    /// Returns a new Surround51 object with default <0,0,0,0,0,0> values.
+    pub const fn new() -> Self {
+        Surround51::SILENCE
+    }
+
+    /// Returns a new Surround51 object created from the individual audio
+    /// samples of each channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `fl` - the front left audio sample.
+    /// * `fr` - the front right audio sample.
+    /// * `c` - the center audio sample.
+    /// * `lfe` - the low-frequency effects audio sample.
+    /// * `sl` - the surround left audio sample.
+    /// * `sr` - the surround right audio sample.
+    pub const fn from(
+        fl: Sample,
+        fr: Sample,
+        c: Sample,
+        lfe: Sample,
+        sl: Sample,
+        sr: Sample,
+    ) -> Self {
+        Surround51 {
+            front_left: fl,
+            front_right: fr,
+            center: c,
+            lfe,
+            surround_left: sl,
+            surround_right: sr,
+        }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 6] {
+        let s: [Sample; 6] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Surround51 object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 6]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Downmixes the sample to stereo following ITU-R BS.775, with the center
+    /// and each surround channel mixed into the front channels at -3 dB:
+    ///
+    /// * `left = front_left + 0.707 * center + 0.707 * surround_left`
+    /// * `right = front_right + 0.707 * center + 0.707 * surround_right`
+    ///
+    /// The recommendation discards the LFE channel, which can instead be mixed
+    /// into both channels at the given gain. The downmix isn't normalized, so
+    /// loud material can exceed full scale and may need attenuating first.
+    ///
+    /// # Parameters
+    ///
+    /// * `lfe` - the gain of the LFE channel in decibels. Negative infinity
+    /// discards the channel.
+    pub fn downmix_bs775(self, lfe: Math) -> Stereo {
+        let h = std::f64::consts::FRAC_1_SQRT_2;
+        let lfe = bae_utils::db_to_linear(lfe).0 * self.lfe.0 as AccurateMath;
+        let common = h * self.center.0 as AccurateMath + lfe;
+
+        Stereo {
+            left: Sample(
+                (self.front_left.0 as AccurateMath
+                    + common
+                    + h * self.surround_left.0 as AccurateMath) as FastMath,
+            ),
+            right: Sample(
+                (self.front_right.0 as AccurateMath
+                    + common
+                    + h * self.surround_right.0 as AccurateMath) as FastMath,
+            ),
+        }
+    }
+
+    /// Pans the given sample to the given azimuth in degrees. This is the
+    /// implementation shared by the [`Panner`] impls.
+    ///
+    /// [`Panner`]: ../trait.Panner.html
+    fn pan(s: Sample, azimuth: AccurateMath) -> Self {
+        let mut gains = [0.0; 5];
+
+        panning::ring_gains(azimuth, &panning::SURROUND51_AZIMUTHS, &mut gains);
+
+        let g = |i: usize| Sample((s.0 as AccurateMath * gains[i]) as FastMath);
+
+        Surround51 {
+            front_left: g(0),
+            front_right: g(1),
+            center: g(2),
+            lfe: Sample(0.0),
+            surround_left: g(3),
+            surround_right: g(4),
+        }
+    }
+
+    /// Creates a Surround51 object from a frame of channels given in the given
+    /// channel order.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame` - the channels of the frame, at least 6 long.
+    /// * `order` - the order of the channels within `frame`.
+    pub fn from_order(frame: &[Sample], order: ChannelOrder) -> Result<Self, SampleFormatError> {
+        if frame.len() < 6 {
+            return Err(SampleFormatError::WrongLength {
+                expected: 6,
+                actual: frame.len(),
+            });
+        }
+
+        let mut c = [Sample(0.0); 6];
+        for (s, i) in frame.iter().zip(order.surround51_indices().iter()) {
+            c[*i] = *s;
+        }
+
+        Ok(Surround51 {
+            front_left: c[0],
+            front_right: c[1],
+            center: c[2],
+            lfe: c[3],
+            surround_left: c[4],
+            surround_right: c[5],
+        })
+    }
+
+    /// Returns the channels of the sample in the given channel order.
+    ///
+    /// # Parameters
+    ///
+    /// * `order` - the order to return the channels in.
+    pub fn to_order(self, order: ChannelOrder) -> [Sample; 6] {
+        let c = [
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.surround_left,
+            self.surround_right,
+        ];
+        let mut frame = [Sample(0.0); 6];
+        for (s, i) in frame.iter_mut().zip(order.surround51_indices().iter()) {
+            *s = c[*i];
+        }
+        frame
+    }
+}
+
+impl SampleFormat for Surround51 {
+    const CHANNEL_COUNT: usize = 6;
+
+    const SILENCE: Self = Surround51 {
+        front_left: Sample(0.0),
+        front_right: Sample(0.0),
+        center: Sample(0.0),
+        lfe: Sample(0.0),
+        surround_left: Sample(0.0),
+        surround_right: Sample(0.0),
+    };
+    const UNITY: Self = Surround51 {
+        front_left: Sample(1.0),
+        front_right: Sample(1.0),
+        center: Sample(1.0),
+        lfe: Sample(1.0),
+        surround_left: Sample(1.0),
+        surround_right: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        Surround51 {
+            center: x,
+            ..Default::default()
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        Sample(
+            self.center.0
+                + (self.front_left.0 + self.front_right.0) * FastMath::sqrt(0.5)
+                + (self.surround_left.0 + self.surround_right.0) * FastMath::sqrt(0.5),
+        )
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[
+            ChannelLabel::FrontLeft,
+            ChannelLabel::FrontRight,
+            ChannelLabel::Center,
+            ChannelLabel::Lfe,
+            ChannelLabel::SideLeft,
+            ChannelLabel::SideRight,
+        ]
+    }
+}
+
+/// Pans a given sample to the given position, using the speaker angles of
+/// ITU-R BS.775. The sample is panned with constant power between the two
+/// speakers adjacent to the azimuth. As the layout has no height speakers the
+/// elevation is ignored, placing the sample at its projection onto the
+/// horizontal plane. The LFE channel receives no signal.
+impl Panner<SphericalPosition> for Surround51 {
+    fn to_sample_format(s: Sample, g: SphericalPosition) -> Self {
+        Surround51::pan(s, g.azimuth)
+    }
+}
+
+/// Pans a given sample to the given position in the same way as the panner
+/// taking only a [`SphericalPosition`], additionally sending the sample to the
+/// LFE channel with the given [`LfeSend`].
+///
+/// [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+/// [`LfeSend`]: ../lfe_send/struct.LfeSend.html
+impl Panner<(SphericalPosition, LfeSend)> for Surround51 {
+    fn to_sample_format(s: Sample, g: (SphericalPosition, LfeSend)) -> Self {
+        let mut p = Surround51::to_sample_format(s, g.0);
+        p.lfe = g.1.apply(s);
+        p
+    }
+}
+
+/// Pans a given sample to the given position in the same way as the panner
+/// taking only a [`SphericalPosition`], diverting part of the center channel to
+/// a phantom center with the given [`CenterDivergence`].
+///
+/// [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+/// [`CenterDivergence`]: ../center_divergence/struct.CenterDivergence.html
+impl Panner<(SphericalPosition, CenterDivergence)> for Surround51 {
+    fn to_sample_format(s: Sample, g: (SphericalPosition, CenterDivergence)) -> Self {
+        let mut p = Surround51::to_sample_format(s, g.0);
+        g.1.apply(&mut p.front_left, &mut p.center, &mut p.front_right);
+        p
+    }
+}
+
+/// Pans a given sample to the position of the given [`SpatialSource`],
+/// attenuating it with its distance and spreading it across its width. A
+/// spread source is split into virtual sources across its arc, each panned in
+/// the same way as the panner taking only a [`SphericalPosition`], and the
+/// summed gains are normalized to constant power.
+///
+/// [`SpatialSource`]: ../spatial_source/struct.SpatialSource.html
+/// [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+impl Panner<SpatialSource> for Surround51 {
+    fn to_sample_format(s: Sample, g: SpatialSource) -> Self {
+        let gains: Surround51 = panning::spread_gains(g.position, g.spread);
+
+        gains * Sample((s.0 as AccurateMath * g.gain()) as FastMath)
+    }
+}
+
+impl From<Mono> for Surround51 {
+    fn from(s: Mono) -> Self {
+        s.convert_format()
+    }
+}
+
+impl From<Stereo> for Surround51 {
+    fn from(s: Stereo) -> Self {
+        s.convert_format()
+    }
+}
+
+impl From<Lcr> for Surround51 {
+    fn from(s: Lcr) -> Self {
+        s.convert_format()
+    }
+}
+
+impl From<Surround21> for Surround51 {
+    fn from(s: Surround21) -> Self {
+        s.convert_format()
+    }
+}
+
+impl Balancer for Surround51 {}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Surround51 {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.front_left,
+            1 => &self.front_right,
+            2 => &self.center,
+            3 => &self.lfe,
+            4 => &self.surround_left,
+            5 => &self.surround_right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround51 with 6 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Surround51 {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.front_left,
+            1 => &mut self.front_right,
+            2 => &mut self.center,
+            3 => &mut self.lfe,
+            4 => &mut self.surround_left,
+            5 => &mut self.surround_right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround51 with 6 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Surround51 {
+    fn as_ref(&self) -> &[Sample] {
+        // Surround51 is #[repr(C)] and made up solely of 6 Sample fields.
+        unsafe { channel::as_slice(self, 6) }
+    }
+}
+impl AsMut<[Sample]> for Surround51 {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Surround51 is #[repr(C)] and made up solely of 6 Sample fields.
+        unsafe { channel::as_mut_slice(self, 6) }
+    }
+}
+
+impl std::ops::Neg for Surround51 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Surround51 {
+            front_left: Sample(-self.front_left.0),
+            front_right: Sample(-self.front_right.0),
+            center: Sample(-self.center.0),
+            lfe: Sample(-self.lfe.0),
+            surround_left: Sample(-self.surround_left.0),
+            surround_right: Sample(-self.surround_right.0),
+        }
+    }
+}
+
+impl std::ops::Add<Surround51> for Surround51 {
+    type Output = Self;
+
+    fn add(self, rhs: Surround51) -> Self::Output {
+        Surround51 {
+            front_left: Sample(self.front_left.0 + rhs.front_left.0),
+            front_right: Sample(self.front_right.0 + rhs.front_right.0),
+            center: Sample(self.center.0 + rhs.center.0),
+            lfe: Sample(self.lfe.0 + rhs.lfe.0),
+            surround_left: Sample(self.surround_left.0 + rhs.surround_left.0),
+            surround_right: Sample(self.surround_right.0 + rhs.surround_right.0),
+        }
+    }
+}
+impl std::ops::AddAssign<Surround51> for Surround51 {
+    fn add_assign(&mut self, rhs: Surround51) {
+        self.front_left.0 += rhs.front_left.0;
+        self.front_right.0 += rhs.front_right.0;
+        self.center.0 += rhs.center.0;
+        self.lfe.0 += rhs.lfe.0;
+        self.surround_left.0 += rhs.surround_left.0;
+        self.surround_right.0 += rhs.surround_right.0;
+    }
+}
+
+impl std::ops::Sub<Surround51> for Surround51 {
+    type Output = Self;
+
+    fn sub(self, rhs: Surround51) -> Self {
+        Surround51 {
+            front_left: Sample(self.front_left.0 - rhs.front_left.0),
+            front_right: Sample(self.front_right.0 - rhs.front_right.0),
+            center: Sample(self.center.0 - rhs.center.0),
+            lfe: Sample(self.lfe.0 - rhs.lfe.0),
+            surround_left: Sample(self.surround_left.0 - rhs.surround_left.0),
+            surround_right: Sample(self.surround_right.0 - rhs.surround_right.0),
+        }
+    }
+}
+impl std::ops::SubAssign<Surround51> for Surround51 {
+    fn sub_assign(&mut self, rhs: Surround51) {
+        self.front_left.0 -= rhs.front_left.0;
+        self.front_right.0 -= rhs.front_right.0;
+        self.center.0 -= rhs.center.0;
+        self.lfe.0 -= rhs.lfe.0;
+        self.surround_left.0 -= rhs.surround_left.0;
+        self.surround_right.0 -= rhs.surround_right.0;
+    }
+}
+
+impl std::ops::Mul<Surround51> for Surround51 {
+    type Output = Surround51;
+
+    fn mul(self, rhs: Surround51) -> Self::Output {
+        Surround51 {
+            front_left: Sample(self.front_left.0 * rhs.front_left.0),
+            front_right: Sample(self.front_right.0 * rhs.front_right.0),
+            center: Sample(self.center.0 * rhs.center.0),
+            lfe: Sample(self.lfe.0 * rhs.lfe.0),
+            surround_left: Sample(self.surround_left.0 * rhs.surround_left.0),
+            surround_right: Sample(self.surround_right.0 * rhs.surround_right.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Surround51> for Surround51 {
+    fn mul_assign(&mut self, rhs: Surround51) {
+        self.front_left.0 *= rhs.front_left.0;
+        self.front_right.0 *= rhs.front_right.0;
+        self.center.0 *= rhs.center.0;
+        self.lfe.0 *= rhs.lfe.0;
+        self.surround_left.0 *= rhs.surround_left.0;
+        self.surround_right.0 *= rhs.surround_right.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for Surround51 {
+    type Output = Surround51;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        Surround51 {
+            front_left: Sample(self.front_left.0 * rhs.0),
+            front_right: Sample(self.front_right.0 * rhs.0),
+            center: Sample(self.center.0 * rhs.0),
+            lfe: Sample(self.lfe.0 * rhs.0),
+            surround_left: Sample(self.surround_left.0 * rhs.0),
+            surround_right: Sample(self.surround_right.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for Surround51 {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.front_left.0 *= rhs.0;
+        self.front_right.0 *= rhs.0;
+        self.center.0 *= rhs.0;
+        self.lfe.0 *= rhs.0;
+        self.surround_left.0 *= rhs.0;
+        self.surround_right.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for Surround51 {
+    type Output = Surround51;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        Surround51 {
+            front_left: Sample((self.front_left.0 as AccurateMath * rhs.0) as FastMath),
+            front_right: Sample((self.front_right.0 as AccurateMath * rhs.0) as FastMath),
+            center: Sample((self.center.0 as AccurateMath * rhs.0) as FastMath),
+            lfe: Sample((self.lfe.0 as AccurateMath * rhs.0) as FastMath),
+            surround_left: Sample((self.surround_left.0 as AccurateMath * rhs.0) as FastMath),
+            surround_right: Sample((self.surround_right.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for Surround51 {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.front_left.0 *= rhs.0 as FastMath;
+        self.front_right.0 *= rhs.0 as FastMath;
+        self.center.0 *= rhs.0 as FastMath;
+        self.lfe.0 *= rhs.0 as FastMath;
+        self.surround_left.0 *= rhs.0 as FastMath;
+        self.surround_right.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for Surround51 {
+    type Output = Surround51;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Surround51 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Surround51 {
+    type Output = Surround51;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Surround51 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 6]> for Surround51 {
+    type Output = Surround51;
+
+    fn mul(mut self, rhs: [Sample; 6]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 6]> for Surround51 {
+    fn mul_assign(&mut self, rhs: [Sample; 6]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Surround51 {
+    fn sum<I: Iterator<Item = Surround51>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Surround51> for Surround51 {
+    fn sum<I: Iterator<Item = &'a Surround51>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Surround51 {
+    fn product<I: Iterator<Item = Surround51>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Surround51> for Surround51 {
+    fn product<I: Iterator<Item = &'a Surround51>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for Surround51 {
+    fn from(s: Sample) -> Self {
+        Surround51::from_sample(s)
+    }
+}
+impl Into<Sample> for Surround51 {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 6]> for Surround51 {
+    fn from(a: [Sample; 6]) -> Self {
+        Surround51 {
+            front_left: a[0],
+            front_right: a[1],
+            center: a[2],
+            lfe: a[3],
+            surround_left: a[4],
+            surround_right: a[5],
+        }
+    }
+}
+impl Into<[Sample; 6]> for Surround51 {
+    fn into(self) -> [Sample; 6] {
+        [
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.surround_left,
+            self.surround_right,
+        ]
+    }
+}
+
+impl From<(Sample, Sample, Sample, Sample, Sample, Sample)> for Surround51 {
+    fn from(t: (Sample, Sample, Sample, Sample, Sample, Sample)) -> Self {
+        Surround51 {
+            front_left: t.0,
+            front_right: t.1,
+            center: t.2,
+            lfe: t.3,
+            surround_left: t.4,
+            surround_right: t.5,
+        }
+    }
+}
+impl Into<(Sample, Sample, Sample, Sample, Sample, Sample)> for Surround51 {
+    fn into(self) -> (Sample, Sample, Sample, Sample, Sample, Sample) {
+        (
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.surround_left,
+            self.surround_right,
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for Surround51 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 6 {
+            Err(SampleFormatError::WrongLength {
+                expected: 6,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround51 {
+                front_left: sample_from_u8(v[0]),
+                front_right: sample_from_u8(v[1]),
+                center: sample_from_u8(v[2]),
+                lfe: sample_from_u8(v[3]),
+                surround_left: sample_from_u8(v[4]),
+                surround_right: sample_from_u8(v[5]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for Surround51 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for Surround51 {
+    fn into(self) -> Vec<u8> {
+        vec![
+            sample_to_u8(self.front_left),
+            sample_to_u8(self.front_right),
+            sample_to_u8(self.center),
+            sample_to_u8(self.lfe),
+            sample_to_u8(self.surround_left),
+            sample_to_u8(self.surround_right),
+        ]
+    }
+}
+
+impl TryFrom<&[i16]> for Surround51 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 6 {
+            Err(SampleFormatError::WrongLength {
+                expected: 6,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround51 {
+                front_left: sample_from_i16(v[0]),
+                front_right: sample_from_i16(v[1]),
+                center: sample_from_i16(v[2]),
+                lfe: sample_from_i16(v[3]),
+                surround_left: sample_from_i16(v[4]),
+                surround_right: sample_from_i16(v[5]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for Surround51 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for Surround51 {
+    fn into(self) -> Vec<i16> {
+        vec![
+            sample_to_i16(self.front_left),
+            sample_to_i16(self.front_right),
+            sample_to_i16(self.center),
+            sample_to_i16(self.lfe),
+            sample_to_i16(self.surround_left),
+            sample_to_i16(self.surround_right),
+        ]
+    }
+}
+
+impl TryFrom<&[i32]> for Surround51 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 6 {
+            Err(SampleFormatError::WrongLength {
+                expected: 6,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround51 {
+                front_left: sample_from_i24(v[0]),
+                front_right: sample_from_i24(v[1]),
+                center: sample_from_i24(v[2]),
+                lfe: sample_from_i24(v[3]),
+                surround_left: sample_from_i24(v[4]),
+                surround_right: sample_from_i24(v[5]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for Surround51 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for Surround51 {
+    fn into(self) -> Vec<i32> {
+        vec![
+            sample_to_i24(self.front_left),
+            sample_to_i24(self.front_right),
+            sample_to_i24(self.center),
+            sample_to_i24(self.lfe),
+            sample_to_i24(self.surround_left),
+            sample_to_i24(self.surround_right),
+        ]
+    }
+}
+
+impl TryFrom<&[f32]> for Surround51 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 6 {
+            Err(SampleFormatError::WrongLength {
+                expected: 6,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround51 {
+                front_left: sample_from_f32(v[0]),
+                front_right: sample_from_f32(v[1]),
+                center: sample_from_f32(v[2]),
+                lfe: sample_from_f32(v[3]),
+                surround_left: sample_from_f32(v[4]),
+                surround_right: sample_from_f32(v[5]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Surround51 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Surround51 {
+    fn into(self) -> Vec<f32> {
+        vec![
+            sample_to_f32(self.front_left),
+            sample_to_f32(self.front_right),
+            sample_to_f32(self.center),
+            sample_to_f32(self.lfe),
+            sample_to_f32(self.surround_left),
+            sample_to_f32(self.surround_right),
+        ]
+    }
+}
+
+impl TryFrom<&[f64]> for Surround51 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 6 {
+            Err(SampleFormatError::WrongLength {
+                expected: 6,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround51 {
+                front_left: sample_from_f64(v[0]),
+                front_right: sample_from_f64(v[1]),
+                center: sample_from_f64(v[2]),
+                lfe: sample_from_f64(v[3]),
+                surround_left: sample_from_f64(v[4]),
+                surround_right: sample_from_f64(v[5]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Surround51 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Surround51 {
+    fn into(self) -> Vec<f64> {
+        vec![
+            sample_to_f64(self.front_left),
+            sample_to_f64(self.front_right),
+            sample_to_f64(self.center),
+            sample_to_f64(self.lfe),
+            sample_to_f64(self.surround_left),
+            sample_to_f64(self.surround_right),
+        ]
+    }
+}
@@ -0,0 +1,774 @@
+//! # Surround 5.1.2
+//!
+//! Module containing type for handling 5.1.2 immersive audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Surround512`] samples
+///
+/// [`Surround512`]: struct.Surround512.html
+pub type Surround512TrackT = Vec<Surround512>;
+
+/// Struct representing a 5.1.2 immersive audio sample, that being a 5.1 sample
+/// with an added pair of top channels.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Surround512 {
+    /// Front left sample value.
+    pub front_left: Sample,
+    /// Front right sample value.
+    pub front_right: Sample,
+    /// Center sample value.
+    pub center: Sample,
+    /// Low-frequency effects sample value.
+    pub lfe: Sample,
+    /// Surround left sample value.
+    pub surround_left: Sample,
+    /// Surround right sample value.
+    pub surround_right: Sample,
+    /// Top left sample value.
+    pub top_left: Sample,
+    /// Top right sample value.
+    pub top_right: Sample,
+}
+
+impl Surround512 {
+    /// Returns a new Surround512 object with all channels set to 0.
+    pub const fn new() -> Self {
+        Surround512::SILENCE
+    }
+
+    /// Returns a new Surround512 object created from the individual audio
+    /// samples of each channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `fl` - the front left audio sample.
+    /// * `fr` - the front right audio sample.
+    /// * `c` - the center audio sample.
+    /// * `lfe` - the low-frequency effects audio sample.
+    /// * `sl` - the surround left audio sample.
+    /// * `sr` - the surround right audio sample.
+    /// * `tl` - the top left audio sample.
+    /// * `tr` - the top right audio sample.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn from(
+        fl: Sample,
+        fr: Sample,
+        c: Sample,
+        lfe: Sample,
+        sl: Sample,
+        sr: Sample,
+        tl: Sample,
+        tr: Sample,
+    ) -> Self {
+        Surround512 {
+            front_left: fl,
+            front_right: fr,
+            center: c,
+            lfe,
+            surround_left: sl,
+            surround_right: sr,
+            top_left: tl,
+            top_right: tr,
+        }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 8] {
+        let s: [Sample; 8] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Surround512 object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 8]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Downmixes the 5.1.2 sample to a 5.1 sample. The top left and right
+    /// channels are scaled by the given gain and folded down into the front
+    /// left and right channels respectively, the remaining channels are copied
+    /// as-is.
+    ///
+    /// # Parameters
+    ///
+    /// * `height_gain` - the linear gain applied to the top channels before they
+    /// are folded down. A common value is -3dB, which can be created with
+    /// [`db_to_linear`].
+    ///
+    /// [`db_to_linear`]: https://docs.rs/bae_utils/*/bae_utils/fn.db_to_linear.html
+    pub fn to_surround51(self, height_gain: Math) -> Surround51 {
+        let g = height_gain.0;
+
+        Surround51 {
+            front_left: Sample(
+                (self.front_left.0 as AccurateMath + self.top_left.0 as AccurateMath * g)
+                    as FastMath,
+            ),
+            front_right: Sample(
+                (self.front_right.0 as AccurateMath + self.top_right.0 as AccurateMath * g)
+                    as FastMath,
+            ),
+            center: self.center,
+            lfe: self.lfe,
+            surround_left: self.surround_left,
+            surround_right: self.surround_right,
+        }
+    }
+}
+
+impl SampleFormat for Surround512 {
+    const CHANNEL_COUNT: usize = 8;
+
+    const SILENCE: Self = Surround512 {
+        front_left: Sample(0.0),
+        front_right: Sample(0.0),
+        center: Sample(0.0),
+        lfe: Sample(0.0),
+        surround_left: Sample(0.0),
+        surround_right: Sample(0.0),
+        top_left: Sample(0.0),
+        top_right: Sample(0.0),
+    };
+    const UNITY: Self = Surround512 {
+        front_left: Sample(1.0),
+        front_right: Sample(1.0),
+        center: Sample(1.0),
+        lfe: Sample(1.0),
+        surround_left: Sample(1.0),
+        surround_right: Sample(1.0),
+        top_left: Sample(1.0),
+        top_right: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        Surround512 {
+            center: x,
+            ..Default::default()
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        self.to_surround51(Math(1.0)).into_sample()
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[
+            ChannelLabel::FrontLeft,
+            ChannelLabel::FrontRight,
+            ChannelLabel::Center,
+            ChannelLabel::Lfe,
+            ChannelLabel::SideLeft,
+            ChannelLabel::SideRight,
+            ChannelLabel::TopSideLeft,
+            ChannelLabel::TopSideRight,
+        ]
+    }
+}
+
+impl From<Surround51> for Surround512 {
+    fn from(s: Surround51) -> Self {
+        s.convert_format()
+    }
+}
+
+impl Balancer for Surround512 {}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Surround512 {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.front_left,
+            1 => &self.front_right,
+            2 => &self.center,
+            3 => &self.lfe,
+            4 => &self.surround_left,
+            5 => &self.surround_right,
+            6 => &self.top_left,
+            7 => &self.top_right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround512 with 8 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Surround512 {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.front_left,
+            1 => &mut self.front_right,
+            2 => &mut self.center,
+            3 => &mut self.lfe,
+            4 => &mut self.surround_left,
+            5 => &mut self.surround_right,
+            6 => &mut self.top_left,
+            7 => &mut self.top_right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround512 with 8 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Surround512 {
+    fn as_ref(&self) -> &[Sample] {
+        // Surround512 is #[repr(C)] and made up solely of 8 Sample fields.
+        unsafe { channel::as_slice(self, 8) }
+    }
+}
+impl AsMut<[Sample]> for Surround512 {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Surround512 is #[repr(C)] and made up solely of 8 Sample fields.
+        unsafe { channel::as_mut_slice(self, 8) }
+    }
+}
+
+impl std::ops::Neg for Surround512 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Surround512 {
+            front_left: Sample(-self.front_left.0),
+            front_right: Sample(-self.front_right.0),
+            center: Sample(-self.center.0),
+            lfe: Sample(-self.lfe.0),
+            surround_left: Sample(-self.surround_left.0),
+            surround_right: Sample(-self.surround_right.0),
+            top_left: Sample(-self.top_left.0),
+            top_right: Sample(-self.top_right.0),
+        }
+    }
+}
+
+impl std::ops::Add<Surround512> for Surround512 {
+    type Output = Self;
+
+    fn add(self, rhs: Surround512) -> Self::Output {
+        Surround512 {
+            front_left: Sample(self.front_left.0 + rhs.front_left.0),
+            front_right: Sample(self.front_right.0 + rhs.front_right.0),
+            center: Sample(self.center.0 + rhs.center.0),
+            lfe: Sample(self.lfe.0 + rhs.lfe.0),
+            surround_left: Sample(self.surround_left.0 + rhs.surround_left.0),
+            surround_right: Sample(self.surround_right.0 + rhs.surround_right.0),
+            top_left: Sample(self.top_left.0 + rhs.top_left.0),
+            top_right: Sample(self.top_right.0 + rhs.top_right.0),
+        }
+    }
+}
+impl std::ops::AddAssign<Surround512> for Surround512 {
+    fn add_assign(&mut self, rhs: Surround512) {
+        self.front_left.0 += rhs.front_left.0;
+        self.front_right.0 += rhs.front_right.0;
+        self.center.0 += rhs.center.0;
+        self.lfe.0 += rhs.lfe.0;
+        self.surround_left.0 += rhs.surround_left.0;
+        self.surround_right.0 += rhs.surround_right.0;
+        self.top_left.0 += rhs.top_left.0;
+        self.top_right.0 += rhs.top_right.0;
+    }
+}
+
+impl std::ops::Sub<Surround512> for Surround512 {
+    type Output = Self;
+
+    fn sub(self, rhs: Surround512) -> Self {
+        Surround512 {
+            front_left: Sample(self.front_left.0 - rhs.front_left.0),
+            front_right: Sample(self.front_right.0 - rhs.front_right.0),
+            center: Sample(self.center.0 - rhs.center.0),
+            lfe: Sample(self.lfe.0 - rhs.lfe.0),
+            surround_left: Sample(self.surround_left.0 - rhs.surround_left.0),
+            surround_right: Sample(self.surround_right.0 - rhs.surround_right.0),
+            top_left: Sample(self.top_left.0 - rhs.top_left.0),
+            top_right: Sample(self.top_right.0 - rhs.top_right.0),
+        }
+    }
+}
+impl std::ops::SubAssign<Surround512> for Surround512 {
+    fn sub_assign(&mut self, rhs: Surround512) {
+        self.front_left.0 -= rhs.front_left.0;
+        self.front_right.0 -= rhs.front_right.0;
+        self.center.0 -= rhs.center.0;
+        self.lfe.0 -= rhs.lfe.0;
+        self.surround_left.0 -= rhs.surround_left.0;
+        self.surround_right.0 -= rhs.surround_right.0;
+        self.top_left.0 -= rhs.top_left.0;
+        self.top_right.0 -= rhs.top_right.0;
+    }
+}
+
+impl std::ops::Mul<Surround512> for Surround512 {
+    type Output = Surround512;
+
+    fn mul(self, rhs: Surround512) -> Self::Output {
+        Surround512 {
+            front_left: Sample(self.front_left.0 * rhs.front_left.0),
+            front_right: Sample(self.front_right.0 * rhs.front_right.0),
+            center: Sample(self.center.0 * rhs.center.0),
+            lfe: Sample(self.lfe.0 * rhs.lfe.0),
+            surround_left: Sample(self.surround_left.0 * rhs.surround_left.0),
+            surround_right: Sample(self.surround_right.0 * rhs.surround_right.0),
+            top_left: Sample(self.top_left.0 * rhs.top_left.0),
+            top_right: Sample(self.top_right.0 * rhs.top_right.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Surround512> for Surround512 {
+    fn mul_assign(&mut self, rhs: Surround512) {
+        self.front_left.0 *= rhs.front_left.0;
+        self.front_right.0 *= rhs.front_right.0;
+        self.center.0 *= rhs.center.0;
+        self.lfe.0 *= rhs.lfe.0;
+        self.surround_left.0 *= rhs.surround_left.0;
+        self.surround_right.0 *= rhs.surround_right.0;
+        self.top_left.0 *= rhs.top_left.0;
+        self.top_right.0 *= rhs.top_right.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for Surround512 {
+    type Output = Surround512;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        Surround512 {
+            front_left: Sample(self.front_left.0 * rhs.0),
+            front_right: Sample(self.front_right.0 * rhs.0),
+            center: Sample(self.center.0 * rhs.0),
+            lfe: Sample(self.lfe.0 * rhs.0),
+            surround_left: Sample(self.surround_left.0 * rhs.0),
+            surround_right: Sample(self.surround_right.0 * rhs.0),
+            top_left: Sample(self.top_left.0 * rhs.0),
+            top_right: Sample(self.top_right.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for Surround512 {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.front_left.0 *= rhs.0;
+        self.front_right.0 *= rhs.0;
+        self.center.0 *= rhs.0;
+        self.lfe.0 *= rhs.0;
+        self.surround_left.0 *= rhs.0;
+        self.surround_right.0 *= rhs.0;
+        self.top_left.0 *= rhs.0;
+        self.top_right.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for Surround512 {
+    type Output = Surround512;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        Surround512 {
+            front_left: Sample((self.front_left.0 as AccurateMath * rhs.0) as FastMath),
+            front_right: Sample((self.front_right.0 as AccurateMath * rhs.0) as FastMath),
+            center: Sample((self.center.0 as AccurateMath * rhs.0) as FastMath),
+            lfe: Sample((self.lfe.0 as AccurateMath * rhs.0) as FastMath),
+            surround_left: Sample((self.surround_left.0 as AccurateMath * rhs.0) as FastMath),
+            surround_right: Sample((self.surround_right.0 as AccurateMath * rhs.0) as FastMath),
+            top_left: Sample((self.top_left.0 as AccurateMath * rhs.0) as FastMath),
+            top_right: Sample((self.top_right.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for Surround512 {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.front_left.0 *= rhs.0 as FastMath;
+        self.front_right.0 *= rhs.0 as FastMath;
+        self.center.0 *= rhs.0 as FastMath;
+        self.lfe.0 *= rhs.0 as FastMath;
+        self.surround_left.0 *= rhs.0 as FastMath;
+        self.surround_right.0 *= rhs.0 as FastMath;
+        self.top_left.0 *= rhs.0 as FastMath;
+        self.top_right.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for Surround512 {
+    type Output = Surround512;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Surround512 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Surround512 {
+    type Output = Surround512;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Surround512 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 8]> for Surround512 {
+    type Output = Surround512;
+
+    fn mul(mut self, rhs: [Sample; 8]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 8]> for Surround512 {
+    fn mul_assign(&mut self, rhs: [Sample; 8]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Surround512 {
+    fn sum<I: Iterator<Item = Surround512>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Surround512> for Surround512 {
+    fn sum<I: Iterator<Item = &'a Surround512>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Surround512 {
+    fn product<I: Iterator<Item = Surround512>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Surround512> for Surround512 {
+    fn product<I: Iterator<Item = &'a Surround512>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for Surround512 {
+    fn from(s: Sample) -> Self {
+        Surround512::from_sample(s)
+    }
+}
+impl Into<Sample> for Surround512 {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 8]> for Surround512 {
+    fn from(a: [Sample; 8]) -> Self {
+        Surround512 {
+            front_left: a[0],
+            front_right: a[1],
+            center: a[2],
+            lfe: a[3],
+            surround_left: a[4],
+            surround_right: a[5],
+            top_left: a[6],
+            top_right: a[7],
+        }
+    }
+}
+impl Into<[Sample; 8]> for Surround512 {
+    fn into(self) -> [Sample; 8] {
+        [
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.surround_left,
+            self.surround_right,
+            self.top_left,
+            self.top_right,
+        ]
+    }
+}
+
+/// Tuple holding the channels of a [`Surround512`] in order.
+///
+/// [`Surround512`]: struct.Surround512.html
+type Channels = (
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+);
+
+impl From<Channels> for Surround512 {
+    fn from(t: Channels) -> Self {
+        Surround512 {
+            front_left: t.0,
+            front_right: t.1,
+            center: t.2,
+            lfe: t.3,
+            surround_left: t.4,
+            surround_right: t.5,
+            top_left: t.6,
+            top_right: t.7,
+        }
+    }
+}
+impl Into<Channels> for Surround512 {
+    fn into(self) -> Channels {
+        (
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.surround_left,
+            self.surround_right,
+            self.top_left,
+            self.top_right,
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for Surround512 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 8 {
+            Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround512 {
+                front_left: sample_from_u8(v[0]),
+                front_right: sample_from_u8(v[1]),
+                center: sample_from_u8(v[2]),
+                lfe: sample_from_u8(v[3]),
+                surround_left: sample_from_u8(v[4]),
+                surround_right: sample_from_u8(v[5]),
+                top_left: sample_from_u8(v[6]),
+                top_right: sample_from_u8(v[7]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for Surround512 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for Surround512 {
+    fn into(self) -> Vec<u8> {
+        vec![
+            sample_to_u8(self.front_left),
+            sample_to_u8(self.front_right),
+            sample_to_u8(self.center),
+            sample_to_u8(self.lfe),
+            sample_to_u8(self.surround_left),
+            sample_to_u8(self.surround_right),
+            sample_to_u8(self.top_left),
+            sample_to_u8(self.top_right),
+        ]
+    }
+}
+
+impl TryFrom<&[i16]> for Surround512 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 8 {
+            Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround512 {
+                front_left: sample_from_i16(v[0]),
+                front_right: sample_from_i16(v[1]),
+                center: sample_from_i16(v[2]),
+                lfe: sample_from_i16(v[3]),
+                surround_left: sample_from_i16(v[4]),
+                surround_right: sample_from_i16(v[5]),
+                top_left: sample_from_i16(v[6]),
+                top_right: sample_from_i16(v[7]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for Surround512 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for Surround512 {
+    fn into(self) -> Vec<i16> {
+        vec![
+            sample_to_i16(self.front_left),
+            sample_to_i16(self.front_right),
+            sample_to_i16(self.center),
+            sample_to_i16(self.lfe),
+            sample_to_i16(self.surround_left),
+            sample_to_i16(self.surround_right),
+            sample_to_i16(self.top_left),
+            sample_to_i16(self.top_right),
+        ]
+    }
+}
+
+impl TryFrom<&[i32]> for Surround512 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 8 {
+            Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround512 {
+                front_left: sample_from_i24(v[0]),
+                front_right: sample_from_i24(v[1]),
+                center: sample_from_i24(v[2]),
+                lfe: sample_from_i24(v[3]),
+                surround_left: sample_from_i24(v[4]),
+                surround_right: sample_from_i24(v[5]),
+                top_left: sample_from_i24(v[6]),
+                top_right: sample_from_i24(v[7]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for Surround512 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for Surround512 {
+    fn into(self) -> Vec<i32> {
+        vec![
+            sample_to_i24(self.front_left),
+            sample_to_i24(self.front_right),
+            sample_to_i24(self.center),
+            sample_to_i24(self.lfe),
+            sample_to_i24(self.surround_left),
+            sample_to_i24(self.surround_right),
+            sample_to_i24(self.top_left),
+            sample_to_i24(self.top_right),
+        ]
+    }
+}
+
+impl TryFrom<&[f32]> for Surround512 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 8 {
+            Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround512 {
+                front_left: sample_from_f32(v[0]),
+                front_right: sample_from_f32(v[1]),
+                center: sample_from_f32(v[2]),
+                lfe: sample_from_f32(v[3]),
+                surround_left: sample_from_f32(v[4]),
+                surround_right: sample_from_f32(v[5]),
+                top_left: sample_from_f32(v[6]),
+                top_right: sample_from_f32(v[7]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Surround512 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Surround512 {
+    fn into(self) -> Vec<f32> {
+        vec![
+            sample_to_f32(self.front_left),
+            sample_to_f32(self.front_right),
+            sample_to_f32(self.center),
+            sample_to_f32(self.lfe),
+            sample_to_f32(self.surround_left),
+            sample_to_f32(self.surround_right),
+            sample_to_f32(self.top_left),
+            sample_to_f32(self.top_right),
+        ]
+    }
+}
+
+impl TryFrom<&[f64]> for Surround512 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 8 {
+            Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround512 {
+                front_left: sample_from_f64(v[0]),
+                front_right: sample_from_f64(v[1]),
+                center: sample_from_f64(v[2]),
+                lfe: sample_from_f64(v[3]),
+                surround_left: sample_from_f64(v[4]),
+                surround_right: sample_from_f64(v[5]),
+                top_left: sample_from_f64(v[6]),
+                top_right: sample_from_f64(v[7]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Surround512 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Surround512 {
+    fn into(self) -> Vec<f64> {
+        vec![
+            sample_to_f64(self.front_left),
+            sample_to_f64(self.front_right),
+            sample_to_f64(self.center),
+            sample_to_f64(self.lfe),
+            sample_to_f64(self.surround_left),
+            sample_to_f64(self.surround_right),
+            sample_to_f64(self.top_left),
+            sample_to_f64(self.top_right),
+        ]
+    }
+}
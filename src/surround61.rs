@@ -0,0 +1,709 @@
+//! # Surround 6.1
+//!
+//! Module containing type for handling 6.1 surround audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Surround61`] samples
+///
+/// [`Surround61`]: struct.Surround61.html
+pub type Surround61TrackT = Vec<Surround61>;
+
+/// Struct representing a 6.1 surround audio sample, that being a 5.1 sample
+/// with an added rear center channel.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Surround61 {
+    /// Front left sample value.
+    pub front_left: Sample,
+    /// Front right sample value.
+    pub front_right: Sample,
+    /// Center sample value.
+    pub center: Sample,
+    /// Low-frequency effects sample value.
+    pub lfe: Sample,
+    /// Surround left sample value.
+    pub surround_left: Sample,
+    /// Surround right sample value.
+    pub surround_right: Sample,
+    /// Rear center sample value.
+    pub rear_center: Sample,
+}
+
+impl Surround61 {
+    /// Returns a new Surround61 object with default <0,0,0,0,0,0,0> values.
+    pub const fn new() -> Self {
+        Surround61::SILENCE
+    }
+
+    /// Returns a new Surround61 object created from the individual audio
+    /// samples of each channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `fl` - the front left audio sample.
+    /// * `fr` - the front right audio sample.
+    /// * `c` - the center audio sample.
+    /// * `lfe` - the low-frequency effects audio sample.
+    /// * `sl` - the surround left audio sample.
+    /// * `sr` - the surround right audio sample.
+    /// * `rc` - the rear center audio sample.
+    pub const fn from(
+        fl: Sample,
+        fr: Sample,
+        c: Sample,
+        lfe: Sample,
+        sl: Sample,
+        sr: Sample,
+        rc: Sample,
+    ) -> Self {
+        Surround61 {
+            front_left: fl,
+            front_right: fr,
+            center: c,
+            lfe,
+            surround_left: sl,
+            surround_right: sr,
+            rear_center: rc,
+        }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 7] {
+        let s: [Sample; 7] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Surround61 object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 7]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Downmixes the 6.1 sample to a 5.1 sample. The rear center channel is
+    /// split evenly between the surround left and right channels at -3dB, the
+    /// remaining channels are copied as-is.
+    pub fn to_surround51(self) -> Surround51 {
+        let rc = self.rear_center.0 * FastMath::sqrt(0.5);
+
+        Surround51 {
+            front_left: self.front_left,
+            front_right: self.front_right,
+            center: self.center,
+            lfe: self.lfe,
+            surround_left: Sample(self.surround_left.0 + rc),
+            surround_right: Sample(self.surround_right.0 + rc),
+        }
+    }
+}
+
+impl SampleFormat for Surround61 {
+    const CHANNEL_COUNT: usize = 7;
+
+    const SILENCE: Self = Surround61 {
+        front_left: Sample(0.0),
+        front_right: Sample(0.0),
+        center: Sample(0.0),
+        lfe: Sample(0.0),
+        surround_left: Sample(0.0),
+        surround_right: Sample(0.0),
+        rear_center: Sample(0.0),
+    };
+    const UNITY: Self = Surround61 {
+        front_left: Sample(1.0),
+        front_right: Sample(1.0),
+        center: Sample(1.0),
+        lfe: Sample(1.0),
+        surround_left: Sample(1.0),
+        surround_right: Sample(1.0),
+        rear_center: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        Surround61 {
+            center: x,
+            ..Default::default()
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        self.to_surround51().into_sample()
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[
+            ChannelLabel::FrontLeft,
+            ChannelLabel::FrontRight,
+            ChannelLabel::Center,
+            ChannelLabel::Lfe,
+            ChannelLabel::SideLeft,
+            ChannelLabel::SideRight,
+            ChannelLabel::RearCenter,
+        ]
+    }
+}
+
+impl From<Surround51> for Surround61 {
+    fn from(s: Surround51) -> Self {
+        s.convert_format()
+    }
+}
+
+impl Balancer for Surround61 {}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Surround61 {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.front_left,
+            1 => &self.front_right,
+            2 => &self.center,
+            3 => &self.lfe,
+            4 => &self.surround_left,
+            5 => &self.surround_right,
+            6 => &self.rear_center,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround61 with 7 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Surround61 {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.front_left,
+            1 => &mut self.front_right,
+            2 => &mut self.center,
+            3 => &mut self.lfe,
+            4 => &mut self.surround_left,
+            5 => &mut self.surround_right,
+            6 => &mut self.rear_center,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround61 with 7 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Surround61 {
+    fn as_ref(&self) -> &[Sample] {
+        // Surround61 is #[repr(C)] and made up solely of 7 Sample fields.
+        unsafe { channel::as_slice(self, 7) }
+    }
+}
+impl AsMut<[Sample]> for Surround61 {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Surround61 is #[repr(C)] and made up solely of 7 Sample fields.
+        unsafe { channel::as_mut_slice(self, 7) }
+    }
+}
+
+impl std::ops::Neg for Surround61 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Surround61 {
+            front_left: Sample(-self.front_left.0),
+            front_right: Sample(-self.front_right.0),
+            center: Sample(-self.center.0),
+            lfe: Sample(-self.lfe.0),
+            surround_left: Sample(-self.surround_left.0),
+            surround_right: Sample(-self.surround_right.0),
+            rear_center: Sample(-self.rear_center.0),
+        }
+    }
+}
+
+impl std::ops::Add<Surround61> for Surround61 {
+    type Output = Self;
+
+    fn add(self, rhs: Surround61) -> Self::Output {
+        Surround61 {
+            front_left: Sample(self.front_left.0 + rhs.front_left.0),
+            front_right: Sample(self.front_right.0 + rhs.front_right.0),
+            center: Sample(self.center.0 + rhs.center.0),
+            lfe: Sample(self.lfe.0 + rhs.lfe.0),
+            surround_left: Sample(self.surround_left.0 + rhs.surround_left.0),
+            surround_right: Sample(self.surround_right.0 + rhs.surround_right.0),
+            rear_center: Sample(self.rear_center.0 + rhs.rear_center.0),
+        }
+    }
+}
+impl std::ops::AddAssign<Surround61> for Surround61 {
+    fn add_assign(&mut self, rhs: Surround61) {
+        self.front_left.0 += rhs.front_left.0;
+        self.front_right.0 += rhs.front_right.0;
+        self.center.0 += rhs.center.0;
+        self.lfe.0 += rhs.lfe.0;
+        self.surround_left.0 += rhs.surround_left.0;
+        self.surround_right.0 += rhs.surround_right.0;
+        self.rear_center.0 += rhs.rear_center.0;
+    }
+}
+
+impl std::ops::Sub<Surround61> for Surround61 {
+    type Output = Self;
+
+    fn sub(self, rhs: Surround61) -> Self {
+        Surround61 {
+            front_left: Sample(self.front_left.0 - rhs.front_left.0),
+            front_right: Sample(self.front_right.0 - rhs.front_right.0),
+            center: Sample(self.center.0 - rhs.center.0),
+            lfe: Sample(self.lfe.0 - rhs.lfe.0),
+            surround_left: Sample(self.surround_left.0 - rhs.surround_left.0),
+            surround_right: Sample(self.surround_right.0 - rhs.surround_right.0),
+            rear_center: Sample(self.rear_center.0 - rhs.rear_center.0),
+        }
+    }
+}
+impl std::ops::SubAssign<Surround61> for Surround61 {
+    fn sub_assign(&mut self, rhs: Surround61) {
+        self.front_left.0 -= rhs.front_left.0;
+        self.front_right.0 -= rhs.front_right.0;
+        self.center.0 -= rhs.center.0;
+        self.lfe.0 -= rhs.lfe.0;
+        self.surround_left.0 -= rhs.surround_left.0;
+        self.surround_right.0 -= rhs.surround_right.0;
+        self.rear_center.0 -= rhs.rear_center.0;
+    }
+}
+
+impl std::ops::Mul<Surround61> for Surround61 {
+    type Output = Surround61;
+
+    fn mul(self, rhs: Surround61) -> Self::Output {
+        Surround61 {
+            front_left: Sample(self.front_left.0 * rhs.front_left.0),
+            front_right: Sample(self.front_right.0 * rhs.front_right.0),
+            center: Sample(self.center.0 * rhs.center.0),
+            lfe: Sample(self.lfe.0 * rhs.lfe.0),
+            surround_left: Sample(self.surround_left.0 * rhs.surround_left.0),
+            surround_right: Sample(self.surround_right.0 * rhs.surround_right.0),
+            rear_center: Sample(self.rear_center.0 * rhs.rear_center.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Surround61> for Surround61 {
+    fn mul_assign(&mut self, rhs: Surround61) {
+        self.front_left.0 *= rhs.front_left.0;
+        self.front_right.0 *= rhs.front_right.0;
+        self.center.0 *= rhs.center.0;
+        self.lfe.0 *= rhs.lfe.0;
+        self.surround_left.0 *= rhs.surround_left.0;
+        self.surround_right.0 *= rhs.surround_right.0;
+        self.rear_center.0 *= rhs.rear_center.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for Surround61 {
+    type Output = Surround61;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        Surround61 {
+            front_left: Sample(self.front_left.0 * rhs.0),
+            front_right: Sample(self.front_right.0 * rhs.0),
+            center: Sample(self.center.0 * rhs.0),
+            lfe: Sample(self.lfe.0 * rhs.0),
+            surround_left: Sample(self.surround_left.0 * rhs.0),
+            surround_right: Sample(self.surround_right.0 * rhs.0),
+            rear_center: Sample(self.rear_center.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for Surround61 {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.front_left.0 *= rhs.0;
+        self.front_right.0 *= rhs.0;
+        self.center.0 *= rhs.0;
+        self.lfe.0 *= rhs.0;
+        self.surround_left.0 *= rhs.0;
+        self.surround_right.0 *= rhs.0;
+        self.rear_center.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for Surround61 {
+    type Output = Surround61;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        Surround61 {
+            front_left: Sample((self.front_left.0 as AccurateMath * rhs.0) as FastMath),
+            front_right: Sample((self.front_right.0 as AccurateMath * rhs.0) as FastMath),
+            center: Sample((self.center.0 as AccurateMath * rhs.0) as FastMath),
+            lfe: Sample((self.lfe.0 as AccurateMath * rhs.0) as FastMath),
+            surround_left: Sample((self.surround_left.0 as AccurateMath * rhs.0) as FastMath),
+            surround_right: Sample((self.surround_right.0 as AccurateMath * rhs.0) as FastMath),
+            rear_center: Sample((self.rear_center.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for Surround61 {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.front_left.0 *= rhs.0 as FastMath;
+        self.front_right.0 *= rhs.0 as FastMath;
+        self.center.0 *= rhs.0 as FastMath;
+        self.lfe.0 *= rhs.0 as FastMath;
+        self.surround_left.0 *= rhs.0 as FastMath;
+        self.surround_right.0 *= rhs.0 as FastMath;
+        self.rear_center.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for Surround61 {
+    type Output = Surround61;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Surround61 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Surround61 {
+    type Output = Surround61;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Surround61 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 7]> for Surround61 {
+    type Output = Surround61;
+
+    fn mul(mut self, rhs: [Sample; 7]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 7]> for Surround61 {
+    fn mul_assign(&mut self, rhs: [Sample; 7]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Surround61 {
+    fn sum<I: Iterator<Item = Surround61>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Surround61> for Surround61 {
+    fn sum<I: Iterator<Item = &'a Surround61>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Surround61 {
+    fn product<I: Iterator<Item = Surround61>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Surround61> for Surround61 {
+    fn product<I: Iterator<Item = &'a Surround61>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for Surround61 {
+    fn from(s: Sample) -> Self {
+        Surround61::from_sample(s)
+    }
+}
+impl Into<Sample> for Surround61 {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 7]> for Surround61 {
+    fn from(a: [Sample; 7]) -> Self {
+        Surround61 {
+            front_left: a[0],
+            front_right: a[1],
+            center: a[2],
+            lfe: a[3],
+            surround_left: a[4],
+            surround_right: a[5],
+            rear_center: a[6],
+        }
+    }
+}
+impl Into<[Sample; 7]> for Surround61 {
+    fn into(self) -> [Sample; 7] {
+        [
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.surround_left,
+            self.surround_right,
+            self.rear_center,
+        ]
+    }
+}
+
+impl From<(Sample, Sample, Sample, Sample, Sample, Sample, Sample)> for Surround61 {
+    fn from(t: (Sample, Sample, Sample, Sample, Sample, Sample, Sample)) -> Self {
+        Surround61 {
+            front_left: t.0,
+            front_right: t.1,
+            center: t.2,
+            lfe: t.3,
+            surround_left: t.4,
+            surround_right: t.5,
+            rear_center: t.6,
+        }
+    }
+}
+impl Into<(Sample, Sample, Sample, Sample, Sample, Sample, Sample)> for Surround61 {
+    fn into(self) -> (Sample, Sample, Sample, Sample, Sample, Sample, Sample) {
+        (
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.surround_left,
+            self.surround_right,
+            self.rear_center,
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for Surround61 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 7 {
+            Err(SampleFormatError::WrongLength {
+                expected: 7,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround61 {
+                front_left: sample_from_u8(v[0]),
+                front_right: sample_from_u8(v[1]),
+                center: sample_from_u8(v[2]),
+                lfe: sample_from_u8(v[3]),
+                surround_left: sample_from_u8(v[4]),
+                surround_right: sample_from_u8(v[5]),
+                rear_center: sample_from_u8(v[6]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for Surround61 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for Surround61 {
+    fn into(self) -> Vec<u8> {
+        vec![
+            sample_to_u8(self.front_left),
+            sample_to_u8(self.front_right),
+            sample_to_u8(self.center),
+            sample_to_u8(self.lfe),
+            sample_to_u8(self.surround_left),
+            sample_to_u8(self.surround_right),
+            sample_to_u8(self.rear_center),
+        ]
+    }
+}
+
+impl TryFrom<&[i16]> for Surround61 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 7 {
+            Err(SampleFormatError::WrongLength {
+                expected: 7,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround61 {
+                front_left: sample_from_i16(v[0]),
+                front_right: sample_from_i16(v[1]),
+                center: sample_from_i16(v[2]),
+                lfe: sample_from_i16(v[3]),
+                surround_left: sample_from_i16(v[4]),
+                surround_right: sample_from_i16(v[5]),
+                rear_center: sample_from_i16(v[6]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for Surround61 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for Surround61 {
+    fn into(self) -> Vec<i16> {
+        vec![
+            sample_to_i16(self.front_left),
+            sample_to_i16(self.front_right),
+            sample_to_i16(self.center),
+            sample_to_i16(self.lfe),
+            sample_to_i16(self.surround_left),
+            sample_to_i16(self.surround_right),
+            sample_to_i16(self.rear_center),
+        ]
+    }
+}
+
+impl TryFrom<&[i32]> for Surround61 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 7 {
+            Err(SampleFormatError::WrongLength {
+                expected: 7,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround61 {
+                front_left: sample_from_i24(v[0]),
+                front_right: sample_from_i24(v[1]),
+                center: sample_from_i24(v[2]),
+                lfe: sample_from_i24(v[3]),
+                surround_left: sample_from_i24(v[4]),
+                surround_right: sample_from_i24(v[5]),
+                rear_center: sample_from_i24(v[6]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for Surround61 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for Surround61 {
+    fn into(self) -> Vec<i32> {
+        vec![
+            sample_to_i24(self.front_left),
+            sample_to_i24(self.front_right),
+            sample_to_i24(self.center),
+            sample_to_i24(self.lfe),
+            sample_to_i24(self.surround_left),
+            sample_to_i24(self.surround_right),
+            sample_to_i24(self.rear_center),
+        ]
+    }
+}
+
+impl TryFrom<&[f32]> for Surround61 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 7 {
+            Err(SampleFormatError::WrongLength {
+                expected: 7,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround61 {
+                front_left: sample_from_f32(v[0]),
+                front_right: sample_from_f32(v[1]),
+                center: sample_from_f32(v[2]),
+                lfe: sample_from_f32(v[3]),
+                surround_left: sample_from_f32(v[4]),
+                surround_right: sample_from_f32(v[5]),
+                rear_center: sample_from_f32(v[6]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Surround61 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Surround61 {
+    fn into(self) -> Vec<f32> {
+        vec![
+            sample_to_f32(self.front_left),
+            sample_to_f32(self.front_right),
+            sample_to_f32(self.center),
+            sample_to_f32(self.lfe),
+            sample_to_f32(self.surround_left),
+            sample_to_f32(self.surround_right),
+            sample_to_f32(self.rear_center),
+        ]
+    }
+}
+
+impl TryFrom<&[f64]> for Surround61 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 7 {
+            Err(SampleFormatError::WrongLength {
+                expected: 7,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround61 {
+                front_left: sample_from_f64(v[0]),
+                front_right: sample_from_f64(v[1]),
+                center: sample_from_f64(v[2]),
+                lfe: sample_from_f64(v[3]),
+                surround_left: sample_from_f64(v[4]),
+                surround_right: sample_from_f64(v[5]),
+                rear_center: sample_from_f64(v[6]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Surround61 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Surround61 {
+    fn into(self) -> Vec<f64> {
+        vec![
+            sample_to_f64(self.front_left),
+            sample_to_f64(self.front_right),
+            sample_to_f64(self.center),
+            sample_to_f64(self.lfe),
+            sample_to_f64(self.surround_left),
+            sample_to_f64(self.surround_right),
+            sample_to_f64(self.rear_center),
+        ]
+    }
+}
@@ -0,0 +1,882 @@
+//! # Surround 7.1
+//!
+//! Module containing type for handling 7.1 surround audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Surround71`] samples
+///
+/// [`Surround71`]: struct.Surround71.html
+pub type Surround71TrackT = Vec<Surround71>;
+
+/// Struct representing a 7.1 surround audio sample.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Surround71 {
+    /// Front left sample value.
+    pub front_left: Sample,
+    /// Front right sample value.
+    pub front_right: Sample,
+    /// Center sample value.
+    pub center: Sample,
+    /// Low-frequency effects sample value.
+    pub lfe: Sample,
+    /// Side left sample value.
+    pub side_left: Sample,
+    /// Side right sample value.
+    pub side_right: Sample,
+    /// Rear left sample value.
+    pub rear_left: Sample,
+    /// Rear right sample value.
+    pub rear_right: Sample,
+}
+
+impl Surround71 {
+    /// Returns a new Surround71 object with default <0,0,0,0,0,0,0,0> values.
+    pub const fn new() -> Self {
+        Surround71::SILENCE
+    }
+
+    /// Returns a new Surround71 object created from the individual audio
+    /// samples of each channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `fl` - the front left audio sample.
+    /// * `fr` - the front right audio sample.
+    /// * `c` - the center audio sample.
+    /// * `lfe` - the low-frequency effects audio sample.
+    /// * `sl` - the side left audio sample.
+    /// * `sr` - the side right audio sample.
+    /// * `rl` - the rear left audio sample.
+    /// * `rr` - the rear right audio sample.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn from(
+        fl: Sample,
+        fr: Sample,
+        c: Sample,
+        lfe: Sample,
+        sl: Sample,
+        sr: Sample,
+        rl: Sample,
+        rr: Sample,
+    ) -> Self {
+        Surround71 {
+            front_left: fl,
+            front_right: fr,
+            center: c,
+            lfe,
+            side_left: sl,
+            side_right: sr,
+            rear_left: rl,
+            rear_right: rr,
+        }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 8] {
+        let s: [Sample; 8] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Surround71 object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 8]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Pans the given sample to the given azimuth in degrees. This is the
+    /// implementation shared by the [`Panner`] impls.
+    ///
+    /// [`Panner`]: ../trait.Panner.html
+    fn pan(s: Sample, azimuth: AccurateMath) -> Self {
+        let mut gains = [0.0; 7];
+
+        panning::ring_gains(azimuth, &panning::SURROUND71_AZIMUTHS, &mut gains);
+
+        let g = |i: usize| Sample((s.0 as AccurateMath * gains[i]) as FastMath);
+
+        Surround71 {
+            front_left: g(0),
+            front_right: g(1),
+            center: g(2),
+            lfe: Sample(0.0),
+            side_left: g(3),
+            side_right: g(4),
+            rear_left: g(5),
+            rear_right: g(6),
+        }
+    }
+
+    /// Creates a Surround71 object from a frame of channels given in the given
+    /// channel order.
+    ///
+    /// # Parameters
+    ///
+    /// * `frame` - the channels of the frame, at least 8 long.
+    /// * `order` - the order of the channels within `frame`.
+    pub fn from_order(frame: &[Sample], order: ChannelOrder) -> Result<Self, SampleFormatError> {
+        if frame.len() < 8 {
+            return Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: frame.len(),
+            });
+        }
+
+        let mut c = [Sample(0.0); 8];
+        for (s, i) in frame.iter().zip(order.surround71_indices().iter()) {
+            c[*i] = *s;
+        }
+
+        Ok(Surround71 {
+            front_left: c[0],
+            front_right: c[1],
+            center: c[2],
+            lfe: c[3],
+            side_left: c[4],
+            side_right: c[5],
+            rear_left: c[6],
+            rear_right: c[7],
+        })
+    }
+
+    /// Returns the channels of the sample in the given channel order.
+    ///
+    /// # Parameters
+    ///
+    /// * `order` - the order to return the channels in.
+    pub fn to_order(self, order: ChannelOrder) -> [Sample; 8] {
+        let c = [
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.side_left,
+            self.side_right,
+            self.rear_left,
+            self.rear_right,
+        ];
+        let mut frame = [Sample(0.0); 8];
+        for (s, i) in frame.iter_mut().zip(order.surround71_indices().iter()) {
+            *s = c[*i];
+        }
+        frame
+    }
+}
+
+impl SampleFormat for Surround71 {
+    const CHANNEL_COUNT: usize = 8;
+
+    const SILENCE: Self = Surround71 {
+        front_left: Sample(0.0),
+        front_right: Sample(0.0),
+        center: Sample(0.0),
+        lfe: Sample(0.0),
+        side_left: Sample(0.0),
+        side_right: Sample(0.0),
+        rear_left: Sample(0.0),
+        rear_right: Sample(0.0),
+    };
+    const UNITY: Self = Surround71 {
+        front_left: Sample(1.0),
+        front_right: Sample(1.0),
+        center: Sample(1.0),
+        lfe: Sample(1.0),
+        side_left: Sample(1.0),
+        side_right: Sample(1.0),
+        rear_left: Sample(1.0),
+        rear_right: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        Surround71 {
+            center: x,
+            ..Default::default()
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        Sample(
+            self.center.0
+                + (self.front_left.0 + self.front_right.0) * FastMath::sqrt(0.5)
+                + (self.side_left.0 + self.side_right.0) * FastMath::sqrt(0.5)
+                + (self.rear_left.0 + self.rear_right.0) * FastMath::sqrt(0.5),
+        )
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[
+            ChannelLabel::FrontLeft,
+            ChannelLabel::FrontRight,
+            ChannelLabel::Center,
+            ChannelLabel::Lfe,
+            ChannelLabel::SideLeft,
+            ChannelLabel::SideRight,
+            ChannelLabel::RearLeft,
+            ChannelLabel::RearRight,
+        ]
+    }
+}
+
+/// Pans a given sample to the given position, using the same ear-level speaker
+/// angles as the [`Surround714`] panner. The sample is panned with constant
+/// power between the two speakers adjacent to the azimuth. As the layout has no
+/// height speakers the elevation is ignored, placing the sample at its
+/// projection onto the horizontal plane. The LFE channel receives no signal.
+///
+/// [`Surround714`]: ../surround714/struct.Surround714.html
+impl Panner<SphericalPosition> for Surround71 {
+    fn to_sample_format(s: Sample, g: SphericalPosition) -> Self {
+        Surround71::pan(s, g.azimuth)
+    }
+}
+
+/// Pans a given sample to the given position in the same way as the panner
+/// taking only a [`SphericalPosition`], additionally sending the sample to the
+/// LFE channel with the given [`LfeSend`].
+///
+/// [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+/// [`LfeSend`]: ../lfe_send/struct.LfeSend.html
+impl Panner<(SphericalPosition, LfeSend)> for Surround71 {
+    fn to_sample_format(s: Sample, g: (SphericalPosition, LfeSend)) -> Self {
+        let mut p = Surround71::to_sample_format(s, g.0);
+        p.lfe = g.1.apply(s);
+        p
+    }
+}
+
+/// Pans a given sample to the given position in the same way as the panner
+/// taking only a [`SphericalPosition`], diverting part of the center channel to
+/// a phantom center with the given [`CenterDivergence`].
+///
+/// [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+/// [`CenterDivergence`]: ../center_divergence/struct.CenterDivergence.html
+impl Panner<(SphericalPosition, CenterDivergence)> for Surround71 {
+    fn to_sample_format(s: Sample, g: (SphericalPosition, CenterDivergence)) -> Self {
+        let mut p = Surround71::to_sample_format(s, g.0);
+        g.1.apply(&mut p.front_left, &mut p.center, &mut p.front_right);
+        p
+    }
+}
+
+/// Pans a given sample to the position of the given [`SpatialSource`],
+/// attenuating it with its distance and spreading it across its width. A
+/// spread source is split into virtual sources across its arc, each panned in
+/// the same way as the panner taking only a [`SphericalPosition`], and the
+/// summed gains are normalized to constant power.
+///
+/// [`SpatialSource`]: ../spatial_source/struct.SpatialSource.html
+/// [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+impl Panner<SpatialSource> for Surround71 {
+    fn to_sample_format(s: Sample, g: SpatialSource) -> Self {
+        let gains: Surround71 = panning::spread_gains(g.position, g.spread);
+
+        gains * Sample((s.0 as AccurateMath * g.gain()) as FastMath)
+    }
+}
+
+impl From<Surround51> for Surround71 {
+    fn from(s: Surround51) -> Self {
+        s.convert_format()
+    }
+}
+
+impl Balancer for Surround71 {}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Surround71 {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.front_left,
+            1 => &self.front_right,
+            2 => &self.center,
+            3 => &self.lfe,
+            4 => &self.side_left,
+            5 => &self.side_right,
+            6 => &self.rear_left,
+            7 => &self.rear_right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround71 with 8 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Surround71 {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.front_left,
+            1 => &mut self.front_right,
+            2 => &mut self.center,
+            3 => &mut self.lfe,
+            4 => &mut self.side_left,
+            5 => &mut self.side_right,
+            6 => &mut self.rear_left,
+            7 => &mut self.rear_right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround71 with 8 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Surround71 {
+    fn as_ref(&self) -> &[Sample] {
+        // Surround71 is #[repr(C)] and made up solely of 8 Sample fields.
+        unsafe { channel::as_slice(self, 8) }
+    }
+}
+impl AsMut<[Sample]> for Surround71 {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Surround71 is #[repr(C)] and made up solely of 8 Sample fields.
+        unsafe { channel::as_mut_slice(self, 8) }
+    }
+}
+
+impl std::ops::Neg for Surround71 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Surround71 {
+            front_left: Sample(-self.front_left.0),
+            front_right: Sample(-self.front_right.0),
+            center: Sample(-self.center.0),
+            lfe: Sample(-self.lfe.0),
+            side_left: Sample(-self.side_left.0),
+            side_right: Sample(-self.side_right.0),
+            rear_left: Sample(-self.rear_left.0),
+            rear_right: Sample(-self.rear_right.0),
+        }
+    }
+}
+
+impl std::ops::Add<Surround71> for Surround71 {
+    type Output = Self;
+
+    fn add(self, rhs: Surround71) -> Self::Output {
+        Surround71 {
+            front_left: Sample(self.front_left.0 + rhs.front_left.0),
+            front_right: Sample(self.front_right.0 + rhs.front_right.0),
+            center: Sample(self.center.0 + rhs.center.0),
+            lfe: Sample(self.lfe.0 + rhs.lfe.0),
+            side_left: Sample(self.side_left.0 + rhs.side_left.0),
+            side_right: Sample(self.side_right.0 + rhs.side_right.0),
+            rear_left: Sample(self.rear_left.0 + rhs.rear_left.0),
+            rear_right: Sample(self.rear_right.0 + rhs.rear_right.0),
+        }
+    }
+}
+impl std::ops::AddAssign<Surround71> for Surround71 {
+    fn add_assign(&mut self, rhs: Surround71) {
+        self.front_left.0 += rhs.front_left.0;
+        self.front_right.0 += rhs.front_right.0;
+        self.center.0 += rhs.center.0;
+        self.lfe.0 += rhs.lfe.0;
+        self.side_left.0 += rhs.side_left.0;
+        self.side_right.0 += rhs.side_right.0;
+        self.rear_left.0 += rhs.rear_left.0;
+        self.rear_right.0 += rhs.rear_right.0;
+    }
+}
+
+impl std::ops::Sub<Surround71> for Surround71 {
+    type Output = Self;
+
+    fn sub(self, rhs: Surround71) -> Self {
+        Surround71 {
+            front_left: Sample(self.front_left.0 - rhs.front_left.0),
+            front_right: Sample(self.front_right.0 - rhs.front_right.0),
+            center: Sample(self.center.0 - rhs.center.0),
+            lfe: Sample(self.lfe.0 - rhs.lfe.0),
+            side_left: Sample(self.side_left.0 - rhs.side_left.0),
+            side_right: Sample(self.side_right.0 - rhs.side_right.0),
+            rear_left: Sample(self.rear_left.0 - rhs.rear_left.0),
+            rear_right: Sample(self.rear_right.0 - rhs.rear_right.0),
+        }
+    }
+}
+impl std::ops::SubAssign<Surround71> for Surround71 {
+    fn sub_assign(&mut self, rhs: Surround71) {
+        self.front_left.0 -= rhs.front_left.0;
+        self.front_right.0 -= rhs.front_right.0;
+        self.center.0 -= rhs.center.0;
+        self.lfe.0 -= rhs.lfe.0;
+        self.side_left.0 -= rhs.side_left.0;
+        self.side_right.0 -= rhs.side_right.0;
+        self.rear_left.0 -= rhs.rear_left.0;
+        self.rear_right.0 -= rhs.rear_right.0;
+    }
+}
+
+impl std::ops::Mul<Surround71> for Surround71 {
+    type Output = Surround71;
+
+    fn mul(self, rhs: Surround71) -> Self::Output {
+        Surround71 {
+            front_left: Sample(self.front_left.0 * rhs.front_left.0),
+            front_right: Sample(self.front_right.0 * rhs.front_right.0),
+            center: Sample(self.center.0 * rhs.center.0),
+            lfe: Sample(self.lfe.0 * rhs.lfe.0),
+            side_left: Sample(self.side_left.0 * rhs.side_left.0),
+            side_right: Sample(self.side_right.0 * rhs.side_right.0),
+            rear_left: Sample(self.rear_left.0 * rhs.rear_left.0),
+            rear_right: Sample(self.rear_right.0 * rhs.rear_right.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Surround71> for Surround71 {
+    fn mul_assign(&mut self, rhs: Surround71) {
+        self.front_left.0 *= rhs.front_left.0;
+        self.front_right.0 *= rhs.front_right.0;
+        self.center.0 *= rhs.center.0;
+        self.lfe.0 *= rhs.lfe.0;
+        self.side_left.0 *= rhs.side_left.0;
+        self.side_right.0 *= rhs.side_right.0;
+        self.rear_left.0 *= rhs.rear_left.0;
+        self.rear_right.0 *= rhs.rear_right.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for Surround71 {
+    type Output = Surround71;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        Surround71 {
+            front_left: Sample(self.front_left.0 * rhs.0),
+            front_right: Sample(self.front_right.0 * rhs.0),
+            center: Sample(self.center.0 * rhs.0),
+            lfe: Sample(self.lfe.0 * rhs.0),
+            side_left: Sample(self.side_left.0 * rhs.0),
+            side_right: Sample(self.side_right.0 * rhs.0),
+            rear_left: Sample(self.rear_left.0 * rhs.0),
+            rear_right: Sample(self.rear_right.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for Surround71 {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.front_left.0 *= rhs.0;
+        self.front_right.0 *= rhs.0;
+        self.center.0 *= rhs.0;
+        self.lfe.0 *= rhs.0;
+        self.side_left.0 *= rhs.0;
+        self.side_right.0 *= rhs.0;
+        self.rear_left.0 *= rhs.0;
+        self.rear_right.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for Surround71 {
+    type Output = Surround71;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        Surround71 {
+            front_left: Sample((self.front_left.0 as AccurateMath * rhs.0) as FastMath),
+            front_right: Sample((self.front_right.0 as AccurateMath * rhs.0) as FastMath),
+            center: Sample((self.center.0 as AccurateMath * rhs.0) as FastMath),
+            lfe: Sample((self.lfe.0 as AccurateMath * rhs.0) as FastMath),
+            side_left: Sample((self.side_left.0 as AccurateMath * rhs.0) as FastMath),
+            side_right: Sample((self.side_right.0 as AccurateMath * rhs.0) as FastMath),
+            rear_left: Sample((self.rear_left.0 as AccurateMath * rhs.0) as FastMath),
+            rear_right: Sample((self.rear_right.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for Surround71 {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.front_left.0 *= rhs.0 as FastMath;
+        self.front_right.0 *= rhs.0 as FastMath;
+        self.center.0 *= rhs.0 as FastMath;
+        self.lfe.0 *= rhs.0 as FastMath;
+        self.side_left.0 *= rhs.0 as FastMath;
+        self.side_right.0 *= rhs.0 as FastMath;
+        self.rear_left.0 *= rhs.0 as FastMath;
+        self.rear_right.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for Surround71 {
+    type Output = Surround71;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Surround71 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Surround71 {
+    type Output = Surround71;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Surround71 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 8]> for Surround71 {
+    type Output = Surround71;
+
+    fn mul(mut self, rhs: [Sample; 8]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 8]> for Surround71 {
+    fn mul_assign(&mut self, rhs: [Sample; 8]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Surround71 {
+    fn sum<I: Iterator<Item = Surround71>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Surround71> for Surround71 {
+    fn sum<I: Iterator<Item = &'a Surround71>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Surround71 {
+    fn product<I: Iterator<Item = Surround71>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Surround71> for Surround71 {
+    fn product<I: Iterator<Item = &'a Surround71>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for Surround71 {
+    fn from(s: Sample) -> Self {
+        Surround71::from_sample(s)
+    }
+}
+impl Into<Sample> for Surround71 {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 8]> for Surround71 {
+    fn from(a: [Sample; 8]) -> Self {
+        Surround71 {
+            front_left: a[0],
+            front_right: a[1],
+            center: a[2],
+            lfe: a[3],
+            side_left: a[4],
+            side_right: a[5],
+            rear_left: a[6],
+            rear_right: a[7],
+        }
+    }
+}
+impl Into<[Sample; 8]> for Surround71 {
+    fn into(self) -> [Sample; 8] {
+        [
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.side_left,
+            self.side_right,
+            self.rear_left,
+            self.rear_right,
+        ]
+    }
+}
+
+/// Tuple holding the channels of a [`Surround71`] in order.
+///
+/// [`Surround71`]: struct.Surround71.html
+type Channels = (
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+);
+
+impl From<Channels> for Surround71 {
+    fn from(t: Channels) -> Self {
+        Surround71 {
+            front_left: t.0,
+            front_right: t.1,
+            center: t.2,
+            lfe: t.3,
+            side_left: t.4,
+            side_right: t.5,
+            rear_left: t.6,
+            rear_right: t.7,
+        }
+    }
+}
+impl Into<Channels> for Surround71 {
+    fn into(self) -> Channels {
+        (
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.side_left,
+            self.side_right,
+            self.rear_left,
+            self.rear_right,
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for Surround71 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 8 {
+            Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround71 {
+                front_left: sample_from_u8(v[0]),
+                front_right: sample_from_u8(v[1]),
+                center: sample_from_u8(v[2]),
+                lfe: sample_from_u8(v[3]),
+                side_left: sample_from_u8(v[4]),
+                side_right: sample_from_u8(v[5]),
+                rear_left: sample_from_u8(v[6]),
+                rear_right: sample_from_u8(v[7]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for Surround71 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for Surround71 {
+    fn into(self) -> Vec<u8> {
+        vec![
+            sample_to_u8(self.front_left),
+            sample_to_u8(self.front_right),
+            sample_to_u8(self.center),
+            sample_to_u8(self.lfe),
+            sample_to_u8(self.side_left),
+            sample_to_u8(self.side_right),
+            sample_to_u8(self.rear_left),
+            sample_to_u8(self.rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[i16]> for Surround71 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 8 {
+            Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround71 {
+                front_left: sample_from_i16(v[0]),
+                front_right: sample_from_i16(v[1]),
+                center: sample_from_i16(v[2]),
+                lfe: sample_from_i16(v[3]),
+                side_left: sample_from_i16(v[4]),
+                side_right: sample_from_i16(v[5]),
+                rear_left: sample_from_i16(v[6]),
+                rear_right: sample_from_i16(v[7]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for Surround71 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for Surround71 {
+    fn into(self) -> Vec<i16> {
+        vec![
+            sample_to_i16(self.front_left),
+            sample_to_i16(self.front_right),
+            sample_to_i16(self.center),
+            sample_to_i16(self.lfe),
+            sample_to_i16(self.side_left),
+            sample_to_i16(self.side_right),
+            sample_to_i16(self.rear_left),
+            sample_to_i16(self.rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[i32]> for Surround71 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 8 {
+            Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround71 {
+                front_left: sample_from_i24(v[0]),
+                front_right: sample_from_i24(v[1]),
+                center: sample_from_i24(v[2]),
+                lfe: sample_from_i24(v[3]),
+                side_left: sample_from_i24(v[4]),
+                side_right: sample_from_i24(v[5]),
+                rear_left: sample_from_i24(v[6]),
+                rear_right: sample_from_i24(v[7]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for Surround71 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for Surround71 {
+    fn into(self) -> Vec<i32> {
+        vec![
+            sample_to_i24(self.front_left),
+            sample_to_i24(self.front_right),
+            sample_to_i24(self.center),
+            sample_to_i24(self.lfe),
+            sample_to_i24(self.side_left),
+            sample_to_i24(self.side_right),
+            sample_to_i24(self.rear_left),
+            sample_to_i24(self.rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[f32]> for Surround71 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 8 {
+            Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround71 {
+                front_left: sample_from_f32(v[0]),
+                front_right: sample_from_f32(v[1]),
+                center: sample_from_f32(v[2]),
+                lfe: sample_from_f32(v[3]),
+                side_left: sample_from_f32(v[4]),
+                side_right: sample_from_f32(v[5]),
+                rear_left: sample_from_f32(v[6]),
+                rear_right: sample_from_f32(v[7]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Surround71 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Surround71 {
+    fn into(self) -> Vec<f32> {
+        vec![
+            sample_to_f32(self.front_left),
+            sample_to_f32(self.front_right),
+            sample_to_f32(self.center),
+            sample_to_f32(self.lfe),
+            sample_to_f32(self.side_left),
+            sample_to_f32(self.side_right),
+            sample_to_f32(self.rear_left),
+            sample_to_f32(self.rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[f64]> for Surround71 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 8 {
+            Err(SampleFormatError::WrongLength {
+                expected: 8,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround71 {
+                front_left: sample_from_f64(v[0]),
+                front_right: sample_from_f64(v[1]),
+                center: sample_from_f64(v[2]),
+                lfe: sample_from_f64(v[3]),
+                side_left: sample_from_f64(v[4]),
+                side_right: sample_from_f64(v[5]),
+                rear_left: sample_from_f64(v[6]),
+                rear_right: sample_from_f64(v[7]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Surround71 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Surround71 {
+    fn into(self) -> Vec<f64> {
+        vec![
+            sample_to_f64(self.front_left),
+            sample_to_f64(self.front_right),
+            sample_to_f64(self.center),
+            sample_to_f64(self.lfe),
+            sample_to_f64(self.side_left),
+            sample_to_f64(self.side_right),
+            sample_to_f64(self.rear_left),
+            sample_to_f64(self.rear_right),
+        ]
+    }
+}
@@ -0,0 +1,1002 @@
+//! # Surround 7.1.4
+//!
+//! Module containing type for handling 7.1.4 immersive audio data.
+
+use super::*;
+use bae_utils::*;
+
+/// Type for a track of [`Surround714`] samples
+///
+/// [`Surround714`]: struct.Surround714.html
+pub type Surround714TrackT = Vec<Surround714>;
+
+/// Struct representing a 7.1.4 immersive audio sample, that being a 7.1 sample
+/// with an added layer of four height channels.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Surround714 {
+    /// Front left sample value.
+    pub front_left: Sample,
+    /// Front right sample value.
+    pub front_right: Sample,
+    /// Center sample value.
+    pub center: Sample,
+    /// Low-frequency effects sample value.
+    pub lfe: Sample,
+    /// Side left sample value.
+    pub side_left: Sample,
+    /// Side right sample value.
+    pub side_right: Sample,
+    /// Rear left sample value.
+    pub rear_left: Sample,
+    /// Rear right sample value.
+    pub rear_right: Sample,
+    /// Top front left sample value.
+    pub top_front_left: Sample,
+    /// Top front right sample value.
+    pub top_front_right: Sample,
+    /// Top rear left sample value.
+    pub top_rear_left: Sample,
+    /// Top rear right sample value.
+    pub top_rear_right: Sample,
+}
+
+impl Surround714 {
+    /// Returns a new Surround714 object with all channels set to 0.
+    pub const fn new() -> Self {
+        Surround714::SILENCE
+    }
+
+    /// Returns a new Surround714 object created from the individual audio
+    /// samples of each channel.
+    ///
+    /// # Parameters
+    ///
+    /// * `fl` - the front left audio sample.
+    /// * `fr` - the front right audio sample.
+    /// * `c` - the center audio sample.
+    /// * `lfe` - the low-frequency effects audio sample.
+    /// * `sl` - the side left audio sample.
+    /// * `sr` - the side right audio sample.
+    /// * `rl` - the rear left audio sample.
+    /// * `rr` - the rear right audio sample.
+    /// * `tfl` - the top front left audio sample.
+    /// * `tfr` - the top front right audio sample.
+    /// * `trl` - the top rear left audio sample.
+    /// * `trr` - the top rear right audio sample.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn from(
+        fl: Sample,
+        fr: Sample,
+        c: Sample,
+        lfe: Sample,
+        sl: Sample,
+        sr: Sample,
+        rl: Sample,
+        rr: Sample,
+        tfl: Sample,
+        tfr: Sample,
+        trl: Sample,
+        trr: Sample,
+    ) -> Self {
+        Surround714 {
+            front_left: fl,
+            front_right: fr,
+            center: c,
+            lfe,
+            side_left: sl,
+            side_right: sr,
+            rear_left: rl,
+            rear_right: rr,
+            top_front_left: tfl,
+            top_front_right: tfr,
+            top_rear_left: trl,
+            top_rear_right: trr,
+        }
+    }
+
+    /// Returns the level of each channel in dBFS, in channel order. Silent
+    /// channels return negative infinity.
+    pub fn to_dbfs(self) -> [Math; 12] {
+        let s: [Sample; 12] = self.into();
+        s.map(sample_to_dbfs)
+    }
+
+    /// Returns a new Surround714 object with each channel set to the positive
+    /// linear value of the given level in dBFS.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the level of each channel in dBFS, in channel order.
+    pub fn from_dbfs(db: [Math; 12]) -> Self {
+        db.map(sample_from_dbfs).into()
+    }
+
+    /// Pans the given sample to the given azimuth and elevation, both given in
+    /// degrees. This is the implementation shared by the [`Panner`] impls.
+    ///
+    /// [`Panner`]: ../trait.Panner.html
+    fn pan(s: Sample, azimuth: AccurateMath, elevation: AccurateMath) -> Self {
+        let mut bed = [0.0; 7];
+        let mut top = [0.0; 4];
+        let (bed_gain, top_gain) =
+            panning::layer_gains(elevation, panning::SURROUND714_TOP_ELEVATION);
+
+        panning::ring_gains(azimuth, &panning::SURROUND71_AZIMUTHS, &mut bed);
+        panning::ring_gains(azimuth, &panning::SURROUND714_TOP_AZIMUTHS, &mut top);
+
+        let b = |i: usize| Sample((s.0 as AccurateMath * bed[i] * bed_gain) as FastMath);
+        let t = |i: usize| Sample((s.0 as AccurateMath * top[i] * top_gain) as FastMath);
+
+        Surround714 {
+            front_left: b(0),
+            front_right: b(1),
+            center: b(2),
+            lfe: Sample(0.0),
+            side_left: b(3),
+            side_right: b(4),
+            rear_left: b(5),
+            rear_right: b(6),
+            top_front_left: t(0),
+            top_front_right: t(1),
+            top_rear_left: t(2),
+            top_rear_right: t(3),
+        }
+    }
+}
+
+impl SampleFormat for Surround714 {
+    const CHANNEL_COUNT: usize = 12;
+
+    const SILENCE: Self = Surround714 {
+        front_left: Sample(0.0),
+        front_right: Sample(0.0),
+        center: Sample(0.0),
+        lfe: Sample(0.0),
+        side_left: Sample(0.0),
+        side_right: Sample(0.0),
+        rear_left: Sample(0.0),
+        rear_right: Sample(0.0),
+        top_front_left: Sample(0.0),
+        top_front_right: Sample(0.0),
+        top_rear_left: Sample(0.0),
+        top_rear_right: Sample(0.0),
+    };
+    const UNITY: Self = Surround714 {
+        front_left: Sample(1.0),
+        front_right: Sample(1.0),
+        center: Sample(1.0),
+        lfe: Sample(1.0),
+        side_left: Sample(1.0),
+        side_right: Sample(1.0),
+        rear_left: Sample(1.0),
+        rear_right: Sample(1.0),
+        top_front_left: Sample(1.0),
+        top_front_right: Sample(1.0),
+        top_rear_left: Sample(1.0),
+        top_rear_right: Sample(1.0),
+    };
+
+    fn from_sample(x: Sample) -> Self {
+        Surround714 {
+            center: x,
+            ..Default::default()
+        }
+    }
+
+    fn into_sample(self) -> Sample {
+        Sample(
+            self.center.0
+                + (self.front_left.0 + self.front_right.0) * FastMath::sqrt(0.5)
+                + (self.side_left.0 + self.side_right.0) * FastMath::sqrt(0.5)
+                + (self.rear_left.0 + self.rear_right.0) * FastMath::sqrt(0.5)
+                + (self.top_front_left.0 + self.top_front_right.0) * FastMath::sqrt(0.5)
+                + (self.top_rear_left.0 + self.top_rear_right.0) * FastMath::sqrt(0.5),
+        )
+    }
+
+    fn channel_labels() -> &'static [ChannelLabel] {
+        &[
+            ChannelLabel::FrontLeft,
+            ChannelLabel::FrontRight,
+            ChannelLabel::Center,
+            ChannelLabel::Lfe,
+            ChannelLabel::SideLeft,
+            ChannelLabel::SideRight,
+            ChannelLabel::RearLeft,
+            ChannelLabel::RearRight,
+            ChannelLabel::TopFrontLeft,
+            ChannelLabel::TopFrontRight,
+            ChannelLabel::TopRearLeft,
+            ChannelLabel::TopRearRight,
+        ]
+    }
+}
+
+/// Pans a given sample to a position given by the tuple `(azimuth, elevation)`
+/// in degrees. The azimuth is measured clockwise from the front, such that -90
+/// is directly left and 90 is directly right. The elevation is measured upwards
+/// from the horizontal plane.
+///
+/// The sample is panned with constant power between the two adjacent speakers
+/// of both the ear-level bed and the height layer, and the elevation then
+/// distributes the energy between the two layers with constant power, where an
+/// elevation of 0 or below is fully within the bed and an elevation of 45 or
+/// above is fully within the height layer. The LFE channel receives no signal.
+impl Panner<(f32, f32)> for Surround714 {
+    fn to_sample_format(s: Sample, g: (f32, f32)) -> Self {
+        Surround714::pan(s, g.0 as AccurateMath, g.1 as AccurateMath)
+    }
+}
+impl Panner<(f64, f64)> for Surround714 {
+    fn to_sample_format(s: Sample, g: (f64, f64)) -> Self {
+        Surround714::pan(s, g.0 as AccurateMath, g.1 as AccurateMath)
+    }
+}
+impl Panner<SphericalPosition> for Surround714 {
+    fn to_sample_format(s: Sample, g: SphericalPosition) -> Self {
+        Surround714::pan(s, g.azimuth, g.elevation)
+    }
+}
+
+/// Pans a given sample to the given position in the same way as the panner
+/// taking only a [`SphericalPosition`], additionally sending the sample to the
+/// LFE channel with the given [`LfeSend`].
+///
+/// [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+/// [`LfeSend`]: ../lfe_send/struct.LfeSend.html
+impl Panner<(SphericalPosition, LfeSend)> for Surround714 {
+    fn to_sample_format(s: Sample, g: (SphericalPosition, LfeSend)) -> Self {
+        let mut p = Surround714::to_sample_format(s, g.0);
+        p.lfe = g.1.apply(s);
+        p
+    }
+}
+
+/// Pans a given sample to the given position in the same way as the panner
+/// taking only a [`SphericalPosition`], diverting part of the center channel to
+/// a phantom center with the given [`CenterDivergence`].
+///
+/// [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+/// [`CenterDivergence`]: ../center_divergence/struct.CenterDivergence.html
+impl Panner<(SphericalPosition, CenterDivergence)> for Surround714 {
+    fn to_sample_format(s: Sample, g: (SphericalPosition, CenterDivergence)) -> Self {
+        let mut p = Surround714::to_sample_format(s, g.0);
+        g.1.apply(&mut p.front_left, &mut p.center, &mut p.front_right);
+        p
+    }
+}
+
+/// Pans a given sample to the position of the given [`SpatialSource`],
+/// attenuating it with its distance and spreading it across its width. A
+/// spread source is split into virtual sources across its arc, each panned in
+/// the same way as the panner taking only a [`SphericalPosition`], and the
+/// summed gains are normalized to constant power.
+///
+/// [`SpatialSource`]: ../spatial_source/struct.SpatialSource.html
+/// [`SphericalPosition`]: ../spherical_position/struct.SphericalPosition.html
+impl Panner<SpatialSource> for Surround714 {
+    fn to_sample_format(s: Sample, g: SpatialSource) -> Self {
+        let gains: Surround714 = panning::spread_gains(g.position, g.spread);
+
+        gains * Sample((s.0 as AccurateMath * g.gain()) as FastMath)
+    }
+}
+
+impl From<Surround51> for Surround714 {
+    fn from(s: Surround51) -> Self {
+        s.convert_format()
+    }
+}
+
+impl From<Surround71> for Surround714 {
+    fn from(s: Surround71) -> Self {
+        s.convert_format()
+    }
+}
+
+impl Balancer for Surround714 {}
+
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::Index<usize> for Surround714 {
+    type Output = Sample;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match i {
+            0 => &self.front_left,
+            1 => &self.front_right,
+            2 => &self.center,
+            3 => &self.lfe,
+            4 => &self.side_left,
+            5 => &self.side_right,
+            6 => &self.rear_left,
+            7 => &self.rear_right,
+            8 => &self.top_front_left,
+            9 => &self.top_front_right,
+            10 => &self.top_rear_left,
+            11 => &self.top_rear_right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround714 with 12 channels.",
+                i
+            ),
+        }
+    }
+}
+/// # Panics
+///
+/// Panics if the index is not less than the number of channels.
+impl std::ops::IndexMut<usize> for Surround714 {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match i {
+            0 => &mut self.front_left,
+            1 => &mut self.front_right,
+            2 => &mut self.center,
+            3 => &mut self.lfe,
+            4 => &mut self.side_left,
+            5 => &mut self.side_right,
+            6 => &mut self.rear_left,
+            7 => &mut self.rear_right,
+            8 => &mut self.top_front_left,
+            9 => &mut self.top_front_right,
+            10 => &mut self.top_rear_left,
+            11 => &mut self.top_rear_right,
+            _ => panic!(
+                "ERROR: Index {} is out of range for Surround714 with 12 channels.",
+                i
+            ),
+        }
+    }
+}
+
+impl AsRef<[Sample]> for Surround714 {
+    fn as_ref(&self) -> &[Sample] {
+        // Surround714 is #[repr(C)] and made up solely of 12 Sample fields.
+        unsafe { channel::as_slice(self, 12) }
+    }
+}
+impl AsMut<[Sample]> for Surround714 {
+    fn as_mut(&mut self) -> &mut [Sample] {
+        // Surround714 is #[repr(C)] and made up solely of 12 Sample fields.
+        unsafe { channel::as_mut_slice(self, 12) }
+    }
+}
+
+impl std::ops::Neg for Surround714 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Surround714 {
+            front_left: Sample(-self.front_left.0),
+            front_right: Sample(-self.front_right.0),
+            center: Sample(-self.center.0),
+            lfe: Sample(-self.lfe.0),
+            side_left: Sample(-self.side_left.0),
+            side_right: Sample(-self.side_right.0),
+            rear_left: Sample(-self.rear_left.0),
+            rear_right: Sample(-self.rear_right.0),
+            top_front_left: Sample(-self.top_front_left.0),
+            top_front_right: Sample(-self.top_front_right.0),
+            top_rear_left: Sample(-self.top_rear_left.0),
+            top_rear_right: Sample(-self.top_rear_right.0),
+        }
+    }
+}
+
+impl std::ops::Add<Surround714> for Surround714 {
+    type Output = Self;
+
+    fn add(self, rhs: Surround714) -> Self::Output {
+        Surround714 {
+            front_left: Sample(self.front_left.0 + rhs.front_left.0),
+            front_right: Sample(self.front_right.0 + rhs.front_right.0),
+            center: Sample(self.center.0 + rhs.center.0),
+            lfe: Sample(self.lfe.0 + rhs.lfe.0),
+            side_left: Sample(self.side_left.0 + rhs.side_left.0),
+            side_right: Sample(self.side_right.0 + rhs.side_right.0),
+            rear_left: Sample(self.rear_left.0 + rhs.rear_left.0),
+            rear_right: Sample(self.rear_right.0 + rhs.rear_right.0),
+            top_front_left: Sample(self.top_front_left.0 + rhs.top_front_left.0),
+            top_front_right: Sample(self.top_front_right.0 + rhs.top_front_right.0),
+            top_rear_left: Sample(self.top_rear_left.0 + rhs.top_rear_left.0),
+            top_rear_right: Sample(self.top_rear_right.0 + rhs.top_rear_right.0),
+        }
+    }
+}
+impl std::ops::AddAssign<Surround714> for Surround714 {
+    fn add_assign(&mut self, rhs: Surround714) {
+        self.front_left.0 += rhs.front_left.0;
+        self.front_right.0 += rhs.front_right.0;
+        self.center.0 += rhs.center.0;
+        self.lfe.0 += rhs.lfe.0;
+        self.side_left.0 += rhs.side_left.0;
+        self.side_right.0 += rhs.side_right.0;
+        self.rear_left.0 += rhs.rear_left.0;
+        self.rear_right.0 += rhs.rear_right.0;
+        self.top_front_left.0 += rhs.top_front_left.0;
+        self.top_front_right.0 += rhs.top_front_right.0;
+        self.top_rear_left.0 += rhs.top_rear_left.0;
+        self.top_rear_right.0 += rhs.top_rear_right.0;
+    }
+}
+
+impl std::ops::Sub<Surround714> for Surround714 {
+    type Output = Self;
+
+    fn sub(self, rhs: Surround714) -> Self {
+        Surround714 {
+            front_left: Sample(self.front_left.0 - rhs.front_left.0),
+            front_right: Sample(self.front_right.0 - rhs.front_right.0),
+            center: Sample(self.center.0 - rhs.center.0),
+            lfe: Sample(self.lfe.0 - rhs.lfe.0),
+            side_left: Sample(self.side_left.0 - rhs.side_left.0),
+            side_right: Sample(self.side_right.0 - rhs.side_right.0),
+            rear_left: Sample(self.rear_left.0 - rhs.rear_left.0),
+            rear_right: Sample(self.rear_right.0 - rhs.rear_right.0),
+            top_front_left: Sample(self.top_front_left.0 - rhs.top_front_left.0),
+            top_front_right: Sample(self.top_front_right.0 - rhs.top_front_right.0),
+            top_rear_left: Sample(self.top_rear_left.0 - rhs.top_rear_left.0),
+            top_rear_right: Sample(self.top_rear_right.0 - rhs.top_rear_right.0),
+        }
+    }
+}
+impl std::ops::SubAssign<Surround714> for Surround714 {
+    fn sub_assign(&mut self, rhs: Surround714) {
+        self.front_left.0 -= rhs.front_left.0;
+        self.front_right.0 -= rhs.front_right.0;
+        self.center.0 -= rhs.center.0;
+        self.lfe.0 -= rhs.lfe.0;
+        self.side_left.0 -= rhs.side_left.0;
+        self.side_right.0 -= rhs.side_right.0;
+        self.rear_left.0 -= rhs.rear_left.0;
+        self.rear_right.0 -= rhs.rear_right.0;
+        self.top_front_left.0 -= rhs.top_front_left.0;
+        self.top_front_right.0 -= rhs.top_front_right.0;
+        self.top_rear_left.0 -= rhs.top_rear_left.0;
+        self.top_rear_right.0 -= rhs.top_rear_right.0;
+    }
+}
+
+impl std::ops::Mul<Surround714> for Surround714 {
+    type Output = Surround714;
+
+    fn mul(self, rhs: Surround714) -> Self::Output {
+        Surround714 {
+            front_left: Sample(self.front_left.0 * rhs.front_left.0),
+            front_right: Sample(self.front_right.0 * rhs.front_right.0),
+            center: Sample(self.center.0 * rhs.center.0),
+            lfe: Sample(self.lfe.0 * rhs.lfe.0),
+            side_left: Sample(self.side_left.0 * rhs.side_left.0),
+            side_right: Sample(self.side_right.0 * rhs.side_right.0),
+            rear_left: Sample(self.rear_left.0 * rhs.rear_left.0),
+            rear_right: Sample(self.rear_right.0 * rhs.rear_right.0),
+            top_front_left: Sample(self.top_front_left.0 * rhs.top_front_left.0),
+            top_front_right: Sample(self.top_front_right.0 * rhs.top_front_right.0),
+            top_rear_left: Sample(self.top_rear_left.0 * rhs.top_rear_left.0),
+            top_rear_right: Sample(self.top_rear_right.0 * rhs.top_rear_right.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Surround714> for Surround714 {
+    fn mul_assign(&mut self, rhs: Surround714) {
+        self.front_left.0 *= rhs.front_left.0;
+        self.front_right.0 *= rhs.front_right.0;
+        self.center.0 *= rhs.center.0;
+        self.lfe.0 *= rhs.lfe.0;
+        self.side_left.0 *= rhs.side_left.0;
+        self.side_right.0 *= rhs.side_right.0;
+        self.rear_left.0 *= rhs.rear_left.0;
+        self.rear_right.0 *= rhs.rear_right.0;
+        self.top_front_left.0 *= rhs.top_front_left.0;
+        self.top_front_right.0 *= rhs.top_front_right.0;
+        self.top_rear_left.0 *= rhs.top_rear_left.0;
+        self.top_rear_right.0 *= rhs.top_rear_right.0;
+    }
+}
+
+impl std::ops::Mul<Sample> for Surround714 {
+    type Output = Surround714;
+
+    fn mul(self, rhs: Sample) -> Self::Output {
+        Surround714 {
+            front_left: Sample(self.front_left.0 * rhs.0),
+            front_right: Sample(self.front_right.0 * rhs.0),
+            center: Sample(self.center.0 * rhs.0),
+            lfe: Sample(self.lfe.0 * rhs.0),
+            side_left: Sample(self.side_left.0 * rhs.0),
+            side_right: Sample(self.side_right.0 * rhs.0),
+            rear_left: Sample(self.rear_left.0 * rhs.0),
+            rear_right: Sample(self.rear_right.0 * rhs.0),
+            top_front_left: Sample(self.top_front_left.0 * rhs.0),
+            top_front_right: Sample(self.top_front_right.0 * rhs.0),
+            top_rear_left: Sample(self.top_rear_left.0 * rhs.0),
+            top_rear_right: Sample(self.top_rear_right.0 * rhs.0),
+        }
+    }
+}
+impl std::ops::MulAssign<Sample> for Surround714 {
+    fn mul_assign(&mut self, rhs: Sample) {
+        self.front_left.0 *= rhs.0;
+        self.front_right.0 *= rhs.0;
+        self.center.0 *= rhs.0;
+        self.lfe.0 *= rhs.0;
+        self.side_left.0 *= rhs.0;
+        self.side_right.0 *= rhs.0;
+        self.rear_left.0 *= rhs.0;
+        self.rear_right.0 *= rhs.0;
+        self.top_front_left.0 *= rhs.0;
+        self.top_front_right.0 *= rhs.0;
+        self.top_rear_left.0 *= rhs.0;
+        self.top_rear_right.0 *= rhs.0;
+    }
+}
+
+impl std::ops::Mul<Math> for Surround714 {
+    type Output = Surround714;
+
+    fn mul(self, rhs: Math) -> Self::Output {
+        Surround714 {
+            front_left: Sample((self.front_left.0 as AccurateMath * rhs.0) as FastMath),
+            front_right: Sample((self.front_right.0 as AccurateMath * rhs.0) as FastMath),
+            center: Sample((self.center.0 as AccurateMath * rhs.0) as FastMath),
+            lfe: Sample((self.lfe.0 as AccurateMath * rhs.0) as FastMath),
+            side_left: Sample((self.side_left.0 as AccurateMath * rhs.0) as FastMath),
+            side_right: Sample((self.side_right.0 as AccurateMath * rhs.0) as FastMath),
+            rear_left: Sample((self.rear_left.0 as AccurateMath * rhs.0) as FastMath),
+            rear_right: Sample((self.rear_right.0 as AccurateMath * rhs.0) as FastMath),
+            top_front_left: Sample((self.top_front_left.0 as AccurateMath * rhs.0) as FastMath),
+            top_front_right: Sample((self.top_front_right.0 as AccurateMath * rhs.0) as FastMath),
+            top_rear_left: Sample((self.top_rear_left.0 as AccurateMath * rhs.0) as FastMath),
+            top_rear_right: Sample((self.top_rear_right.0 as AccurateMath * rhs.0) as FastMath),
+        }
+    }
+}
+impl std::ops::MulAssign<Math> for Surround714 {
+    fn mul_assign(&mut self, rhs: Math) {
+        self.front_left.0 *= rhs.0 as FastMath;
+        self.front_right.0 *= rhs.0 as FastMath;
+        self.center.0 *= rhs.0 as FastMath;
+        self.lfe.0 *= rhs.0 as FastMath;
+        self.side_left.0 *= rhs.0 as FastMath;
+        self.side_right.0 *= rhs.0 as FastMath;
+        self.rear_left.0 *= rhs.0 as FastMath;
+        self.rear_right.0 *= rhs.0 as FastMath;
+        self.top_front_left.0 *= rhs.0 as FastMath;
+        self.top_front_right.0 *= rhs.0 as FastMath;
+        self.top_rear_left.0 *= rhs.0 as FastMath;
+        self.top_rear_right.0 *= rhs.0 as FastMath;
+    }
+}
+
+impl std::ops::Mul<f32> for Surround714 {
+    type Output = Surround714;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * sample_from_f32(rhs)
+    }
+}
+impl std::ops::MulAssign<f32> for Surround714 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self *= sample_from_f32(rhs);
+    }
+}
+
+impl std::ops::Mul<f64> for Surround714 {
+    type Output = Surround714;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Math(rhs as AccurateMath)
+    }
+}
+impl std::ops::MulAssign<f64> for Surround714 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self *= Math(rhs as AccurateMath);
+    }
+}
+
+impl std::ops::Mul<[Sample; 12]> for Surround714 {
+    type Output = Surround714;
+
+    fn mul(mut self, rhs: [Sample; 12]) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl std::ops::MulAssign<[Sample; 12]> for Surround714 {
+    fn mul_assign(&mut self, rhs: [Sample; 12]) {
+        for (c, g) in self.as_mut().iter_mut().zip(rhs.iter()) {
+            c.0 *= g.0;
+        }
+    }
+}
+
+impl std::iter::Sum for Surround714 {
+    fn sum<I: Iterator<Item = Surround714>>(iter: I) -> Self {
+        fold::sum(iter)
+    }
+}
+impl<'a> std::iter::Sum<&'a Surround714> for Surround714 {
+    fn sum<I: Iterator<Item = &'a Surround714>>(iter: I) -> Self {
+        fold::sum(iter.copied())
+    }
+}
+
+impl std::iter::Product for Surround714 {
+    fn product<I: Iterator<Item = Surround714>>(iter: I) -> Self {
+        fold::product(iter)
+    }
+}
+impl<'a> std::iter::Product<&'a Surround714> for Surround714 {
+    fn product<I: Iterator<Item = &'a Surround714>>(iter: I) -> Self {
+        fold::product(iter.copied())
+    }
+}
+
+impl From<Sample> for Surround714 {
+    fn from(s: Sample) -> Self {
+        Surround714::from_sample(s)
+    }
+}
+impl Into<Sample> for Surround714 {
+    fn into(self) -> Sample {
+        self.into_sample()
+    }
+}
+
+impl From<[Sample; 12]> for Surround714 {
+    fn from(a: [Sample; 12]) -> Self {
+        Surround714 {
+            front_left: a[0],
+            front_right: a[1],
+            center: a[2],
+            lfe: a[3],
+            side_left: a[4],
+            side_right: a[5],
+            rear_left: a[6],
+            rear_right: a[7],
+            top_front_left: a[8],
+            top_front_right: a[9],
+            top_rear_left: a[10],
+            top_rear_right: a[11],
+        }
+    }
+}
+impl Into<[Sample; 12]> for Surround714 {
+    fn into(self) -> [Sample; 12] {
+        [
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.side_left,
+            self.side_right,
+            self.rear_left,
+            self.rear_right,
+            self.top_front_left,
+            self.top_front_right,
+            self.top_rear_left,
+            self.top_rear_right,
+        ]
+    }
+}
+
+/// Tuple holding the channels of a [`Surround714`] in order.
+///
+/// [`Surround714`]: struct.Surround714.html
+type Channels = (
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+    Sample,
+);
+
+impl From<Channels> for Surround714 {
+    fn from(t: Channels) -> Self {
+        Surround714 {
+            front_left: t.0,
+            front_right: t.1,
+            center: t.2,
+            lfe: t.3,
+            side_left: t.4,
+            side_right: t.5,
+            rear_left: t.6,
+            rear_right: t.7,
+            top_front_left: t.8,
+            top_front_right: t.9,
+            top_rear_left: t.10,
+            top_rear_right: t.11,
+        }
+    }
+}
+impl Into<Channels> for Surround714 {
+    fn into(self) -> Channels {
+        (
+            self.front_left,
+            self.front_right,
+            self.center,
+            self.lfe,
+            self.side_left,
+            self.side_right,
+            self.rear_left,
+            self.rear_right,
+            self.top_front_left,
+            self.top_front_right,
+            self.top_rear_left,
+            self.top_rear_right,
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for Surround714 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        if v.len() < 12 {
+            Err(SampleFormatError::WrongLength {
+                expected: 12,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround714 {
+                front_left: sample_from_u8(v[0]),
+                front_right: sample_from_u8(v[1]),
+                center: sample_from_u8(v[2]),
+                lfe: sample_from_u8(v[3]),
+                side_left: sample_from_u8(v[4]),
+                side_right: sample_from_u8(v[5]),
+                rear_left: sample_from_u8(v[6]),
+                rear_right: sample_from_u8(v[7]),
+                top_front_left: sample_from_u8(v[8]),
+                top_front_right: sample_from_u8(v[9]),
+                top_rear_left: sample_from_u8(v[10]),
+                top_rear_right: sample_from_u8(v[11]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<u8>> for Surround714 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<u8>> for Surround714 {
+    fn into(self) -> Vec<u8> {
+        vec![
+            sample_to_u8(self.front_left),
+            sample_to_u8(self.front_right),
+            sample_to_u8(self.center),
+            sample_to_u8(self.lfe),
+            sample_to_u8(self.side_left),
+            sample_to_u8(self.side_right),
+            sample_to_u8(self.rear_left),
+            sample_to_u8(self.rear_right),
+            sample_to_u8(self.top_front_left),
+            sample_to_u8(self.top_front_right),
+            sample_to_u8(self.top_rear_left),
+            sample_to_u8(self.top_rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[i16]> for Surround714 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i16]) -> Result<Self, Self::Error> {
+        if v.len() < 12 {
+            Err(SampleFormatError::WrongLength {
+                expected: 12,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround714 {
+                front_left: sample_from_i16(v[0]),
+                front_right: sample_from_i16(v[1]),
+                center: sample_from_i16(v[2]),
+                lfe: sample_from_i16(v[3]),
+                side_left: sample_from_i16(v[4]),
+                side_right: sample_from_i16(v[5]),
+                rear_left: sample_from_i16(v[6]),
+                rear_right: sample_from_i16(v[7]),
+                top_front_left: sample_from_i16(v[8]),
+                top_front_right: sample_from_i16(v[9]),
+                top_rear_left: sample_from_i16(v[10]),
+                top_rear_right: sample_from_i16(v[11]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i16>> for Surround714 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i16>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i16>> for Surround714 {
+    fn into(self) -> Vec<i16> {
+        vec![
+            sample_to_i16(self.front_left),
+            sample_to_i16(self.front_right),
+            sample_to_i16(self.center),
+            sample_to_i16(self.lfe),
+            sample_to_i16(self.side_left),
+            sample_to_i16(self.side_right),
+            sample_to_i16(self.rear_left),
+            sample_to_i16(self.rear_right),
+            sample_to_i16(self.top_front_left),
+            sample_to_i16(self.top_front_right),
+            sample_to_i16(self.top_rear_left),
+            sample_to_i16(self.top_rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[i32]> for Surround714 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[i32]) -> Result<Self, Self::Error> {
+        if v.len() < 12 {
+            Err(SampleFormatError::WrongLength {
+                expected: 12,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround714 {
+                front_left: sample_from_i24(v[0]),
+                front_right: sample_from_i24(v[1]),
+                center: sample_from_i24(v[2]),
+                lfe: sample_from_i24(v[3]),
+                side_left: sample_from_i24(v[4]),
+                side_right: sample_from_i24(v[5]),
+                rear_left: sample_from_i24(v[6]),
+                rear_right: sample_from_i24(v[7]),
+                top_front_left: sample_from_i24(v[8]),
+                top_front_right: sample_from_i24(v[9]),
+                top_rear_left: sample_from_i24(v[10]),
+                top_rear_right: sample_from_i24(v[11]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<i32>> for Surround714 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<i32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<i32>> for Surround714 {
+    fn into(self) -> Vec<i32> {
+        vec![
+            sample_to_i24(self.front_left),
+            sample_to_i24(self.front_right),
+            sample_to_i24(self.center),
+            sample_to_i24(self.lfe),
+            sample_to_i24(self.side_left),
+            sample_to_i24(self.side_right),
+            sample_to_i24(self.rear_left),
+            sample_to_i24(self.rear_right),
+            sample_to_i24(self.top_front_left),
+            sample_to_i24(self.top_front_right),
+            sample_to_i24(self.top_rear_left),
+            sample_to_i24(self.top_rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[f32]> for Surround714 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f32]) -> Result<Self, Self::Error> {
+        if v.len() < 12 {
+            Err(SampleFormatError::WrongLength {
+                expected: 12,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround714 {
+                front_left: sample_from_f32(v[0]),
+                front_right: sample_from_f32(v[1]),
+                center: sample_from_f32(v[2]),
+                lfe: sample_from_f32(v[3]),
+                side_left: sample_from_f32(v[4]),
+                side_right: sample_from_f32(v[5]),
+                rear_left: sample_from_f32(v[6]),
+                rear_right: sample_from_f32(v[7]),
+                top_front_left: sample_from_f32(v[8]),
+                top_front_right: sample_from_f32(v[9]),
+                top_rear_left: sample_from_f32(v[10]),
+                top_rear_right: sample_from_f32(v[11]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f32>> for Surround714 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f32>> for Surround714 {
+    fn into(self) -> Vec<f32> {
+        vec![
+            sample_to_f32(self.front_left),
+            sample_to_f32(self.front_right),
+            sample_to_f32(self.center),
+            sample_to_f32(self.lfe),
+            sample_to_f32(self.side_left),
+            sample_to_f32(self.side_right),
+            sample_to_f32(self.rear_left),
+            sample_to_f32(self.rear_right),
+            sample_to_f32(self.top_front_left),
+            sample_to_f32(self.top_front_right),
+            sample_to_f32(self.top_rear_left),
+            sample_to_f32(self.top_rear_right),
+        ]
+    }
+}
+
+impl TryFrom<&[f64]> for Surround714 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: &[f64]) -> Result<Self, Self::Error> {
+        if v.len() < 12 {
+            Err(SampleFormatError::WrongLength {
+                expected: 12,
+                actual: v.len(),
+            })
+        } else {
+            Ok(Surround714 {
+                front_left: sample_from_f64(v[0]),
+                front_right: sample_from_f64(v[1]),
+                center: sample_from_f64(v[2]),
+                lfe: sample_from_f64(v[3]),
+                side_left: sample_from_f64(v[4]),
+                side_right: sample_from_f64(v[5]),
+                rear_left: sample_from_f64(v[6]),
+                rear_right: sample_from_f64(v[7]),
+                top_front_left: sample_from_f64(v[8]),
+                top_front_right: sample_from_f64(v[9]),
+                top_rear_left: sample_from_f64(v[10]),
+                top_rear_right: sample_from_f64(v[11]),
+            })
+        }
+    }
+}
+impl TryFrom<Vec<f64>> for Surround714 {
+    type Error = SampleFormatError;
+
+    fn try_from(v: Vec<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_slice())
+    }
+}
+impl Into<Vec<f64>> for Surround714 {
+    fn into(self) -> Vec<f64> {
+        vec![
+            sample_to_f64(self.front_left),
+            sample_to_f64(self.front_right),
+            sample_to_f64(self.center),
+            sample_to_f64(self.lfe),
+            sample_to_f64(self.side_left),
+            sample_to_f64(self.side_right),
+            sample_to_f64(self.rear_left),
+            sample_to_f64(self.rear_right),
+            sample_to_f64(self.top_front_left),
+            sample_to_f64(self.top_front_right),
+            sample_to_f64(self.top_rear_left),
+            sample_to_f64(self.top_rear_right),
+        ]
+    }
+}
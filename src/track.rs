@@ -0,0 +1,332 @@
+//! # Track
+//!
+//! Module containing a type holding a sequence of samples of a single sample
+//! format, such as the contents of an audio file or a mixer bus.
+
+use super::*;
+
+/// Struct holding a sequence of samples of the format `F`, with the common
+/// operations performed on whole tracks of audio.
+///
+/// Unlike the `Vec`-based track aliases of each format, such as
+/// [`StereoTrackT`], a Track provides methods for processing the samples as
+/// audio. It dereferences to a slice of its samples, giving access to their
+/// number, indexing and iteration, and converts to and from a `Vec` without
+/// copying.
+///
+/// [`StereoTrackT`]: ../stereo/type.StereoTrackT.html
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Track<F> {
+    samples: Vec<F>,
+}
+
+impl<F: SampleFormat + Copy> Track<F> {
+    /// Returns a new empty Track.
+    pub fn new() -> Self {
+        Track {
+            samples: Vec::new(),
+        }
+    }
+
+    /// Returns a new Track of the given number of silent samples.
+    ///
+    /// # Parameters
+    ///
+    /// * `len` - the number of samples.
+    pub fn silence(len: usize) -> Self {
+        Track {
+            samples: vec![F::SILENCE; len],
+        }
+    }
+
+    /// Returns the samples of the track as a `Vec`.
+    pub fn into_vec(self) -> Vec<F> {
+        self.samples
+    }
+
+    /// Applies the given gain in decibels to every sample of the track.
+    ///
+    /// # Parameters
+    ///
+    /// * `db` - the gain in decibels.
+    pub fn gain(&mut self, db: Math) {
+        let gain = bae_utils::db_to_linear(db);
+
+        for s in self.samples.iter_mut() {
+            *s *= gain.0;
+        }
+    }
+
+    /// Adds the samples of the given track to the samples of this track, both
+    /// starting from the first sample. If the given track is longer, this track
+    /// is extended with silence to its length first.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the track to mix into this track.
+    pub fn mix(&mut self, other: &Track<F>) {
+        if other.len() > self.len() {
+            self.resize_silence(other.len());
+        }
+
+        for (s, o) in self.samples.iter_mut().zip(other.iter()) {
+            *s += *o;
+        }
+    }
+
+    /// Appends the samples of the given track to the end of this track.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the track to append.
+    pub fn append(&mut self, other: &Track<F>) {
+        self.samples.extend_from_slice(&other.samples);
+    }
+
+    /// Splits the track in two at the given index. Returns a tuple of the
+    /// samples before the index and the samples from the index onwards
+    /// respectively.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - the index of the first sample of the second track.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than the number of samples.
+    pub fn split_at(mut self, index: usize) -> (Track<F>, Track<F>) {
+        let tail = self.samples.split_off(index);
+
+        (self, Track { samples: tail })
+    }
+
+    /// Resizes the track to the given number of samples, truncating it if it
+    /// is longer or extending it with silence if it is shorter.
+    ///
+    /// # Parameters
+    ///
+    /// * `len` - the new number of samples.
+    pub fn resize_silence(&mut self, len: usize) {
+        self.samples.resize(len, F::SILENCE);
+    }
+
+    /// Appends a single sample to the end of the track.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the sample to append.
+    pub fn push(&mut self, s: F) {
+        self.samples.push(s);
+    }
+}
+
+/// Mixes the tracks into a new track, each at its own gain in decibels. The
+/// result is as long as the longest track, with shorter tracks padded with
+/// silence.
+///
+/// # Parameters
+///
+/// * `tracks` - the tracks to mix and their gains in decibels.
+pub fn mix<F: SampleFormat + Copy>(tracks: &[(&Track<F>, Math)]) -> Track<F> {
+    let len = tracks.iter().map(|(t, _)| t.len()).max().unwrap_or(0);
+    let mut bus = Track::silence(len);
+
+    mix_into(&mut bus, tracks);
+
+    bus
+}
+
+/// Mixes the tracks into the given bus, each at its own gain in decibels,
+/// without allocating. Tracks shorter than the bus are mixed into its start,
+/// and tracks longer than the bus are truncated to it.
+///
+/// # Parameters
+///
+/// * `bus` - the samples the tracks are added to.
+/// * `tracks` - the tracks to mix and their gains in decibels.
+pub fn mix_into<F: SampleFormat + Copy>(bus: &mut [F], tracks: &[(&Track<F>, Math)]) {
+    for (track, db) in tracks {
+        let gain = bae_utils::db_to_linear(Math(db.0)).0;
+
+        for (b, s) in bus.iter_mut().zip(track.iter()) {
+            *b += *s * gain;
+        }
+    }
+}
+
+/// Splits the track into a mono track for each of its channels, in the same
+/// order as the [`Index`] impl of the format.
+///
+/// # Parameters
+///
+/// * `track` - the track to split.
+///
+/// [`Index`]: https://doc.rust-lang.org/std/ops/trait.Index.html
+pub fn split_channels<F: SampleFormat>(track: &Track<F>) -> Vec<MonoTrackT> {
+    (0..F::CHANNEL_COUNT)
+        .map(|c| track.iter().map(|s| Mono::from(s[c])).collect())
+        .collect()
+}
+
+/// Merges a mono track for each channel into a track of the format `F`, the
+/// inverse of [`split_channels`]. Returns [`ChannelCountMismatch`] if the
+/// number of tracks isn't the channel count of `F`, or [`WrongLength`] if the
+/// tracks aren't all as long as the first.
+///
+/// # Parameters
+///
+/// * `channels` - the tracks of each channel, in the same order as the
+/// [`Index`] impl of the format.
+///
+/// [`split_channels`]: fn.split_channels.html
+/// [`ChannelCountMismatch`]: ../error/enum.SampleFormatError.html#variant.ChannelCountMismatch
+/// [`WrongLength`]: ../error/enum.SampleFormatError.html#variant.WrongLength
+/// [`Index`]: https://doc.rust-lang.org/std/ops/trait.Index.html
+pub fn merge_channels<F: SampleFormat + Copy>(
+    channels: &[MonoTrackT],
+) -> Result<Track<F>, SampleFormatError> {
+    if channels.len() != F::CHANNEL_COUNT {
+        return Err(SampleFormatError::ChannelCountMismatch {
+            left: channels.len(),
+            right: F::CHANNEL_COUNT,
+        });
+    }
+
+    let len = channels.first().map_or(0, |c| c.len());
+    if let Some(c) = channels.iter().find(|c| c.len() != len) {
+        return Err(SampleFormatError::WrongLength {
+            expected: len,
+            actual: c.len(),
+        });
+    }
+
+    let mut track: Track<F> = Track::silence(len);
+    for (c, channel) in channels.iter().enumerate() {
+        for (s, m) in track.iter_mut().zip(channel.iter()) {
+            s[c] = m.into_sample();
+        }
+    }
+
+    Ok(track)
+}
+
+/// Reverses the order of the samples of the track in place.
+///
+/// # Parameters
+///
+/// * `track` - the track to reverse.
+pub fn reverse<F>(track: &mut Track<F>) {
+    track.samples.reverse();
+}
+
+/// Returns the track repeated the given number of times, with each repetition
+/// joined to the next by an equal-power crossfade from the end of the track to
+/// its start, as done by [`crossfade`], to avoid clicks at the joins.
+///
+/// # Parameters
+///
+/// * `track` - the track to loop.
+/// * `n` - the number of repetitions.
+/// * `crossfade_len` - the length of each crossfade in samples. Lengths
+/// greater than half the track are shortened to it, so that each repetition
+/// still adds to the length, and a length of 0 joins the repetitions directly.
+///
+/// [`crossfade`]: ../fade/fn.crossfade.html
+pub fn looped<F: SampleFormat + Copy>(
+    track: &Track<F>,
+    n: usize,
+    crossfade_len: usize,
+) -> Track<F> {
+    let xf = crossfade_len.min(track.len() / 2);
+    let mut samples =
+        Vec::with_capacity((n * track.len()).saturating_sub(n.saturating_sub(1) * xf));
+
+    for i in 0..n {
+        if i == 0 {
+            samples.extend_from_slice(track);
+            continue;
+        }
+
+        let start = samples.len() - xf;
+        for (j, (s, h)) in samples[start..].iter_mut().zip(track.iter()).enumerate() {
+            let t = (j as AccurateMath + 0.5) / xf as AccurateMath;
+            *s = *s * FadeCurve::EqualPower.gain(1.0 - t) + *h * FadeCurve::EqualPower.gain(t);
+        }
+        samples.extend_from_slice(&track[xf..]);
+    }
+
+    Track { samples }
+}
+
+impl<F> std::ops::Deref for Track<F> {
+    type Target = [F];
+
+    fn deref(&self) -> &Self::Target {
+        &self.samples
+    }
+}
+impl<F> std::ops::DerefMut for Track<F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.samples
+    }
+}
+
+impl<F> AsRef<[F]> for Track<F> {
+    fn as_ref(&self) -> &[F] {
+        &self.samples
+    }
+}
+impl<F> AsMut<[F]> for Track<F> {
+    fn as_mut(&mut self) -> &mut [F] {
+        &mut self.samples
+    }
+}
+
+impl<F> From<Vec<F>> for Track<F> {
+    fn from(samples: Vec<F>) -> Self {
+        Track { samples }
+    }
+}
+impl<F> From<Track<F>> for Vec<F> {
+    fn from(t: Track<F>) -> Self {
+        t.samples
+    }
+}
+
+impl<F> std::iter::FromIterator<F> for Track<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        Track {
+            samples: iter.into_iter().collect(),
+        }
+    }
+}
+impl<F> Extend<F> for Track<F> {
+    fn extend<I: IntoIterator<Item = F>>(&mut self, iter: I) {
+        self.samples.extend(iter);
+    }
+}
+
+impl<F> IntoIterator for Track<F> {
+    type Item = F;
+    type IntoIter = std::vec::IntoIter<F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.samples.into_iter()
+    }
+}
+impl<'a, F> IntoIterator for &'a Track<F> {
+    type Item = &'a F;
+    type IntoIter = std::slice::Iter<'a, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.samples.iter()
+    }
+}
+impl<'a, F> IntoIterator for &'a mut Track<F> {
+    type Item = &'a mut F;
+    type IntoIter = std::slice::IterMut<'a, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.samples.iter_mut()
+    }
+}
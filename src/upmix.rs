@@ -0,0 +1,99 @@
+//! # Upmix
+//!
+//! Module containing a passive matrix upmix of stereo content into the
+//! surround formats.
+
+use super::*;
+
+/// Delays in seconds applied to the left and right surround channels
+/// respectively at full decorrelation. The delays differ between the two
+/// channels so that they aren't simply inverted copies of each other.
+const DECORRELATION_DELAYS: [AccurateMath; 2] = [0.010, 0.0137];
+
+/// Stateful passive upmix from [`Stereo`] to [`Surround51`], so that stereo
+/// content can feed surround buses.
+///
+/// A proportion of the content common to both channels of the stereo sample,
+/// that being the smaller of the two when they have the same polarity, is
+/// extracted from the front channels into the center channel. As no more than
+/// is common to both is removed, content in only one channel stays in its front
+/// channel and no front channel changes polarity. The side signal `(L - R) / 2`,
+/// which holds the ambience of most recordings, is fed to the surround channels
+/// in opposite polarity. The surround channels are delayed to decorrelate them from the
+/// front channels and from each other. The LFE channel is left silent.
+///
+/// [`Stereo`]: ../stereo/struct.Stereo.html
+/// [`Surround51`]: ../surround51/struct.Surround51.html
+#[derive(Debug, Clone)]
+pub struct StereoUpmixer {
+    center: AccurateMath,
+    delays: [usize; 2],
+    side: Vec<Sample>,
+    index: usize,
+}
+
+impl StereoUpmixer {
+    /// Creates a new StereoUpmixer for the given sample rate.
+    ///
+    /// # Parameters
+    ///
+    /// * `sample_rate` - the sample rate of the audio being processed in Hz.
+    /// * `center` - the proportion of the common content extracted into the
+    /// center channel, in the range \[0,1\]. At 0 the center channel is silent, and at
+    /// 1 a sample panned to the center is reproduced by the center channel
+    /// alone at the same power. Values outside of the range are clamped to it.
+    /// * `decorrelation` - the amount the surround channels are delayed, in the
+    /// range \[0,1\]. At 0 the surround channels aren't delayed, and at 1 they
+    /// are delayed by 10 and 13.7 milliseconds respectively. Values outside of
+    /// the range are clamped to it.
+    pub fn new(sample_rate: Math, center: AccurateMath, decorrelation: AccurateMath) -> Self {
+        let d = decorrelation.clamp(0.0, 1.0);
+        let delays = DECORRELATION_DELAYS.map(|t| (t * d * sample_rate.0).round() as usize);
+
+        StereoUpmixer {
+            center: center.clamp(0.0, 1.0),
+            delays,
+            side: vec![Sample(0.0); delays[1].max(delays[0]) + 1],
+            index: 0,
+        }
+    }
+
+    /// Processes the next sample of the stereo source.
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the next stereo sample.
+    pub fn process(&mut self, s: Stereo) -> Surround51 {
+        let (l, r) = (s.left.0 as AccurateMath, s.right.0 as AccurateMath);
+        let common = if l * r > 0.0 {
+            l.signum() * l.abs().min(r.abs())
+        } else {
+            0.0
+        };
+        let len = self.side.len();
+
+        self.side[self.index] = Sample(((l - r) / 2.0) as FastMath);
+        let ls = self.side[(self.index + len - self.delays[0]) % len];
+        let rs = self.side[(self.index + len - self.delays[1]) % len];
+        self.index = (self.index + 1) % len;
+
+        let extracted = self.center * common;
+
+        Surround51 {
+            front_left: Sample((l - extracted) as FastMath),
+            front_right: Sample((r - extracted) as FastMath),
+            center: Sample((extracted * std::f64::consts::SQRT_2) as FastMath),
+            lfe: Sample(0.0),
+            surround_left: ls,
+            surround_right: Sample(-rs.0),
+        }
+    }
+
+    /// Clears the internal delay line.
+    pub fn reset(&mut self) {
+        for s in self.side.iter_mut() {
+            *s = Sample(0.0);
+        }
+        self.index = 0;
+    }
+}
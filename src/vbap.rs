@@ -0,0 +1,113 @@
+//! # VBAP
+//!
+//! Module containing a panner for custom horizontal loudspeaker rigs using
+//! vector base amplitude panning (VBAP).
+
+use super::*;
+
+/// Struct describing a horizontal ring of `N` loudspeakers at arbitrary
+/// azimuths, used to pan monophonic sources into a [`MultiChannel`] sample with
+/// one channel per speaker.
+///
+/// Azimuths are given in degrees, measured clockwise from the front of the
+/// listener such that -90 is directly left and 90 is directly right, matching
+/// the other panners of this crate.
+///
+/// [`MultiChannel`]: ../multichannel/struct.MultiChannel.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpeakerArray<const N: usize> {
+    azimuths: [AccurateMath; N],
+}
+
+impl<const N: usize> SpeakerArray<N> {
+    /// Creates a new speaker array from the azimuths of its speakers. The
+    /// speakers may be given in any order, and the channels of the panned
+    /// samples are in the same order.
+    ///
+    /// # Parameters
+    ///
+    /// * `azimuths` - the azimuth of each speaker in degrees.
+    pub fn new(azimuths: [AccurateMath; N]) -> Self {
+        SpeakerArray { azimuths }
+    }
+
+    /// Returns the azimuths of the speakers in degrees.
+    pub fn azimuths(&self) -> &[AccurateMath; N] {
+        &self.azimuths
+    }
+
+    /// Calculates the gain of each speaker for a source at the given azimuth.
+    ///
+    /// The source is panned between the two speakers adjacent to it, with the
+    /// gains chosen such that the sum of the speaker direction vectors points
+    /// at the source, and then normalized to constant power. All other
+    /// speakers receive a gain of 0. If the two adjacent speakers are 180
+    /// degrees or more apart, such as across a gap behind the listener, no
+    /// such gains exist and the source is instead panned with constant power
+    /// in proportion to its angle between the speakers.
+    ///
+    /// # Parameters
+    ///
+    /// * `azimuth` - the azimuth of the source in degrees.
+    pub fn gains(&self, azimuth: AccurateMath) -> [AccurateMath; N] {
+        let mut gains = [0.0; N];
+
+        if N == 0 {
+            return gains;
+        }
+
+        let (prev, next) = panning::adjacent_speakers(azimuth, &self.azimuths);
+
+        if prev.0 == next.0 {
+            gains[prev.0] = 1.0;
+            return gains;
+        }
+        if prev.1 + next.1 >= 180.0 {
+            panning::ring_gains(azimuth, &self.azimuths, &mut gains);
+            return gains;
+        }
+
+        let (sx, sy) = direction(azimuth);
+        let (px, py) = direction(self.azimuths[prev.0]);
+        let (nx, ny) = direction(self.azimuths[next.0]);
+
+        // Solves [p n] * [gp gn]^T = s for the gains of the speaker pair
+        let det = px * ny - nx * py;
+        let gp = (sx * ny - nx * sy) / det;
+        let gn = (px * sy - sx * py) / det;
+        let norm = (gp * gp + gn * gn).sqrt();
+
+        gains[prev.0] = gp / norm;
+        gains[next.0] = gn / norm;
+
+        gains
+    }
+
+    /// Pans the given sample to the given azimuth using the gains calculated
+    /// by [`gains`].
+    ///
+    /// # Parameters
+    ///
+    /// * `s` - the monophonic sample to pan.
+    /// * `azimuth` - the azimuth of the source in degrees.
+    ///
+    /// [`gains`]: #method.gains
+    pub fn pan(&self, s: Sample, azimuth: AccurateMath) -> MultiChannel<N> {
+        let gains = self.gains(azimuth);
+        let mut m = MultiChannel::new();
+
+        for (c, g) in m.channels.iter_mut().zip(gains.iter()) {
+            *c = Sample((s.0 as AccurateMath * g) as FastMath);
+        }
+
+        m
+    }
+}
+
+/// Returns the unit vector pointing towards the given azimuth in degrees, with
+/// x to the right of the listener and y to the front.
+fn direction(azimuth: AccurateMath) -> (AccurateMath, AccurateMath) {
+    let a = azimuth.to_radians();
+
+    (a.sin(), a.cos())
+}
@@ -0,0 +1,54 @@
+use bae_sf::*;
+use bae_types::{Math, Sample};
+
+#[test]
+fn test_head_shadow_panner_itd() {
+    let rate = 48000.0;
+    let mut p = HeadShadowPanner::new(Math(rate));
+    let itd = Binaural::interaural_time_difference(30.0) * rate;
+
+    // An impulse reaches the right ear immediately and the left ear after the
+    // fractional interaural delay, split between the two adjacent samples
+    let out: Vec<Binaural> = (0..40)
+        .map(|i| p.process(Sample(if i == 0 { 1.0 } else { 0.0 }), 30.0))
+        .collect();
+    let left: Vec<f64> = out.iter().map(|s| s.left.0 as f64).collect();
+
+    let whole = itd.floor() as usize;
+    assert!(left[..whole].iter().all(|x| *x == 0.0));
+    assert!(left[whole] > 0.0 && left[whole + 1] > 0.0);
+    assert!(out[0].right.0 > 0.0);
+
+    let centroid: f64 = left
+        .iter()
+        .enumerate()
+        .map(|(i, x)| i as f64 * x)
+        .sum::<f64>()
+        / left.iter().sum::<f64>();
+    assert!(centroid > itd);
+}
+
+#[test]
+fn test_head_shadow_panner_shelf() {
+    let mut p = HeadShadowPanner::new(Math(48000.0));
+
+    // A constant signal is below the shelf, so both ears settle to unity
+    let mut s = Binaural::new();
+    for _ in 0..48000 {
+        s = p.process(Sample(1.0), -90.0);
+    }
+    assert!((s.left.0 - 1.0).abs() < 1e-4);
+    assert!((s.right.0 - 1.0).abs() < 1e-4);
+
+    // A signal at the Nyquist frequency is above the shelf, so the far ear is
+    // attenuated
+    p.reset();
+    let (mut near, mut far) = (0.0f32, 0.0f32);
+    for i in 0..4800 {
+        let s = p.process(Sample(if i % 2 == 0 { 1.0 } else { -1.0 }), -90.0);
+        near = near.max(s.left.0.abs());
+        far = far.max(s.right.0.abs());
+    }
+    assert!(far < 0.5 * near);
+    assert!(HeadShadowPanner::shelf_frequency() > 500.0);
+}
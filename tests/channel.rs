@@ -0,0 +1,234 @@
+use bae_sf::*;
+use bae_types::Sample;
+
+#[test]
+fn test_format_masks() {
+    assert_eq!(ChannelMask::from_format::<Mono>(), Some(ChannelMask(0x4)));
+    assert_eq!(ChannelMask::from_format::<Stereo>(), Some(ChannelMask(0x3)));
+    assert_eq!(ChannelMask::from_format::<Quad>(), Some(ChannelMask(0x33)));
+    assert_eq!(
+        ChannelMask::from_format::<Surround51>(),
+        Some(ChannelMask(0x60F))
+    );
+    assert_eq!(
+        ChannelMask::from_format::<Surround71>(),
+        Some(ChannelMask(0x63F))
+    );
+    assert_eq!(ChannelMask::from_format::<MidSide>(), None);
+    assert_eq!(ChannelMask::from_format::<MultiChannel<4>>(), None);
+}
+
+#[test]
+fn test_mask_labels() {
+    let m = ChannelMask::FRONT_LEFT | ChannelMask::FRONT_RIGHT | ChannelMask::LOW_FREQUENCY;
+
+    assert_eq!(
+        m.labels(),
+        vec![
+            ChannelLabel::FrontLeft,
+            ChannelLabel::FrontRight,
+            ChannelLabel::Lfe
+        ]
+    );
+    assert!(m.matches_format::<Surround21>());
+    assert!(!m.matches_format::<Stereo>());
+}
+
+#[test]
+fn test_reorder_interleaved() {
+    use bae_types::Sample;
+
+    let from = ChannelLayout::new(vec![
+        ChannelLabel::FrontLeft,
+        ChannelLabel::Center,
+        ChannelLabel::FrontRight,
+    ]);
+    let to = ChannelLayout::from_format::<Stereo>();
+    assert!(from.mapping(&to).is_err());
+
+    let to = ChannelLayout::new(vec![
+        ChannelLabel::FrontLeft,
+        ChannelLabel::FrontRight,
+        ChannelLabel::Center,
+    ]);
+    let mut data: Vec<Sample> = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0]
+        .iter()
+        .map(|x| Sample(*x))
+        .collect();
+
+    reorder_interleaved(&from, &to, &mut data).unwrap();
+    assert_eq!(
+        data.iter().map(|s| s.0).collect::<Vec<_>>(),
+        vec![0.0, 2.0, 1.0, 3.0, 5.0, 4.0]
+    );
+}
+
+#[test]
+fn test_channel_order() {
+    use bae_types::Sample;
+
+    let film: Vec<Sample> = (0..8).map(|x| Sample(x as f32)).collect();
+    let s = Surround71::from_order(&film, ChannelOrder::Film).unwrap();
+
+    assert_eq!(s.center, Sample(1.0));
+    assert_eq!(s.lfe, Sample(7.0));
+    assert_eq!(s.to_order(ChannelOrder::Film).to_vec(), film);
+
+    let wav = s.to_order(ChannelOrder::Wav);
+    assert_eq!(wav[4], s.rear_left);
+    assert_eq!(wav[6], s.side_left);
+    assert_eq!(
+        ChannelOrder::Wav.surround71(),
+        ChannelLayout::from_mask(ChannelMask::from_format::<Surround71>().unwrap())
+    );
+}
+
+#[test]
+fn test_index() {
+    use bae_types::Sample;
+
+    fn sum<F: SampleFormat>(f: &F) -> f32 {
+        (0..F::CHANNEL_COUNT).map(|i| f[i].0).sum()
+    }
+
+    let mut s = Stereo::from(Sample(0.25), Sample(0.5));
+    assert_eq!(s[0], s.left);
+    assert_eq!(s[1], s.right);
+
+    s[1] = Sample(1.0);
+    assert_eq!(s.right, Sample(1.0));
+    assert_eq!(sum(&s), 1.25);
+
+    let mut m = MultiChannel::<3>::new();
+    m[2] = Sample(2.0);
+    assert_eq!(m.channels[2], Sample(2.0));
+    assert_eq!(sum(&m), 2.0);
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_range() {
+    let s = Stereo::new();
+    let _ = s[2];
+}
+
+#[test]
+fn test_format_reorder() {
+    use bae_types::Sample;
+
+    let from = ChannelLayout::new(vec![ChannelLabel::FrontRight, ChannelLabel::FrontLeft]);
+    let to = ChannelLayout::from_format::<Stereo>();
+
+    let s = Stereo::from(Sample(1.0), Sample(0.0))
+        .reorder(&from, &to)
+        .unwrap();
+    assert_eq!(s, Stereo::from(Sample(0.0), Sample(1.0)));
+
+    assert!(Mono::new().reorder(&from, &to).is_err());
+}
+
+#[test]
+fn test_channel_count() {
+    use bae_types::Sample;
+
+    const FRAME: [Sample; Surround51::CHANNEL_COUNT] = [Sample(0.0); Surround51::CHANNEL_COUNT];
+
+    assert_eq!(FRAME.len(), 6);
+    assert_eq!(Stereo::CHANNEL_COUNT, 2);
+    assert_eq!(Hoa2::CHANNEL_COUNT, 9);
+    assert_eq!(Surround51::num_samples(), Surround51::CHANNEL_COUNT);
+}
+
+#[test]
+fn test_display() {
+    use bae_types::Sample;
+
+    let s = Stereo::from(Sample(0.5), Sample(0.0));
+    assert_eq!(
+        s.to_string(),
+        "L: 0.5000 (-6.02 dBFS), R: 0.0000 (-inf dBFS)"
+    );
+    assert_eq!(
+        format!(
+            "{:.1}",
+            Surround21::from(Sample(1.0), Sample(-1.0), Sample(0.1))
+        ),
+        "L: 1.0 (0.00 dBFS), R: -1.0 (0.00 dBFS), LFE: 0.1 (-20.00 dBFS)"
+    );
+    assert_eq!(
+        DynFormat::from(vec![Sample(1.0)]).to_string(),
+        "0: 1.0000 (0.00 dBFS)"
+    );
+    assert_eq!(ChannelLabel::Ambisonic(3).to_string(), "ACN3");
+}
+
+#[test]
+fn test_label_side() {
+    assert!(ChannelLabel::SideLeft.is_left());
+    assert!(!ChannelLabel::SideLeft.is_right());
+    assert!(ChannelLabel::TopRearRight.is_right());
+    assert!(!ChannelLabel::Center.is_left() && !ChannelLabel::Center.is_right());
+    assert!(!ChannelLabel::Discrete(0).is_left());
+}
+
+#[test]
+fn test_mute_solo() {
+    let x = |v| Sample(v);
+    let s = Surround21::from(x(0.1), x(0.2), x(0.3));
+
+    assert_eq!(s.mute(1), Surround21::from(x(0.1), x(0.0), x(0.3)));
+    assert_eq!(s.solo(2), Surround21::from(x(0.0), x(0.0), x(0.3)));
+
+    let mut track = vec![s, s * Sample(2.0)];
+    Surround21::mute_track(&mut track, 0);
+    assert_eq!(track[1], Surround21::from(x(0.0), x(0.4), x(0.6)));
+    Surround21::solo_track(&mut track, 1);
+    assert_eq!(track, vec![s.solo(1), (s * Sample(2.0)).solo(1)]);
+
+    let mut empty: Vec<Stereo> = vec![];
+    Stereo::mute_track(&mut empty, 5);
+}
+
+#[test]
+#[should_panic]
+fn test_mute_out_of_range() {
+    let _ = Stereo::new().mute(2);
+}
+
+#[test]
+fn test_invert_channels() {
+    let x = |v| Sample(v);
+    let s = Surround21::from(x(0.1), x(0.2), x(0.3));
+
+    assert_eq!(
+        s.invert_channels(&[1, 2]),
+        Surround21::from(x(0.1), x(-0.2), x(-0.3))
+    );
+    assert_eq!(s.invert_channels(&[]), s);
+    assert_eq!(s.invert_channels(&[0, 0]), s.invert_channels(&[0]));
+
+    let mut track = vec![s, -s];
+    Surround21::invert_channels_track(&mut track, &[0]);
+    assert_eq!(
+        track,
+        vec![
+            Surround21::from(x(-0.1), x(0.2), x(0.3)),
+            Surround21::from(x(0.1), x(-0.2), x(-0.3))
+        ]
+    );
+
+    let m = MultiChannel::<4>::splat(x(0.5)).invert_channels(&[0, 3]);
+    assert_eq!(m.channels, [x(-0.5), x(0.5), x(0.5), x(-0.5)]);
+
+    let b = AmbisonicB::splat(x(0.5)).invert_channels(&[2]);
+    assert_eq!(b[2], x(-0.5));
+
+    let mut empty: Vec<Stereo> = vec![];
+    Stereo::invert_channels_track(&mut empty, &[5]);
+}
+
+#[test]
+#[should_panic]
+fn test_invert_channels_out_of_range() {
+    let _ = Stereo::new().invert_channels(&[2]);
+}
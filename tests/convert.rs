@@ -0,0 +1,589 @@
+use bae_sf::*;
+use bae_types::{Math, Sample};
+
+#[test]
+fn test_packed_i24() {
+    let bytes = [0x00, 0x00, 0x40, 0x00, 0x00, 0xC0, 0xFF];
+
+    let s = Stereo::from_packed_i24(&bytes, Endianness::Little).unwrap();
+    assert_eq!(s, Stereo::from(Sample(0.5), Sample(-0.5)));
+    assert_eq!(s.into_packed_i24(Endianness::Little), bytes[..6].to_vec());
+    assert_eq!(
+        s.into_packed_i24(Endianness::Big),
+        vec![0x40, 0x00, 0x00, 0xC0, 0x00, 0x00]
+    );
+
+    assert_eq!(
+        Surround21::from_packed_i24(&bytes, Endianness::Little),
+        Err(SampleFormatError::WrongLength {
+            expected: 9,
+            actual: 7
+        })
+    );
+
+    let d = DynFormat::from_packed_i24(&bytes, 2, Endianness::Little).unwrap();
+    assert_eq!(d.channels, vec![s.left, s.right]);
+    assert_eq!(d.into_packed_i24(Endianness::Little), bytes[..6].to_vec());
+}
+
+#[test]
+fn test_big_endian() {
+    let s = Stereo::from(Sample(0.5), Sample(-0.25));
+
+    let be = s.into_i16_bytes(Endianness::Big);
+    assert_eq!(be, vec![0x40, 0x00, 0xE0, 0x00]);
+    assert_eq!(Stereo::from_i16_bytes(&be, Endianness::Big), Ok(s));
+    assert_ne!(Stereo::from_i16_bytes(&be, Endianness::Little), Ok(s));
+
+    let be = s.into_f32_bytes(Endianness::Big);
+    assert_eq!(&be[..4], &0.5f32.to_be_bytes());
+    assert_eq!(Stereo::from_f32_bytes(&be, Endianness::Big), Ok(s));
+
+    let d = DynFormat::from_i16_bytes(&[0x40, 0x00], 1, Endianness::Big).unwrap();
+    assert_eq!(d.channels, vec![Sample(0.5)]);
+}
+
+#[test]
+fn test_convert_format() {
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    let s = Stereo::from(Sample(0.5), Sample(-0.25));
+
+    let m: Mono = s.convert_format();
+    assert!((m.mono.0 - 0.25 * h).abs() < 1e-6);
+
+    let back: Stereo = Mono::from(Sample(1.0)).convert_format();
+    assert!(back.approx_eq(&Stereo::from(Sample(h), Sample(h)), 1e-6));
+
+    let up: Surround51 = s.convert_format();
+    assert_eq!(
+        up,
+        Surround51::from(
+            Sample(0.5),
+            Sample(-0.25),
+            Sample(0.0),
+            Sample(0.0),
+            Sample(0.0),
+            Sample(0.0)
+        )
+    );
+    let down: Stereo = up.convert_format();
+    assert_eq!(down, s);
+
+    let x = |v| Sample(v);
+    let surround = Surround51::from(x(0.1), x(0.2), x(0.4), x(1.0), x(0.3), x(0.6));
+    let down: Stereo = surround.convert_format();
+    let expected = Stereo::from(x(0.1 + h * 0.4 + h * 0.3), x(0.2 + h * 0.4 + h * 0.6));
+    assert!(down.approx_eq(&expected, 1e-6));
+
+    let s71 = Surround71::from(
+        x(0.1),
+        x(0.2),
+        x(0.3),
+        x(0.4),
+        x(0.5),
+        x(0.6),
+        x(0.7),
+        x(0.8),
+    );
+    let s51: Surround51 = s71.convert_format();
+    let expected = Surround51::from(
+        x(0.1),
+        x(0.2),
+        x(0.3),
+        x(0.4),
+        x(0.5 + h * 0.7),
+        x(0.6 + h * 0.8),
+    );
+    assert!(s51.approx_eq(&expected, 1e-6));
+
+    let s61 = Surround61::from(x(0.0), x(0.0), x(0.0), x(0.0), x(0.0), x(0.0), x(1.0));
+    let s51: Surround51 = s61.convert_format();
+    assert!((s51.surround_left.0 - h).abs() < 1e-6);
+    assert!((s51.surround_right.0 - h).abs() < 1e-6);
+    assert_eq!(s51.center, x(0.0));
+
+    let ms: MidSide = s.convert_format();
+    let encoded: MidSide = s.into();
+    let decoded: Stereo = ms.into();
+    assert_eq!(ms, encoded);
+    let back: Stereo = ms.convert_format();
+    assert_eq!(back, decoded);
+    let m: Mono = ms.convert_format();
+    assert_eq!(m, Mono::from(ms.mid));
+
+    let b: AmbisonicB = s.convert_format();
+    assert!(b.is_silent());
+}
+
+#[test]
+fn test_downmix_bs775() {
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    let x = |v| Sample(v);
+    let s = Surround51::from(x(0.1), x(0.2), x(0.4), x(0.5), x(0.3), x(0.6));
+
+    let down = s.downmix_bs775(Math(f64::NEG_INFINITY));
+    let expected = Stereo::from(x(0.1 + h * 0.4 + h * 0.3), x(0.2 + h * 0.4 + h * 0.6));
+    assert!(down.approx_eq(&expected, 1e-6));
+    let converted: Stereo = s.convert_format();
+    assert!(down.approx_eq(&converted, 1e-6));
+
+    let down = s.downmix_bs775(Math(0.0));
+    assert!(down.approx_eq(&(expected + Stereo::splat(x(0.5))), 1e-6));
+
+    let down = s.downmix_bs775(Math(-6.0));
+    assert!(down.approx_eq(&(expected + Stereo::splat(x(0.5 * 0.501_187))), 1e-6));
+}
+
+#[test]
+fn test_stereo_upmixer() {
+    let x = |v| Sample(v);
+
+    let mut up = StereoUpmixer::new(Math(1000.0), 0.0, 0.0);
+    let s = up.process(Stereo::from(x(0.5), x(0.25)));
+    assert_eq!(
+        s,
+        Surround51::from(x(0.5), x(0.25), x(0.0), x(0.0), x(0.125), x(-0.125))
+    );
+
+    let mut up = StereoUpmixer::new(Math(1000.0), 1.0, 0.0);
+    let s = up.process(Stereo::splat(x(0.5)));
+    assert_eq!(s.front_left, x(0.0));
+    assert_eq!(s.front_right, x(0.0));
+    assert!((s.center.0 - 0.5 * std::f32::consts::SQRT_2).abs() < 1e-6);
+    assert!(s.surround_left.0.abs() < 1e-9);
+
+    let s = up.process(Stereo::from(x(0.5), x(0.25)));
+    assert_eq!(s.front_left, x(0.25));
+    assert_eq!(s.front_right, x(0.0));
+    assert!((s.center.0 - 0.25 * std::f32::consts::SQRT_2).abs() < 1e-6);
+
+    let s = up.process(Stereo::from(x(0.5), x(-0.5)));
+    assert_eq!(s.front_left, x(0.5));
+    assert_eq!(s.front_right, x(-0.5));
+    assert_eq!(s.center, x(0.0));
+
+    let mut up = StereoUpmixer::new(Math(1000.0), 0.5, 1.0);
+    let out: Vec<Surround51> = (0..20)
+        .map(|i| {
+            up.process(if i == 0 {
+                Stereo::from(x(1.0), x(0.0))
+            } else {
+                Stereo::new()
+            })
+        })
+        .collect();
+    assert_eq!(out[0].front_left, x(1.0));
+    assert_eq!(out[0].front_right, x(0.0));
+    assert_eq!(out[0].center, x(0.0));
+    let ls: Vec<usize> = (0..20).filter(|i| out[*i].surround_left.0 != 0.0).collect();
+    let rs: Vec<usize> = (0..20)
+        .filter(|i| out[*i].surround_right.0 != 0.0)
+        .collect();
+    assert_eq!(ls, vec![10]);
+    assert_eq!(rs, vec![14]);
+    assert_eq!(out[10].surround_left, x(0.5));
+    assert_eq!(out[14].surround_right, x(-0.5));
+
+    up.reset();
+    assert!(up.process(Stereo::new()).is_silent());
+}
+
+#[test]
+fn test_lt_rt() {
+    let x = |v| Sample(v);
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+
+    let mut passive = LtRtEncoder::new(NoPhaseShift);
+    let s = Surround51::from(x(0.1), x(0.2), x(0.4), x(1.0), x(0.3), x(0.0));
+    let lt_rt = passive.process(s);
+    let expected = Stereo::from(
+        x(0.1 + h * 0.4 - 0.8716 * 0.3),
+        x(0.2 + h * 0.4 + 0.4898 * 0.3),
+    );
+    assert!(lt_rt.approx_eq(&expected, 1e-6));
+
+    let decoded = decode_lt_rt(Stereo::from(x(0.5), x(0.5)));
+    assert_eq!(decoded.front_left, x(0.5));
+    assert!((decoded.center.0 - h).abs() < 1e-6);
+    assert_eq!(decoded.surround_left, x(0.0));
+
+    let decoded = decode_lt_rt(Stereo::from(x(-0.5), x(0.5)));
+    assert_eq!(decoded.center, x(0.0));
+    assert!((decoded.surround_right.0 - h).abs() < 1e-6);
+
+    // The quadrature output lags the in-phase output by a quarter period
+    let mut filter = HilbertFilter::default();
+    let period = 48;
+    let out: Vec<(Sample, Sample)> = (0..4800)
+        .map(|n| {
+            let t = n as f64 / period as f64 * std::f64::consts::TAU;
+            filter.process(Sample(t.sin() as f32))
+        })
+        .collect();
+    for n in 4000..4800 {
+        assert!((out[n].1 .0 - out[n - period / 4].0 .0).abs() < 0.02);
+        assert!(out[n].0 .0.abs() < 1.01);
+    }
+
+    filter.reset();
+    assert_eq!(filter, HilbertFilter::default());
+
+    let mut encoder: LtRtEncoder = LtRtEncoder::default();
+    assert!(encoder.process(Surround51::new()).is_silent());
+    encoder.reset();
+}
+
+#[test]
+fn test_mix_matrix() {
+    let x = |v| Sample(v);
+    let s = Stereo::from(x(0.5), x(0.25));
+
+    let swap = MixMatrix::new([[0.0, 1.0], [1.0, 0.0]]);
+    let swapped: Stereo = swap.apply(s);
+    assert_eq!(swapped, Stereo::from(x(0.25), x(0.5)));
+
+    let identity: MixMatrix<2, 2> = MixMatrix::identity();
+    assert_eq!(identity.then(&swap), swap);
+    assert_eq!(swap.then(&swap), identity);
+
+    let sum = MixMatrix::new([[0.5, 0.5]]);
+    let m: Mono = sum.apply(s);
+    assert_eq!(m, Mono::from(x(0.375)));
+    let ms: MixMatrix<2, 1> = swap.then(&sum);
+    assert_eq!(ms.gains(), &[[0.5, 0.5]]);
+
+    let mut down: MixMatrix<6, 2> = MixMatrix::conversion::<Surround51, Stereo>();
+    let surround = Surround51::from(x(0.1), x(0.2), x(0.4), x(1.0), x(0.3), x(0.6));
+    let converted: Stereo = surround.convert_format();
+    let applied: Stereo = down.apply(surround);
+    assert!(applied.approx_eq(&converted, 1e-6));
+
+    down.set_gain(0, 3, 1.0);
+    down.set_gain(1, 3, 1.0);
+    assert_eq!(down.gain(1, 3), 1.0);
+    let with_lfe: Stereo = down.apply(surround);
+    assert!(with_lfe.approx_eq(&surround.downmix_bs775(Math(0.0)), 1e-6));
+
+    let track: Vec<Stereo> = swap.apply_track(&[s, swapped]);
+    assert_eq!(track, vec![swapped, s]);
+}
+
+#[test]
+#[should_panic]
+fn test_mix_matrix_wrong_channel_count() {
+    let swap = MixMatrix::new([[0.0, 1.0], [1.0, 0.0]]);
+    let _: Stereo = swap.apply(Surround21::new());
+}
+
+#[test]
+fn test_downmix_builder() {
+    let x = |v| Sample(v);
+    let surround = Surround51::from(x(0.1), x(0.2), x(0.4), x(1.0), x(0.3), x(0.6));
+
+    let default: MixMatrix<6, 2> = DownmixBuilder::<Surround51, Stereo>::new().build();
+    assert_eq!(default, MixMatrix::conversion::<Surround51, Stereo>());
+
+    let builder = DownmixBuilder::<Surround51, Stereo>::new()
+        .gain(ChannelLabel::Center, Math(-6.0))
+        .gain(ChannelLabel::Lfe, Math(0.0))
+        .mute(ChannelLabel::SideLeft)
+        .mute(ChannelLabel::SideRight)
+        .gain(ChannelLabel::Center, Math(0.0));
+    let matrix: MixMatrix<6, 2> = builder.build();
+    assert_eq!(
+        matrix.gains(),
+        &[
+            [1.0, 0.0, 1.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 1.0, 1.0, 0.0, 0.0]
+        ]
+    );
+
+    let downmix = builder.build_fn();
+    let expected: Stereo = matrix.apply(surround);
+    assert!(downmix(surround).approx_eq(&expected, 1e-6));
+    assert!(downmix(surround).approx_eq(&Stereo::from(x(1.5), x(1.6)), 1e-6));
+
+    let ms = DownmixBuilder::<MidSide, Stereo>::new()
+        .gain(ChannelLabel::Side, Math(-6.0))
+        .build_fn();
+    let s = ms(MidSide::from(x(0.5), x(0.5)));
+    assert!(s.approx_eq(&Stereo::from(x(0.75), x(0.25)), 1e-3));
+}
+
+#[test]
+fn test_widening_from() {
+    let x = |v| Sample(v);
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+
+    let s: Stereo = Mono::from(x(1.0)).into();
+    assert!(s.approx_eq(&Stereo::from_sample(x(1.0)), 1e-6));
+    assert!(s.approx_eq(&Stereo::from(x(h), x(h)), 1e-6));
+
+    let lcr: Lcr = Mono::from(x(0.5)).into();
+    assert_eq!(lcr, Lcr::from(x(0.0), x(0.5), x(0.0)));
+
+    let stereo = Stereo::from(x(0.25), x(0.5));
+    let s51: Surround51 = stereo.into();
+    assert_eq!(
+        s51,
+        Surround51::from(x(0.25), x(0.5), x(0.0), x(0.0), x(0.0), x(0.0))
+    );
+    let quad: Quad = stereo.into();
+    assert_eq!(quad, Quad::from(x(0.25), x(0.5), x(0.0), x(0.0)));
+
+    let s51 = Surround51::from(x(0.1), x(0.2), x(0.3), x(0.4), x(0.5), x(0.6));
+    let s71: Surround71 = s51.into();
+    assert_eq!(
+        s71,
+        Surround71::from(
+            x(0.1),
+            x(0.2),
+            x(0.3),
+            x(0.4),
+            x(0.5),
+            x(0.6),
+            x(0.0),
+            x(0.0)
+        )
+    );
+    let s714: Surround714 = s71.into();
+    let from51: Surround714 = s51.into();
+    assert_eq!(s714, from51);
+    let back: Surround51 = s714.convert_format();
+    assert_eq!(back, s51);
+}
+
+#[test]
+fn test_interleave() {
+    let x = |v| Sample(v);
+    let track = vec![
+        Stereo::from(x(0.1), x(0.2)),
+        Stereo::from(x(0.3), x(0.4)),
+        Stereo::from(x(0.5), x(0.6)),
+    ];
+    let data = vec![x(0.1), x(0.2), x(0.3), x(0.4), x(0.5), x(0.6)];
+
+    assert_eq!(interleave(&track), data);
+    assert_eq!(deinterleave::<Stereo>(&data), Ok(track.clone()));
+    assert_eq!(interleaved(&track).nth(3), Some(x(0.4)));
+
+    let mut out = vec![x(0.0); 6];
+    assert_eq!(interleave_into(&track, &mut out), Ok(()));
+    assert_eq!(out, data);
+    assert_eq!(
+        interleave_into(&track, &mut out[..5]),
+        Err(SampleFormatError::WrongLength {
+            expected: 6,
+            actual: 5
+        })
+    );
+
+    let mut frames = vec![Stereo::new(); 3];
+    assert_eq!(deinterleave_into(&data, &mut frames), Ok(()));
+    assert_eq!(frames, track);
+    assert!(deinterleave_into(&data[..4], &mut frames).is_err());
+
+    assert_eq!(
+        deinterleave::<Surround21>(&data[..5]),
+        Err(SampleFormatError::UnalignedLength {
+            channels: 3,
+            actual: 5
+        })
+    );
+    let frames: Vec<Surround21> = deinterleaved(&data).unwrap().collect();
+    assert_eq!(frames[1], Surround21::from(x(0.4), x(0.5), x(0.6)));
+}
+
+#[test]
+fn test_encode_track() {
+    let x = |v| Sample(v);
+    let track = vec![
+        Stereo::from(x(0.5), x(-0.25)),
+        Stereo::from(x(1.0), x(-1.0)),
+    ];
+    let per_sample =
+        |f: &dyn Fn(Stereo) -> Vec<u8>| -> Vec<u8> { track.iter().flat_map(|s| f(*s)).collect() };
+
+    assert_eq!(
+        encode_track(&track, BitDepth::U8, Endianness::Little),
+        per_sample(&|s| s.into())
+    );
+    for e in [Endianness::Little, Endianness::Big] {
+        assert_eq!(
+            encode_track(&track, BitDepth::I16, e),
+            per_sample(&|s| s.into_i16_bytes(e))
+        );
+        assert_eq!(
+            encode_track(&track, BitDepth::I24, e),
+            per_sample(&|s| s.into_packed_i24(e))
+        );
+        assert_eq!(
+            encode_track(&track, BitDepth::F32, e),
+            per_sample(&|s| s.into_f32_bytes(e))
+        );
+    }
+
+    let bytes = encode_track(&track, BitDepth::I32, Endianness::Big);
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(bytes[..4], 0x4000_0000i32.to_be_bytes());
+    assert_eq!(bytes[4..8], (-0x2000_0000i32).to_be_bytes());
+    assert_eq!(bytes[8..12], i32::MAX.to_be_bytes());
+    assert_eq!(bytes[12..], i32::MIN.to_be_bytes());
+
+    let loud = [Mono::from(x(2.0))];
+    assert_eq!(
+        encode_track(&loud, BitDepth::I16, Endianness::Little),
+        Mono::from(x(1.0)).into_i16_bytes(Endianness::Little)
+    );
+    assert_eq!(BitDepth::I24.bytes(), 3);
+    assert!(encode_track::<Stereo>(&[], BitDepth::F32, Endianness::Little).is_empty());
+}
+
+#[test]
+fn test_decode_track() {
+    let x = |v| Sample(v);
+    let track = vec![
+        Stereo::from(x(0.5), x(-0.25)),
+        Stereo::from(x(0.0), x(-1.0)),
+    ];
+
+    for e in [Endianness::Little, Endianness::Big] {
+        for depth in [BitDepth::I16, BitDepth::I24, BitDepth::I32, BitDepth::F32] {
+            let bytes = encode_track(&track, depth, e);
+            assert_eq!(decode_track::<Stereo>(&bytes, depth, e), Ok(track.clone()));
+        }
+    }
+
+    let decoded: Vec<Stereo> =
+        decode_track(&[128, 0, 192, 64], BitDepth::U8, Endianness::Little).unwrap();
+    assert_eq!(decoded[0], Stereo::from(x(0.0), x(-1.0)));
+    assert_eq!(decoded[1].left, x(0.5));
+
+    assert_eq!(
+        decode_track::<Stereo>(&[0; 6], BitDepth::I16, Endianness::Little),
+        Err(SampleFormatError::UnalignedLength {
+            channels: 4,
+            actual: 6
+        })
+    );
+    assert_eq!(
+        decode_track::<Mono>(&[], BitDepth::I24, Endianness::Big),
+        Ok(vec![])
+    );
+}
+
+#[test]
+fn test_tpdf_dither() {
+    let x = |v| Sample(v);
+    let mut dither = TpdfDither::new(1);
+
+    // A constant at a third of an LSB averages out to its value once dithered.
+    let lsb = 1.0 / 32768.0;
+    let mut track = vec![Mono::from(x(lsb / 3.0)); 30000];
+    dither.dither_track(&mut track, BitDepth::I16);
+    let mut sum = 0.0;
+    for s in track.iter() {
+        let v = s.into_sample().0 / lsb;
+        assert_eq!(v, v.round());
+        assert!(v.abs() <= 2.0);
+        sum += v as f64;
+    }
+    assert!((sum / 30000.0 - 1.0 / 3.0).abs() < 0.02);
+
+    let loud = dither.dither(Stereo::from(x(1.0), x(-1.0)), BitDepth::U8);
+    assert!(loud.left.0 <= 127.0 / 128.0 && loud.right.0 >= -1.0);
+    assert_eq!(
+        dither.dither(Mono::from(x(0.1)), BitDepth::F32),
+        Mono::from(x(0.1))
+    );
+
+    let track = vec![Stereo::from(x(0.25), x(-0.5)); 8];
+    let a = encode_track_dithered(
+        &track,
+        BitDepth::I24,
+        Endianness::Little,
+        &mut TpdfDither::new(7),
+    );
+    let b = encode_track_dithered(
+        &track,
+        BitDepth::I24,
+        Endianness::Little,
+        &mut TpdfDither::new(7),
+    );
+    assert_eq!(a, b);
+    assert_eq!(a.len(), 48);
+}
+
+#[test]
+fn test_encode_track_clipped() {
+    let x = |v| Sample(v);
+
+    assert_eq!(ClipMode::Hard.apply(x(-2.0)), x(-1.0));
+    assert_eq!(ClipMode::Hard.apply(x(0.5)), x(0.5));
+    assert!((ClipMode::Tanh.apply(x(0.01)).0 - 0.01).abs() < 1e-5);
+    assert!(ClipMode::Tanh.apply(x(100.0)).0 <= 1.0);
+    assert!((ClipMode::Cubic.apply(x(1.5)).0 - 1.0).abs() < 1e-6);
+    assert_eq!(
+        ClipMode::Cubic.apply(x(-3.0)),
+        ClipMode::Cubic.apply(x(-1.5))
+    );
+
+    let track = [Mono::from(x(4.0)), Mono::from(x(-4.0))];
+    for mode in [ClipMode::Hard, ClipMode::Tanh, ClipMode::Cubic] {
+        let bytes = encode_track_clipped(&track, BitDepth::I16, Endianness::Little, mode);
+        let values: Vec<Mono> = decode_track(&bytes, BitDepth::I16, Endianness::Little).unwrap();
+        assert!(values[0].into_sample().0 > 0.99);
+        assert!(values[1].into_sample().0 < -0.99);
+    }
+
+    let soft = encode_track_clipped(
+        &[Mono::from(x(0.9))],
+        BitDepth::I32,
+        Endianness::Big,
+        ClipMode::Tanh,
+    );
+    let expected = [Mono::from(ClipMode::Tanh.apply(x(0.9)))];
+    assert_eq!(
+        soft,
+        encode_track(&expected, BitDepth::I32, Endianness::Big)
+    );
+}
+
+#[test]
+fn test_lazy_interleaved() {
+    let x = |v| Sample(v);
+
+    let mut samples = samples_from_i16_interleaved::<Stereo>(&[16384, -16384, 0, 32767]).unwrap();
+    assert_eq!(samples.next(), Some(Stereo::from(x(0.5), x(-0.5))));
+    assert_eq!(samples.next().map(|s| s.left), Some(x(0.0)));
+    assert_eq!(samples.next(), None);
+
+    let v: Vec<Mono> = samples_from_i32_interleaved(&[4_194_304, -8_388_608])
+        .unwrap()
+        .collect();
+    assert_eq!(v, vec![Mono::from(x(0.5)), Mono::from(x(-1.0))]);
+
+    let v: Vec<Stereo> = samples_from_f32_interleaved(&[0.25, -0.75])
+        .unwrap()
+        .collect();
+    assert_eq!(v, vec![Stereo::from(x(0.25), x(-0.75))]);
+
+    let track = vec![Stereo::from(x(0.5), x(-0.25)); 3];
+    let bytes = encode_track(&track, BitDepth::I24, Endianness::Big);
+    let v: Vec<Stereo> = samples_from_bytes_interleaved(&bytes, BitDepth::I24, Endianness::Big)
+        .unwrap()
+        .collect();
+    assert_eq!(v, track);
+
+    assert_eq!(
+        samples_from_i16_interleaved::<Stereo>(&[0; 3]).err(),
+        Some(SampleFormatError::UnalignedLength {
+            channels: 2,
+            actual: 3
+        })
+    );
+    assert!(
+        samples_from_bytes_interleaved::<Stereo>(&bytes[1..], BitDepth::I24, Endianness::Big)
+            .is_err()
+    );
+}
@@ -0,0 +1,97 @@
+use bae_sf::*;
+use bae_types::Sample;
+use std::convert::TryFrom;
+
+#[test]
+fn test_wrong_length() {
+    let v: Vec<i16> = vec![0; 5];
+
+    assert_eq!(
+        Surround51::try_from(v),
+        Err(SampleFormatError::WrongLength {
+            expected: 6,
+            actual: 5
+        })
+    );
+    assert_eq!(
+        DynFormat::try_from(Vec::<u8>::new()),
+        Err(SampleFormatError::WrongLength {
+            expected: 1,
+            actual: 0
+        })
+    );
+}
+
+#[test]
+fn test_layout_errors() {
+    let stereo = ChannelLayout::from_format::<Stereo>();
+    let lcr = ChannelLayout::from_format::<Lcr>();
+    let mut data = vec![Sample(0.0); 3];
+
+    assert_eq!(
+        stereo.mapping(&lcr),
+        Err(SampleFormatError::ChannelCountMismatch { left: 2, right: 3 })
+    );
+    assert_eq!(
+        stereo.mapping(&ChannelLayout::from_format::<MidSide>()),
+        Err(SampleFormatError::MissingChannel(ChannelLabel::Mid))
+    );
+    assert_eq!(
+        reorder_interleaved(&stereo, &stereo, &mut data),
+        Err(SampleFormatError::UnalignedLength {
+            channels: 2,
+            actual: 3
+        })
+    );
+}
+
+#[test]
+fn test_display() {
+    let e: Box<dyn std::error::Error> = Box::new(SampleFormatError::WrongLength {
+        expected: 2,
+        actual: 0,
+    });
+
+    assert_eq!(
+        e.to_string(),
+        "Given data was length 0. This function requires length 2."
+    );
+}
+
+#[test]
+fn test_slice_conversions() {
+    let data: Vec<i16> = vec![0, 16384, -16384, 0, 32767, 0];
+
+    let frames: Vec<Stereo> = data
+        .chunks_exact(Stereo::CHANNEL_COUNT)
+        .map(|c| Stereo::try_from(c).unwrap())
+        .collect();
+    assert_eq!(frames.len(), 3);
+    assert_eq!(frames[1], Stereo::try_from(vec![-16384i16, 0]).unwrap());
+
+    assert_eq!(
+        Quad::try_from(&data[..3]),
+        Err(SampleFormatError::WrongLength {
+            expected: 4,
+            actual: 3
+        })
+    );
+    assert!(MultiChannel::<6>::try_from(&data[..]).is_ok());
+}
+
+#[test]
+fn test_float_conversions() {
+    let s = Stereo::try_from(vec![0.5f32, -1.5]).unwrap();
+    assert_eq!(s, Stereo::from(Sample(0.5), Sample(-1.5)));
+
+    let v: Vec<f64> = s.into();
+    assert_eq!(v, vec![0.5, -1.5]);
+
+    assert_eq!(
+        Surround51::try_from(&[0.0f64; 4][..]),
+        Err(SampleFormatError::WrongLength {
+            expected: 6,
+            actual: 4
+        })
+    );
+}
@@ -0,0 +1,40 @@
+use bae_sf::*;
+use bae_types::*;
+
+#[test]
+fn test_first_order_encoding() {
+    let h = Hoa1::to_sample_format(Sample(1.0), (0.0f64, 0.0f64));
+    assert!((h.channels[0].0 - 1.0).abs() < 1e-6);
+    assert!(h.channels[1].0.abs() < 1e-6);
+    assert!(h.channels[2].0.abs() < 1e-6);
+    assert!((h.channels[3].0 - 1.0).abs() < 1e-6);
+
+    // Positive azimuths are to the right, where Y is negative
+    let h = Hoa1::to_sample_format(Sample(1.0), (90.0f64, 0.0f64));
+    assert!((h.channels[1].0 + 1.0).abs() < 1e-6);
+
+    let h = Hoa1::to_sample_format(Sample(1.0), (0.0f64, 90.0f64));
+    assert!((h.channels[2].0 - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_second_order_sn3d() {
+    let az: f64 = 30.0;
+    let el: f64 = 20.0;
+    let h = Hoa2::to_sample_format(Sample(1.0), (az, el));
+
+    let a = -az.to_radians();
+    let e = el.to_radians();
+    let k = 3.0f64.sqrt() / 2.0;
+    let expected = [
+        (4, k * e.cos().powi(2) * (2.0 * a).sin()),
+        (5, k * (2.0 * e).sin() * a.sin()),
+        (6, 0.5 * (3.0 * e.sin().powi(2) - 1.0)),
+        (7, k * (2.0 * e).sin() * a.cos()),
+        (8, k * e.cos().powi(2) * (2.0 * a).cos()),
+    ];
+
+    for (acn, y) in expected.iter() {
+        assert!((h.channels[*acn].0 as f64 - y).abs() < 1e-5, "ACN {}", acn);
+    }
+}
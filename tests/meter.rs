@@ -0,0 +1,218 @@
+use bae_sf::*;
+use bae_types::{Math, Sample};
+
+#[test]
+fn test_peak() {
+    let x = |v| Sample(v);
+    let track = [
+        Stereo::from(x(0.25), x(-0.5)),
+        Stereo::from(x(-0.75), x(0.125)),
+    ];
+
+    assert_eq!(peak(&track), Stereo::from(x(0.75), x(0.5)));
+    assert_eq!(peak::<Stereo>(&[]), Stereo::SILENCE);
+}
+
+#[test]
+fn test_peak_meter() {
+    let x = |v| Sample(v);
+    // Holds for 2 samples then falls by 6 dB per sample.
+    let mut meter = PeakMeter::<Mono>::new(Math(1.0), Math(2.0), Math(6.020_599_913_279_624));
+
+    assert_eq!(meter.process(Mono::from(x(-0.8))), Mono::from(x(0.8)));
+    assert_eq!(meter.process(Mono::from(x(0.1))), Mono::from(x(0.8)));
+    assert_eq!(meter.process(Mono::from(x(0.1))), Mono::from(x(0.8)));
+    assert!(meter
+        .process(Mono::from(x(0.1)))
+        .approx_eq(&Mono::from(x(0.4)), 1e-6));
+    assert!(meter
+        .process(Mono::from(x(0.0)))
+        .approx_eq(&Mono::from(x(0.2)), 1e-6));
+    assert!(meter
+        .process(Mono::from(x(0.15)))
+        .approx_eq(&Mono::from(x(0.15)), 1e-6));
+    assert_eq!(meter.process(Mono::from(x(0.5))), Mono::from(x(0.5)));
+
+    meter.reset();
+    assert_eq!(meter.level(), Mono::SILENCE);
+
+    let mut meter = PeakMeter::<Stereo>::new(Math(48000.0), Math(0.0), Math(f64::INFINITY));
+    let level =
+        meter.process_track(&[Stereo::from(x(1.0), x(0.0)), Stereo::from(x(0.25), x(-0.5))]);
+    assert_eq!(level, Stereo::from(x(0.25), x(0.5)));
+}
+
+#[test]
+fn test_rms_meter() {
+    let x = |v| Sample(v);
+    let mut meter = RmsMeter::<Stereo>::new(Math(4.0), Math(0.5));
+
+    assert_eq!(meter.level(), Stereo::SILENCE);
+    assert!(meter
+        .process(Stereo::from(x(0.5), x(-1.0)))
+        .approx_eq(&Stereo::from(x(0.5), x(1.0)), 1e-6));
+    assert!(meter
+        .process(Stereo::from(x(-0.5), x(0.0)))
+        .approx_eq(&Stereo::from(x(0.5), x(0.5f32.sqrt())), 1e-6));
+    // The first sample leaves the 2 sample window.
+    assert!(meter
+        .process(Stereo::from(x(0.0), x(0.0)))
+        .approx_eq(&Stereo::from(x(0.5f32.sqrt() * 0.5), x(0.0)), 1e-6));
+
+    meter.reset();
+    let track = vec![Stereo::from(x(0.25), x(-0.25)); 10];
+    assert!(meter
+        .process_track(&track)
+        .approx_eq(&Stereo::from(x(0.25), x(0.25)), 1e-6));
+}
+
+#[test]
+fn test_loudness() {
+    let rate = 48000.0;
+    let sine = |n: usize, amplitude: f32| -> Vec<Mono> {
+        (0..n)
+            .map(|i| {
+                let t = i as f64 / rate;
+                Mono::from(Sample(
+                    amplitude * (2.0 * std::f64::consts::PI * 1000.0 * t).sin() as f32,
+                ))
+            })
+            .collect()
+    };
+
+    // A 1 kHz sine at 0 dBFS in a single front channel reads -3.01 LUFS.
+    let tone = sine(48000 * 4, 1.0);
+    let mut meter = LoudnessMeter::<Mono>::new(Math(rate));
+    assert_eq!(meter.momentary().0, f64::NEG_INFINITY);
+    meter.process_track(&tone);
+    assert!((meter.momentary().0 + 3.01).abs() < 0.05);
+    assert!((meter.short_term().0 + 3.01).abs() < 0.05);
+    assert!((meter.integrated().0 + 3.01).abs() < 0.05);
+
+    // The same tone in both surround channels is weighted by 1.41.
+    let surround: Vec<Surround51> = tone
+        .iter()
+        .map(|s| {
+            let mut out = Surround51::SILENCE;
+            out.surround_left = s.into_sample();
+            out.surround_right = s.into_sample();
+            out.lfe = Sample(1.0);
+            out
+        })
+        .collect();
+    let expected = -3.01 + 10.0 * (2.0 * 1.41f64).log10();
+    assert!((integrated_loudness(&surround, Math(rate)).0 - expected).abs() < 0.05);
+
+    // Quiet passages are gated out of the integrated loudness, leaving only the
+    // blocks overlapping the start of the tone to lower it slightly.
+    meter.reset();
+    meter.process_track(&sine(48000 * 4, 0.001));
+    meter.process_track(&tone);
+    assert!((meter.integrated().0 + 3.01).abs() < 0.2);
+
+    assert_eq!(
+        integrated_loudness(&vec![Stereo::SILENCE; 48000], Math(rate)).0,
+        f64::NEG_INFINITY
+    );
+}
+
+#[test]
+fn test_true_peak() {
+    // A sine at a quarter of the sample rate, sampled 45 degrees from its
+    // peaks, has a sample peak of 0.707 but a true peak of 1.
+    let track: Vec<Mono> = (0..1000)
+        .map(|i| {
+            let phase = std::f64::consts::FRAC_PI_2 * i as f64 + std::f64::consts::FRAC_PI_4;
+            Mono::from(Sample(phase.sin() as f32))
+        })
+        .collect();
+
+    assert!((peak(&track).into_sample().0 - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+    assert!((true_peak(&track).into_sample().0 - 1.0).abs() < 0.05);
+
+    let mut meter = TruePeakMeter::<Stereo>::new();
+    let level = meter.process_track(&[Stereo::from(Sample(0.5), Sample(-0.25))]);
+    assert!(level.left.0 >= 0.5 && level.right.0 >= 0.25);
+    assert!(meter
+        .flush()
+        .approx_eq(&Stereo::from(Sample(0.5), Sample(0.25)), 0.05));
+
+    meter.reset();
+    assert_eq!(meter.level(), Stereo::SILENCE);
+    assert_eq!(true_peak::<Stereo>(&[]), Stereo::SILENCE);
+}
+
+#[test]
+fn test_normalize() {
+    let x = |v| Sample(v);
+    let mut track: Track<Stereo> = vec![Stereo::from(x(0.25), x(-0.125)); 4].into();
+
+    let gain = normalize_peak(&mut track, Math(-6.020_599_913_279_624));
+    assert!((gain.0 - 6.020_599_913_279_624).abs() < 1e-6);
+    assert!(track[0].approx_eq(&Stereo::from(x(0.5), x(-0.25)), 1e-6));
+
+    let mut silent: Track<Stereo> = Track::silence(4);
+    assert_eq!(normalize_peak(&mut silent, Math(0.0)).0, 0.0);
+    assert_eq!(silent[0], Stereo::SILENCE);
+
+    let rate = 48000.0;
+    let mut tone: Track<Mono> = (0..48000 * 2)
+        .map(|i| {
+            let t = i as f64 / rate;
+            Mono::from(Sample(
+                0.1 * (2.0 * std::f64::consts::PI * 1000.0 * t).sin() as f32,
+            ))
+        })
+        .collect();
+    normalize_lufs(&mut tone, Math(-23.0), Math(rate));
+    assert!((integrated_loudness(&tone, Math(rate)).0 + 23.0).abs() < 1e-3);
+    assert_eq!(normalize_lufs(&mut silent, Math(-23.0), Math(rate)).0, 0.0);
+}
+
+#[test]
+fn test_correlation() {
+    let x = |v| Sample(v);
+    let tone: Vec<f32> = (0..4800).map(|i| (i as f32 * 0.1).sin()).collect();
+    let identical: StereoTrackT = tone.iter().map(|v| Stereo::from(x(*v), x(*v))).collect();
+    let inverted: StereoTrackT = tone.iter().map(|v| Stereo::from(x(*v), x(-*v))).collect();
+    let one_side: StereoTrackT = tone.iter().map(|v| Stereo::from(x(*v), x(0.0))).collect();
+
+    let mut meter = CorrelationMeter::new(Math(48000.0), Math(0.01));
+    assert_eq!(meter.correlation().0, 0.0);
+    assert!((meter.process_track(&identical).0 - 1.0).abs() < 1e-6);
+    assert!((meter.process_track(&inverted).0 + 1.0).abs() < 1e-3);
+
+    meter.reset();
+    assert_eq!(
+        meter.process_track(&one_side).0,
+        Stereo::correlation(&one_side).0
+    );
+
+    meter.reset();
+    assert_eq!(meter.process(Stereo::from(x(0.5), x(-0.5))).0, -1.0);
+}
+
+#[test]
+fn test_goniometer() {
+    let x = |v| Sample(v);
+    let track = [
+        Stereo::from(x(0.5), x(0.5)),
+        Stereo::from(x(0.5), x(0.0)),
+        Stereo::from(x(0.5), x(-0.5)),
+    ];
+
+    let ms: Vec<(Sample, Sample)> = goniometer_mid_side(&track).collect();
+    assert_eq!(
+        ms,
+        vec![(x(0.5), x(0.0)), (x(0.25), x(0.25)), (x(0.0), x(0.5))]
+    );
+
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    let points: Vec<(Sample, Sample)> = goniometer_lissajous(&track).collect();
+    let expected = [(0.0, h), (-0.5 * h, 0.5 * h), (-h, 0.0)];
+    for ((px, py), (ex, ey)) in points.iter().zip(expected.iter()) {
+        assert!((px.0 - ex).abs() < 1e-6 && (py.0 - ey).abs() < 1e-6);
+    }
+
+    assert_eq!(goniometer_lissajous(&track).skip(1).count(), 2);
+}
@@ -0,0 +1,317 @@
+use bae_sf::*;
+use bae_types::{Math, Sample};
+
+#[test]
+fn test_scalar_mul() {
+    let s = Stereo::from(Sample(0.5), Sample(-1.0));
+
+    assert_eq!(s * 0.5f32, Stereo::from(Sample(0.25), Sample(-0.5)));
+    assert_eq!(s * 0.5f64, s * 0.5f32);
+
+    let mut m = MultiChannel::<2>::from([Sample(1.0), Sample(2.0)]);
+    m *= 2.0f32;
+    m *= 0.25f64;
+    assert_eq!(m.channels, [Sample(0.5), Sample(1.0)]);
+
+    let d = DynFormat::from(vec![Sample(1.0)]) * 0.5f32;
+    assert_eq!(d.channels, vec![Sample(0.5)]);
+}
+
+#[test]
+fn test_sum_product() {
+    let track = [
+        Stereo::from(Sample(0.25), Sample(0.5)),
+        Stereo::from(Sample(0.5), Sample(-0.25)),
+    ];
+
+    assert_eq!(
+        track.iter().copied().sum::<Stereo>(),
+        Stereo::from(Sample(0.75), Sample(0.25))
+    );
+    assert_eq!(
+        track.iter().product::<Stereo>(),
+        Stereo::from(Sample(0.125), Sample(-0.125))
+    );
+    assert_eq!(Vec::<Quad>::new().into_iter().sum::<Quad>(), Quad::new());
+    assert_eq!(
+        Vec::<Lcr>::new().into_iter().product::<Lcr>(),
+        Lcr::from(Sample(1.0), Sample(1.0), Sample(1.0))
+    );
+
+    let d: DynFormat = vec![DynFormat::from(vec![Sample(1.0)]); 3].iter().sum();
+    assert_eq!(d.channels, vec![Sample(3.0)]);
+}
+
+#[test]
+fn test_approx_eq() {
+    let a = Stereo::from(Sample(0.1), Sample(0.2));
+    let b = Stereo::from(Sample(0.1 + 1e-7), Sample(0.2 - 1e-7));
+
+    assert!(a.approx_eq(&b, 1e-6));
+    assert!(!a.approx_eq(&(b * 2.0f32), 1e-6));
+
+    let d = DynFormat::from(vec![Sample(0.1)]);
+    assert!(d.approx_eq(&DynFormat::from(vec![Sample(0.1 + 1e-7)]), 1e-6));
+    assert!(!d.approx_eq(&DynFormat::from(vec![Sample(0.1); 2]), 1e-6));
+}
+
+#[test]
+fn test_array_conversions() {
+    let s: Stereo = [Sample(0.25), Sample(0.5)].into();
+    assert_eq!(s, Stereo::from(Sample(0.25), Sample(0.5)));
+
+    let a: [Sample; 2] = s.into();
+    assert_eq!(a, [Sample(0.25), Sample(0.5)]);
+
+    let q: Surround51 = [Sample(0.0); 6].into();
+    assert_eq!(q, Surround51::new());
+
+    let h: Hoa1 = [Sample(1.0); 4].into();
+    let a: [Sample; 4] = h.into();
+    assert_eq!(a, [Sample(1.0); 4]);
+
+    let d: DynFormat = [Sample(1.0); 3].into();
+    assert_eq!(d.num_samples(), 3);
+}
+
+#[test]
+fn test_as_slice() {
+    fn peak<F: SampleFormat>(f: &F) -> f32 {
+        f.as_ref().iter().fold(0.0, |m, s| s.0.abs().max(m))
+    }
+
+    let mut s = Surround51::from(
+        Sample(0.1),
+        Sample(0.2),
+        Sample(-0.9),
+        Sample(0.0),
+        Sample(0.3),
+        Sample(0.4),
+    );
+    assert_eq!(peak(&s), 0.9);
+    assert_eq!(s.as_ref()[4], s.surround_left);
+
+    s.as_mut()[3] = Sample(1.0);
+    assert_eq!(s.lfe, Sample(1.0));
+
+    let mut m = MultiChannel::<2>::new();
+    m.as_mut().copy_from_slice(&[Sample(0.5), Sample(0.25)]);
+    assert_eq!(peak(&m), 0.5);
+}
+
+#[test]
+fn test_map() {
+    let s = Stereo::from(Sample(0.5), Sample(-2.0)).map(|s| Sample(s.0.max(-1.0)));
+    assert_eq!(s, Stereo::from(Sample(0.5), Sample(-1.0)));
+
+    let d = DynFormat::from(vec![Sample(0.5); 2]).map(|s| Sample(s.0 * 2.0));
+    assert_eq!(d.channels, vec![Sample(1.0); 2]);
+}
+
+#[test]
+fn test_zip_with() {
+    let a = Stereo::from(Sample(0.5), Sample(-1.0));
+    let b = Stereo::from(Sample(0.25), Sample(0.5));
+    assert_eq!(
+        a.zip_with(b, |x, y| Sample(x.0.max(y.0))),
+        Stereo::from(Sample(0.5), Sample(0.5))
+    );
+
+    let d = DynFormat::from(vec![Sample(1.0)]);
+    assert_eq!(
+        d.clone()
+            .zip_with(d.clone(), |x, y| Sample(x.0 * y.0 + 1.0))
+            .channels,
+        vec![Sample(2.0)]
+    );
+    assert!(d.try_zip_with(DynFormat::new(2), |x, _| x).is_err());
+}
+
+#[test]
+fn test_abs_clamp_min_max() {
+    let s = Lcr::from(Sample(0.5), Sample(-2.0), Sample(1.5));
+
+    assert_eq!(s.abs(), Lcr::from(Sample(0.5), Sample(2.0), Sample(1.5)));
+    assert_eq!(
+        s.clamp(Sample(-1.0), Sample(1.0)),
+        Lcr::from(Sample(0.5), Sample(-1.0), Sample(1.0))
+    );
+    assert_eq!(s.channel_min(), Sample(-2.0));
+    assert_eq!(s.channel_max(), Sample(1.5));
+    assert_eq!(s.abs().channel_max(), Sample(2.0));
+
+    let d = DynFormat::from(vec![Sample(-0.5), Sample(0.25)]);
+    assert_eq!(d.channel_min(), Sample(-0.5));
+    assert_eq!(d.abs().channel_max(), Sample(0.5));
+}
+
+#[test]
+fn test_silent_finite() {
+    assert!(Quad::new().is_silent());
+
+    let s = Stereo::from(Sample(1e-5), Sample(-1e-5));
+    assert!(!s.is_silent());
+    assert!(s.is_silent_within(Sample(1e-4)));
+    assert!(s.is_finite());
+
+    assert!(!Stereo::from(Sample(0.0), Sample(f32::NAN)).is_finite());
+    assert!(!DynFormat::from(vec![Sample(f32::INFINITY)]).is_finite());
+    assert!(DynFormat::new(4).is_silent());
+}
+
+#[test]
+fn test_lerp() {
+    let a = Stereo::from(Sample(0.0), Sample(1.0));
+    let b = Stereo::from(Sample(1.0), Sample(-1.0));
+
+    assert_eq!(a.lerp(b, Math(0.0)), a);
+    assert_eq!(a.lerp(b, Math(1.0)), b);
+    assert_eq!(
+        a.lerp(b, Math(0.25)),
+        Stereo::from(Sample(0.25), Sample(0.5))
+    );
+
+    let d = DynFormat::from(vec![Sample(0.0)]).lerp(DynFormat::from(vec![Sample(2.0)]), Math(0.5));
+    assert_eq!(d.channels, vec![Sample(1.0)]);
+}
+
+#[test]
+fn test_gain_array() {
+    let s = Stereo::from(Sample(0.5), Sample(0.5)) * [Sample(1.0), Sample(0.5)];
+    assert_eq!(s, Stereo::from(Sample(0.5), Sample(0.25)));
+
+    let mut q = Surround51::from_sample(Sample(1.0));
+    q *= [Sample(0.0); 6];
+    assert!(q.is_silent());
+
+    let d = DynFormat::from(vec![Sample(1.0); 2]) * [Sample(0.5), Sample(2.0)];
+    assert_eq!(d.channels, vec![Sample(0.5), Sample(2.0)]);
+}
+
+#[test]
+#[should_panic]
+fn test_gain_array_mismatch() {
+    let _ = DynFormat::new(3) * [Sample(1.0); 2];
+}
+
+#[test]
+fn test_splat() {
+    assert_eq!(
+        Stereo::splat(Sample(0.5)),
+        Stereo::from(Sample(0.5), Sample(0.5))
+    );
+    assert_ne!(Stereo::splat(Sample(0.5)), Stereo::from_sample(Sample(0.5)));
+    assert_eq!(
+        MultiChannel::<3>::splat(Sample(1.0)).channels,
+        [Sample(1.0); 3]
+    );
+    assert_eq!(
+        DynFormat::splat(Sample(1.0), 2).channels,
+        vec![Sample(1.0); 2]
+    );
+}
+
+#[test]
+fn test_dyn_format_into_sample() {
+    let d = DynFormat::from_sample(Sample(0.5), 4);
+    assert!((d.into_sample().0 - 0.5).abs() < 1e-6);
+    assert_eq!(DynFormat::new(0).into_sample(), Sample(0.0));
+    assert_eq!(DynFormat::splat(Sample(1.0), 0).into_sample(), Sample(0.0));
+}
+
+#[test]
+fn test_stereo_swapped_balance() {
+    let s = Stereo::from(Sample(0.5), Sample(-1.0));
+    assert_eq!(s.swapped(), Stereo::from(Sample(-1.0), Sample(0.5)));
+    assert_eq!(s.swapped().swapped(), s);
+
+    assert_eq!(s.balance(0.0), s);
+    assert_eq!(s.balance(-0.5), Stereo::from(Sample(0.5), Sample(-0.5)));
+    assert_eq!(s.balance(0.5), Stereo::from(Sample(0.25), Sample(-1.0)));
+    assert_eq!(s.balance(2.0), Stereo::from(Sample(0.0), Sample(-1.0)));
+    assert_eq!(s.balance(-1.0).right, Sample(0.0));
+}
+
+#[test]
+fn test_stereo_width_correlation() {
+    assert_eq!(Stereo::from(Sample(0.5), Sample(0.5)).width().0, 0.0);
+    assert_eq!(Stereo::from(Sample(0.5), Sample(0.0)).width().0, 1.0);
+    assert_eq!(Stereo::new().width().0, 0.0);
+    assert!(Stereo::from(Sample(0.5), Sample(-0.5)).width().0.is_infinite());
+
+    let mono = [
+        Stereo::from(Sample(0.5), Sample(0.5)),
+        Stereo::from(Sample(-0.25), Sample(-0.25)),
+    ];
+    let inverted: Vec<Stereo> = mono
+        .iter()
+        .map(|s| Stereo::from(s.left, Sample(-s.right.0)))
+        .collect();
+    let one_sided = [Stereo::from(Sample(0.5), Sample(0.0))];
+
+    assert!((Stereo::correlation(&mono).0 - 1.0).abs() < 1e-9);
+    assert!((Stereo::correlation(&inverted).0 + 1.0).abs() < 1e-9);
+    assert_eq!(Stereo::correlation(&one_sided).0, 0.0);
+    assert_eq!(Stereo::correlation(&[]).0, 0.0);
+}
+
+#[test]
+fn test_silence_unity() {
+    const BUFFER: [Stereo; 4] = [Stereo::SILENCE; 4];
+    assert!(BUFFER.iter().all(|s| s.is_silent()));
+    assert_eq!(Stereo::SILENCE, Stereo::default());
+    assert_eq!(Surround51::SILENCE, Surround51::new());
+    assert_eq!(Hoa1::SILENCE, Hoa1::new());
+
+    let s = Surround71::from_sample(Sample(0.5));
+    assert_eq!(s * Surround71::UNITY, s);
+    assert_eq!(Quad::UNITY, Quad::splat(Sample(1.0)));
+    assert_eq!(MultiChannel::<3>::UNITY.channels, [Sample(1.0); 3]);
+}
+
+#[test]
+fn test_dbfs() {
+    let s = Stereo::from(Sample(0.5), Sample(-1.0));
+    let db = s.to_dbfs();
+    assert!((db[0].0 + 6.0206).abs() < 1e-3);
+    assert!(db[1].0.abs() < 1e-9);
+    assert_eq!(Stereo::new().to_dbfs()[0].0, f64::NEG_INFINITY);
+
+    let s = Stereo::from_dbfs([Math(0.0), Math(-6.0206)]);
+    assert!(s.approx_eq(&Stereo::from(Sample(1.0), Sample(0.5)), 1e-4));
+    assert_eq!(Mono::from_dbfs([Math(f64::NEG_INFINITY)]), Mono::new());
+
+    let d = DynFormat::from_dbfs(vec![Math(0.0); 3]);
+    assert_eq!(d.channels, vec![Sample(1.0); 3]);
+    assert!(d.to_dbfs().iter().all(|l| l.0 == 0.0));
+}
+
+#[test]
+fn test_tuple_conversions() {
+    let s: Stereo = (Sample(0.5), Sample(-0.5)).into();
+    assert_eq!(s, Stereo::from(Sample(0.5), Sample(-0.5)));
+
+    let (l, r): (Sample, Sample) = s.into();
+    assert_eq!((l, r), (Sample(0.5), Sample(-0.5)));
+
+    let q: Lcr = (Sample(1.0), Sample(2.0), Sample(3.0)).into();
+    assert_eq!(q.center, Sample(2.0));
+
+    let s = Surround71::from_sample(Sample(0.25));
+    let (fl, _, _, _, _, _, _, rr) = s.into();
+    assert_eq!((fl, rr), (s.front_left, s.rear_right));
+}
+
+#[test]
+fn test_mid_side_functions() {
+    let s = Stereo::from(Sample(0.75), Sample(0.25));
+    let (m, side) = to_mid_side(s);
+    assert_eq!((m, side), (Sample(0.5), Sample(0.25)));
+    assert_eq!(from_mid_side(m, side), s);
+
+    let track = vec![s, Stereo::from(Sample(-0.5), Sample(0.5))];
+    let encoded = to_mid_side_track(&track);
+    assert_eq!(encoded[0], MidSide::from(Sample(0.5), Sample(0.25)));
+    assert_eq!(encoded[1], MidSide::from(Sample(0.0), Sample(-0.5)));
+    assert_eq!(from_mid_side_track(&encoded), track);
+}
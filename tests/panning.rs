@@ -0,0 +1,544 @@
+use bae_sf::*;
+use bae_types::{Math, Sample};
+use std::f32::consts::FRAC_1_SQRT_2;
+
+fn db(s: Sample) -> f64 {
+    20.0 * (s.0 as f64).log10()
+}
+
+#[test]
+fn test_stereo_pan() {
+    let c = Stereo::to_sample_format(Sample(1.0), 0.0f32);
+    assert!((db(c.left) + 3.0).abs() < 1e-4);
+    assert!((db(c.right) + 3.0).abs() < 1e-4);
+
+    let l = Stereo::to_sample_format(Sample(1.0), -1.0f64);
+    assert!((l.left.0 - 1.0).abs() < 1e-6);
+    assert!(l.right.0 < 1e-5);
+
+    let r = Stereo::to_sample_format(Sample(1.0), 2.0f32);
+    assert!((r.right.0 - 1.0).abs() < 1e-6);
+    assert!(r.left.0 < 1e-5);
+
+    let h = Stereo::to_sample_format(Sample(1.0), 0.5f64);
+    assert!((db(h.right) + 1.5).abs() < 1e-4);
+    assert!((db(h.left) + 61.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_stereo_pan_constant_power() {
+    let law = PanLaw::ConstantPower3dB;
+
+    let c = Stereo::to_sample_format(Sample(1.0), (0.0f32, law));
+    assert!((c.left.0 - FRAC_1_SQRT_2).abs() < 1e-6);
+    assert!((c.right.0 - FRAC_1_SQRT_2).abs() < 1e-6);
+
+    let l = Stereo::to_sample_format(Sample(1.0), (-1.0f64, law));
+    assert!((l.left.0 - 1.0).abs() < 1e-6);
+    assert!(l.right.0 < 1e-5);
+
+    // cos and sin of 67.5 and 22.5 degrees
+    let h = Stereo::to_sample_format(Sample(1.0), (0.5f64, law));
+    assert!((h.left.0 - 0.382_683).abs() < 1e-6);
+    assert!((h.right.0 - 0.923_880).abs() < 1e-6);
+
+    let q = Stereo::to_sample_format(Sample(1.0), (-0.5f32, law));
+    assert!((q.left.0 - 0.923_880).abs() < 1e-6);
+    assert!((q.right.0 - 0.382_683).abs() < 1e-6);
+
+    for g in [-0.9f64, -0.25, 0.1, 0.6].iter() {
+        let s = Stereo::to_sample_format(Sample(1.0), (*g, law));
+        assert!((s.left.0.powi(2) + s.right.0.powi(2) - 1.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_stereo_pan_law() {
+    for g in [-1.0f32, -0.3, 0.0, 0.7, 1.0].iter() {
+        assert_eq!(
+            Stereo::to_sample_format(Sample(0.5), *g),
+            Stereo::to_sample_format(Sample(0.5), (*g, PanLaw::DecibelTaper3dB))
+        );
+    }
+    assert_eq!(PanLaw::default(), PanLaw::DecibelTaper3dB);
+}
+
+#[test]
+fn test_lcr_pan() {
+    let l = Lcr::to_sample_format(Sample(1.0), -1.0f32);
+    assert!((l.left.0 - 1.0).abs() < 1e-6);
+    assert!(l.center.0 < 1e-5);
+
+    let c = Lcr::to_sample_format(Sample(1.0), 0.0f32);
+    assert!((c.center.0 - 1.0).abs() < 1e-6);
+    assert!(c.left.0 < 1e-5 && c.right.0 < 1e-5);
+}
+
+#[test]
+fn test_stereo_pan_linear() {
+    let c = Stereo::to_sample_format(Sample(1.0), (0.0f32, PanLaw::Linear));
+    assert_eq!(c, Stereo::from(Sample(0.5), Sample(0.5)));
+
+    let s = Stereo::to_sample_format(Sample(1.0), (0.5f64, PanLaw::Linear));
+    assert_eq!(s, Stereo::from(Sample(0.25), Sample(0.75)));
+
+    let l = Stereo::to_sample_format(Sample(1.0), (-3.0f32, PanLaw::Linear));
+    assert_eq!(l, Stereo::from(Sample(1.0), Sample(0.0)));
+
+    for g in [-1.0f64, -0.6, 0.1, 0.9].iter() {
+        let s = Stereo::to_sample_format(Sample(1.0), (*g, PanLaw::Linear));
+        assert!((s.left.0 + s.right.0 - 1.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_stereo_pan_center_laws() {
+    for (law, center) in [
+        (PanLaw::Compromise4_5dB, -4.5154),
+        (PanLaw::Linear, -6.0206),
+    ]
+    .iter()
+    {
+        let c = Stereo::to_sample_format(Sample(1.0), (0.0f64, *law));
+        assert!((db(c.left) - center).abs() < 1e-4);
+        assert!((db(c.right) - center).abs() < 1e-4);
+
+        let r = Stereo::to_sample_format(Sample(1.0), (1.0f32, *law));
+        assert!((r.right.0 - 1.0).abs() < 1e-6);
+        assert!(r.left.0 < 1e-5);
+    }
+
+    // sqrt of the products of the linear and sin/cos gains at 0.5
+    let h = Stereo::to_sample_format(Sample(1.0), (0.5f64, PanLaw::Compromise4_5dB));
+    assert!((h.left.0 - 0.309_307).abs() < 1e-6);
+    assert!((h.right.0 - 0.832_412).abs() < 1e-6);
+
+    let q = Stereo::to_sample_format(Sample(1.0), (-0.5f32, PanLaw::Compromise4_5dB));
+    assert!((q.left.0 - 0.832_412).abs() < 1e-6);
+    assert!((q.right.0 - 0.309_307).abs() < 1e-6);
+}
+
+#[test]
+fn test_surround_pan() {
+    let c = Surround51::to_sample_format(Sample(1.0), SphericalPosition::horizontal(0.0));
+    assert!((c.center.0 - 1.0).abs() < 1e-6);
+    assert_eq!(c.lfe, Sample(0.0));
+
+    let s = Surround51::to_sample_format(Sample(1.0), SphericalPosition::new(-110.0, 30.0));
+    assert!((s.surround_left.0 - 1.0).abs() < 1e-6);
+
+    let h = Surround51::to_sample_format(Sample(1.0), SphericalPosition::horizontal(15.0));
+    assert!((h.center.0 - h.front_right.0).abs() < 1e-6);
+    assert!((h.center.0.powi(2) + h.front_right.0.powi(2) - 1.0).abs() < 1e-5);
+
+    let r = Surround71::to_sample_format(Sample(1.0), SphericalPosition::horizontal(180.0));
+    assert!((r.rear_left.0 - r.rear_right.0).abs() < 1e-6);
+    assert!((r.rear_left.0 - FRAC_1_SQRT_2).abs() < 1e-6);
+    assert_eq!(r.front_left, Sample(0.0));
+}
+
+#[test]
+fn test_spherical_position_panners() {
+    let p = SphericalPosition::new(30.0, 20.0);
+
+    assert_eq!(
+        Surround714::to_sample_format(Sample(1.0), p),
+        Surround714::to_sample_format(Sample(1.0), (30.0f64, 20.0f64))
+    );
+    assert_eq!(
+        AmbisonicB::to_sample_format(Sample(1.0), p),
+        AmbisonicB::to_sample_format(Sample(1.0), (30.0f32, 20.0f32))
+    );
+    assert_eq!(SphericalPosition::from((30.0f32, 20.0f32)), p);
+}
+
+#[test]
+fn test_vbap() {
+    let rig = SpeakerArray::new([-45.0, 45.0, 135.0, -135.0, 0.0]);
+
+    let g = rig.gains(0.0);
+    assert_eq!(g, [0.0, 0.0, 0.0, 0.0, 1.0]);
+
+    let g = rig.gains(22.5);
+    assert!((g[4] - g[1]).abs() < 1e-9);
+    assert!((g[4] * g[4] + g[1] * g[1] - 1.0).abs() < 1e-9);
+
+    let g = rig.gains(10.0);
+    assert!(g[4] > g[1] && g[1] > 0.0);
+    assert_eq!((g[0], g[2], g[3]), (0.0, 0.0, 0.0));
+    // The combined direction of the speaker pair points at the source
+    let x = g[1] * 45f64.to_radians().sin();
+    let y = g[4] + g[1] * 45f64.to_radians().cos();
+    assert!((x.atan2(y).to_degrees() - 10.0).abs() < 1e-9);
+
+    let m = rig.pan(Sample(0.5), 180.0);
+    assert!((m.channels[2].0 - m.channels[3].0).abs() < 1e-6);
+    assert!((m.channels[2].0 - 0.5 * FRAC_1_SQRT_2).abs() < 1e-6);
+}
+
+#[test]
+fn test_vbap_wide_gap() {
+    let rig = SpeakerArray::new([-30.0, 30.0]);
+
+    let g = rig.gains(180.0);
+    assert!((g[0] - g[1]).abs() < 1e-9);
+    assert!((g[0] * g[0] + g[1] * g[1] - 1.0).abs() < 1e-9);
+
+    let g = rig.gains(15.0);
+    assert!(g[1] > g[0] && g[0] > 0.0);
+
+    assert_eq!(SpeakerArray::new([90.0]).gains(-90.0), [1.0]);
+    assert_eq!(
+        SpeakerArray::<0>::new([]).pan(Sample(1.0), 0.0).channels,
+        []
+    );
+}
+
+#[test]
+fn test_lfe_send() {
+    let p = SphericalPosition::horizontal(-30.0);
+
+    let s = Surround51::to_sample_format(Sample(0.5), (p, LfeSend(0.5)));
+    assert_eq!(s.lfe, Sample(0.25));
+    assert_eq!(
+        s.front_left,
+        Surround51::to_sample_format(Sample(0.5), p).front_left
+    );
+
+    let s = Surround71::to_sample_format(Sample(0.5), (p, LfeSend::NONE));
+    assert_eq!(s, Surround71::to_sample_format(Sample(0.5), p));
+
+    let s = Surround714::to_sample_format(Sample(1.0), (p, LfeSend::from_db(Math(-6.0))));
+    assert!((s.lfe.0 - 0.501187).abs() < 1e-5);
+
+    let s = Surround21::to_sample_format(Sample(1.0), (0.0f32, LfeSend::UNITY));
+    assert_eq!(s, Surround21::to_sample_format(Sample(1.0), 0.0f32));
+    let s = Surround21::to_sample_format(Sample(1.0), (0.0f64, LfeSend::NONE));
+    assert_eq!(s.lfe, Sample(0.0));
+}
+
+#[test]
+fn test_panner_ramp() {
+    let mut ramp = PannerRamp::<Stereo, (f32, PanLaw)>::new((-1.0, PanLaw::Linear), 4);
+    assert!(!ramp.is_ramping());
+    assert_eq!(
+        ramp.process(Sample(1.0), (-1.0, PanLaw::Linear)),
+        Stereo::from(Sample(1.0), Sample(0.0))
+    );
+
+    let out: Vec<Stereo> = (0..6)
+        .map(|_| ramp.process(Sample(1.0), (1.0, PanLaw::Linear)))
+        .collect();
+    let right: Vec<f32> = out.iter().map(|s| s.right.0).collect();
+    assert_eq!(right, vec![0.0, 0.25, 0.5, 0.75, 1.0, 1.0]);
+    assert!(!ramp.is_ramping());
+
+    ramp.process(Sample(1.0), (-1.0, PanLaw::Linear));
+    ramp.process(Sample(1.0), (-1.0, PanLaw::Linear));
+    assert!(ramp.is_ramping());
+    assert_eq!(ramp.gains(), Stereo::from(Sample(0.5), Sample(0.5)));
+
+    // Changing direction mid-ramp continues from the current gains
+    ramp.process(Sample(1.0), (0.0, PanLaw::Linear));
+    assert_eq!(ramp.gains(), Stereo::from(Sample(0.5), Sample(0.5)));
+
+    ramp.reset((1.0, PanLaw::Linear));
+    assert!(!ramp.is_ramping());
+    assert_eq!(ramp.gains(), Stereo::from(Sample(0.0), Sample(1.0)));
+}
+
+#[test]
+fn test_panner_ramp_immediate() {
+    let mut ramp =
+        PannerRamp::<Surround51, SphericalPosition>::new(SphericalPosition::default(), 0);
+    let p = SphericalPosition::horizontal(110.0);
+
+    assert_eq!(
+        ramp.process(Sample(0.5), p),
+        Surround51::to_sample_format(Sample(0.5), p)
+    );
+}
+
+#[test]
+fn test_pan_gains() {
+    let gains = Stereo::pan_gains((0.5f32, PanLaw::Linear));
+    assert_eq!(gains, Stereo::from(Sample(0.25), Sample(0.75)));
+
+    let p = SphericalPosition::horizontal(70.0);
+    let gains = Surround71::pan_gains(p);
+    for s in [Sample(0.5), Sample(-0.25)].iter() {
+        assert_eq!(gains * *s, Surround71::to_sample_format(*s, p));
+    }
+
+    assert_eq!(Mono::pan_gains(0.3f32), Mono::from(Sample(1.0)));
+}
+
+#[test]
+fn test_rolloff() {
+    let inv = Rolloff::InverseSquare { reference: 1.0 };
+    assert_eq!(inv.gain(0.5), 1.0);
+    assert_eq!(inv.gain(2.0), 0.5);
+    assert_eq!(inv.gain(4.0), 0.25);
+
+    let slow = Rolloff::Inverse {
+        reference: 1.0,
+        factor: 0.5,
+    };
+    assert!((slow.gain(3.0) - 0.5).abs() < 1e-12);
+
+    let lin = Rolloff::Linear {
+        reference: 1.0,
+        max: 3.0,
+    };
+    assert_eq!(lin.gain(2.0), 0.5);
+    assert_eq!(lin.gain(10.0), 0.0);
+    assert_eq!(Rolloff::None.gain(100.0), 1.0);
+}
+
+#[test]
+fn test_spatial_source() {
+    let p = SphericalPosition::horizontal(-30.0);
+    let mut src = SpatialSource::new(p);
+
+    assert_eq!(
+        Surround51::to_sample_format(Sample(1.0), src),
+        Surround51::to_sample_format(Sample(1.0), p)
+    );
+
+    src.distance = 2.0;
+    let s = Surround51::to_sample_format(Sample(1.0), src);
+    assert!((s.front_left.0 - 0.5).abs() < 1e-6);
+
+    src.distance = 1.0;
+    src.spread = 90.0;
+    let s = Surround71::to_sample_format(Sample(1.0), src);
+    let power: f32 = s.as_ref().iter().map(|c| c.0 * c.0).sum();
+    assert!((power - 1.0).abs() < 1e-5);
+    assert!(s.center.0 > 0.0 && s.side_left.0 > 0.0);
+    assert!(s.front_left.0 > s.side_left.0);
+
+    src.spread = 360.0;
+    let s = Surround714::to_sample_format(Sample(1.0), src);
+    assert!(s.rear_right.0 > 0.0 && s.front_right.0 > 0.0);
+}
+
+#[test]
+fn test_balancer() {
+    let s = Stereo::from(Sample(0.5), Sample(-1.0));
+    for b in [-1.0, -0.25, 0.0, 0.6, 3.0].iter() {
+        assert_eq!(s.apply_balance(*b), s.balance(*b));
+    }
+
+    let q = Surround51::splat(Sample(1.0)).apply_balance(0.5);
+    assert_eq!(q.front_left, Sample(0.5));
+    assert_eq!(q.surround_left, Sample(0.5));
+    assert_eq!(q.front_right, Sample(1.0));
+    assert_eq!(q.center, Sample(1.0));
+    assert_eq!(q.lfe, Sample(1.0));
+
+    let l = Lcr::splat(Sample(1.0)).apply_balance(-1.0);
+    assert_eq!(l, Lcr::from(Sample(1.0), Sample(1.0), Sample(0.0)));
+}
+
+#[test]
+fn test_center_divergence() {
+    let c = Lcr::to_sample_format(Sample(1.0), (0.0f32, CenterDivergence(0.0)));
+    assert_eq!(c, Lcr::to_sample_format(Sample(1.0), 0.0f32));
+
+    let c = Lcr::to_sample_format(Sample(1.0), (0.0f64, CenterDivergence(1.0)));
+    assert!(c.center.0.abs() < 1e-6);
+    assert!((c.left.0 - FRAC_1_SQRT_2).abs() < 1e-5);
+    assert!((c.right.0 - FRAC_1_SQRT_2).abs() < 1e-5);
+
+    let p = SphericalPosition::horizontal(0.0);
+    let s = Surround51::to_sample_format(Sample(1.0), (p, CenterDivergence(0.5)));
+    let power = s.front_left.0.powi(2) + s.center.0.powi(2) + s.front_right.0.powi(2);
+    assert!((power - 1.0).abs() < 1e-5);
+    assert!(s.center.0 > s.front_left.0);
+    assert_eq!(s.front_left, s.front_right);
+
+    let s = Surround71::to_sample_format(Sample(1.0), (p, CenterDivergence(2.0)));
+    assert!(s.center.0.abs() < 1e-6);
+}
+
+#[test]
+fn test_stereo_panner() {
+    let s = Stereo::from(Sample(0.5), Sample(0.25));
+    let front = StereoSource::new(SphericalPosition::horizontal(0.0));
+
+    let q = Surround51::pan_stereo(s, front);
+    assert!((q.front_left.0 - 0.5).abs() < 1e-6);
+    assert!((q.front_right.0 - 0.25).abs() < 1e-6);
+    assert!(q.center.0.abs() < 1e-6);
+
+    let mut rear = StereoSource::new(SphericalPosition::horizontal(180.0));
+    rear.width = 90.0;
+    let q = Surround71::pan_stereo(s, rear);
+    assert!((q.rear_right.0 - 0.5).abs() < 1e-6);
+    assert!((q.rear_left.0 - 0.25).abs() < 1e-6);
+
+    let mut point = front;
+    point.width = 0.0;
+    assert_eq!(
+        Surround714::pan_stereo(s, point),
+        Surround714::to_sample_format(Sample(0.75), SphericalPosition::horizontal(0.0))
+    );
+}
+
+#[test]
+fn test_pan_automation() {
+    let mut a = PanAutomation::new(AutomationCurve::Linear);
+    assert_eq!(a.value_at(10), 0.0);
+
+    a.add_point(10, 1.0);
+    a.add_point(2, -1.0);
+    a.add_point(6, 0.5);
+    a.add_point(6, 0.0);
+    assert_eq!(a.points(), &[(2, -1.0), (6, 0.0), (10, 1.0)]);
+
+    assert_eq!(a.value_at(0), -1.0);
+    assert_eq!(a.value_at(4), -0.5);
+    assert_eq!(a.value_at(8), 0.5);
+    assert_eq!(a.value_at(100), 1.0);
+
+    let mut c = PanAutomation::new(AutomationCurve::SCurve);
+    c.add_point(0, 0.0);
+    c.add_point(4, 1.0);
+    assert!(c.value_at(1) < 0.25);
+    assert!((c.value_at(2) - 0.5).abs() < 1e-12);
+    assert!(c.value_at(3) > 0.75);
+
+    let mut d = PanAutomation::new(AutomationCurve::Step);
+    d.add_point(0, -1.0);
+    d.add_point(2, 1.0);
+    let out: Vec<Stereo> = d.apply(&[Sample(1.0); 3]);
+    assert_eq!(out[0], Stereo::to_sample_format(Sample(1.0), -1.0f64));
+    assert_eq!(out[1], out[0]);
+    assert_eq!(out[2], Stereo::to_sample_format(Sample(1.0), 1.0f64));
+}
+
+#[test]
+fn test_quad_pairwise_pan() {
+    let front = Quad::to_sample_format(Sample(1.0), SphericalPosition::horizontal(0.0));
+    assert!((front.front_left.0 - FRAC_1_SQRT_2).abs() < 1e-6);
+    assert!((front.front_right.0 - FRAC_1_SQRT_2).abs() < 1e-6);
+    assert_eq!(front.rear_left, Sample(0.0));
+
+    let right = Quad::to_sample_format(Sample(1.0), SphericalPosition::horizontal(90.0));
+    assert!((right.front_right.0 - right.rear_right.0).abs() < 1e-6);
+    assert_eq!(right.front_left, Sample(0.0));
+
+    let corner = Quad::to_sample_format(Sample(1.0), SphericalPosition::horizontal(-135.0));
+    assert_eq!(
+        corner,
+        Quad::from(Sample(0.0), Sample(0.0), Sample(1.0), Sample(0.0))
+    );
+
+    for az in [-170.0, -60.0, 20.0, 100.0].iter() {
+        let q = Quad::to_sample_format(Sample(1.0), SphericalPosition::horizontal(*az));
+        let power: f32 = q.as_ref().iter().map(|c| c.0 * c.0).sum();
+        assert!((power - 1.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_pan_with_gain() {
+    let s = Stereo::pan_with_gain(Sample(1.0), (0.5f32, PanLaw::Linear), Math(-6.0206));
+    assert!(s.approx_eq(&Stereo::from(Sample(0.125), Sample(0.375)), 1e-5));
+
+    let p = SphericalPosition::horizontal(30.0);
+    assert_eq!(
+        Surround51::pan_with_gain(Sample(0.5), p, Math(0.0)),
+        Surround51::to_sample_format(Sample(0.5), p)
+    );
+    assert!(Mono::pan_with_gain(Sample(1.0), 0.0f32, Math(f64::NEG_INFINITY)).is_silent());
+}
+
+#[test]
+fn test_mono_pan_attenuated() {
+    let m = Mono::pan_attenuated::<Stereo, _>(Sample(1.0), 0.0f32);
+    assert!((m.mono.0 - 1.0).abs() < 2e-3);
+
+    let m = Mono::pan_attenuated::<Stereo, _>(Sample(1.0), (0.0f32, PanLaw::Linear));
+    assert!((m.mono.0 - FRAC_1_SQRT_2).abs() < 1e-6);
+
+    let m = Mono::pan_attenuated::<Stereo, _>(Sample(0.5), (1.0f64, PanLaw::Linear));
+    assert_eq!(m, Mono::from(Sample(0.5)));
+
+    assert_eq!(
+        Mono::to_sample_format(Sample(1.0), (0.0f32, PanLaw::Linear)),
+        Mono::from(Sample(1.0))
+    );
+}
+
+#[test]
+fn test_dyn_panner() {
+    let panners: Vec<Box<dyn DynPanner<Stereo>>> = vec![
+        Box::new(0.0f32),
+        Box::new((0.0f64, PanLaw::Linear)),
+        Box::new(-1.0f64),
+    ];
+
+    let s = Sample(0.5);
+    assert_eq!(panners[0].pan(s), Stereo::to_sample_format(s, 0.0f32));
+    assert_eq!(
+        panners[1].pan(s),
+        Stereo::to_sample_format(s, (0.0f64, PanLaw::Linear))
+    );
+    assert_eq!(
+        panners[1].gains(),
+        Stereo::pan_gains((0.0f64, PanLaw::Linear))
+    );
+    assert_eq!(panners[2].gains(), Stereo::pan_gains(-1.0f64));
+
+    let surround: Box<dyn DynPanner<Surround51>> = Box::new(SphericalPosition::horizontal(30.0));
+    assert_eq!(
+        surround.pan(s),
+        Surround51::to_sample_format(s, SphericalPosition::horizontal(30.0))
+    );
+}
+
+#[test]
+fn test_spatialize() {
+    let listener = Listener::new(Vector3::new(1.0, 1.0, 0.0));
+    let mut emitter = Emitter::new(Vector3::new(3.0, 1.0, 0.0));
+
+    let (position, distance) = listener.locate(emitter.position);
+    assert!((position.azimuth - 90.0).abs() < 1e-9);
+    assert!(position.elevation.abs() < 1e-9);
+    assert!((distance - 2.0).abs() < 1e-9);
+
+    let s: Stereo = listener.spatialize(Sample(1.0), &emitter);
+    assert!(s.left.0.abs() < 1e-5);
+    assert!((s.right.0 - 0.5).abs() < 1e-6);
+
+    let s: Surround51 = listener.spatialize(Sample(1.0), &emitter);
+    assert!(s.approx_eq(
+        &Surround51::to_sample_format(Sample(0.5), SphericalPosition::horizontal(90.0)),
+        1e-6
+    ));
+
+    let turned = Listener {
+        orientation: SphericalPosition::horizontal(90.0),
+        ..listener
+    };
+    let (position, _) = turned.locate(emitter.position);
+    assert!(position.azimuth.abs() < 1e-9);
+
+    let raised = Listener {
+        orientation: SphericalPosition::new(0.0, 90.0),
+        ..listener
+    };
+    let (position, _) = raised.locate(Vector3::new(1.0, 1.0, 5.0));
+    assert!(position.elevation.abs() < 1e-9);
+    assert!(position.azimuth.abs() < 1e-9);
+
+    emitter.directivity = 1.0;
+    emitter.orientation = SphericalPosition::horizontal(90.0);
+    assert!(emitter.gain(&listener).abs() < 1e-9);
+    emitter.orientation = SphericalPosition::horizontal(-90.0);
+    assert!((emitter.gain(&listener) - 0.5).abs() < 1e-9);
+
+    let m: Mono = listener.spatialize(Sample(1.0), &Emitter::new(listener.position));
+    assert_eq!(m, Mono::from(Sample(1.0)));
+}
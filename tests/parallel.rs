@@ -0,0 +1,43 @@
+#![cfg(feature = "rayon")]
+
+use bae_sf::*;
+use bae_types::{Math, Sample};
+
+#[test]
+fn test_par_map() {
+    let x = |v| Sample(v);
+    let track: Vec<Stereo> = (0..10000)
+        .map(|i| Stereo::from(x(i as f32 / 10000.0), x(-0.5)))
+        .collect();
+
+    let doubled = par_map(&track, |s| s * Sample(2.0));
+    assert_eq!(doubled.len(), track.len());
+    assert_eq!(doubled[5000], track[5000] * Sample(2.0));
+
+    let mut halved = track.clone();
+    par_gain(&mut halved, Math(-6.020_599_913_279_624));
+    assert!(halved[9999].approx_eq(&(track[9999] * Sample(0.5)), 1e-6));
+
+    let mut negated = track.clone();
+    par_map_in_place(&mut negated, |s| -s);
+    assert_eq!(negated[1], -track[1]);
+
+    let surround: Track<Surround51> = par_convert(&track);
+    let expected: Surround51 = track[1234].convert_format();
+    assert_eq!(surround[1234], expected);
+}
+
+#[test]
+fn test_par_dither() {
+    let track = vec![Mono::from(Sample(0.1)); 10000];
+
+    let mut a = track.clone();
+    let mut b = track.clone();
+    par_dither(&mut a, BitDepth::I16, 3);
+    par_dither(&mut b, BitDepth::I16, 3);
+    assert_eq!(a, b);
+
+    let mut sequential = track[..100].to_vec();
+    TpdfDither::new(3).dither_track(&mut sequential, BitDepth::I16);
+    assert_eq!(a[..100], sequential[..]);
+}
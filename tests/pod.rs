@@ -0,0 +1,29 @@
+#![cfg(feature = "bytemuck")]
+
+use bae_sf::*;
+use bae_types::Sample;
+
+#[test]
+fn test_cast_slice() {
+    let frames = vec![
+        Stereo::from(Sample(0.25), Sample(-0.25)),
+        Stereo::from(Sample(0.5), Sample(-0.5)),
+    ];
+
+    let floats: &[f32] = bytemuck::cast_slice(&frames);
+    assert_eq!(floats, &[0.25, -0.25, 0.5, -0.5]);
+
+    let bytes: &[u8] = bytemuck::cast_slice(&frames);
+    assert_eq!(bytes.len(), 4 * std::mem::size_of::<f32>());
+    assert_eq!(&bytes[..4], &0.25f32.to_ne_bytes());
+}
+
+#[test]
+fn test_cast_slice_mut() {
+    let mut frames = vec![MultiChannel::<3>::new(); 2];
+
+    let floats: &mut [f32] = bytemuck::cast_slice_mut(&mut frames);
+    floats[4] = 1.0;
+
+    assert_eq!(frames[1].channels[1], Sample(1.0));
+}
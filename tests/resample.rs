@@ -0,0 +1,75 @@
+use bae_sf::*;
+use bae_types::{Math, Sample};
+
+fn sine(len: usize, frequency: f64, rate: f64) -> Track<Mono> {
+    (0..len)
+        .map(|i| {
+            let t = i as f64 / rate;
+            Mono::from(Sample(
+                (2.0 * std::f64::consts::PI * frequency * t).sin() as f32
+            ))
+        })
+        .collect()
+}
+
+#[test]
+fn test_linear_resampler() {
+    let x = |v| Sample(v);
+    let track: Track<Mono> =
+        vec![Mono::from(x(0.0)), Mono::from(x(1.0)), Mono::from(x(0.0))].into();
+
+    let up = resample_linear(&track, Math(1.0), Math(2.0));
+    let values: Vec<f32> = up.iter().map(|s| s.into_sample().0).collect();
+    assert_eq!(values, [0.0, 0.5, 1.0, 0.5, 0.0, 0.0]);
+
+    let down = resample_linear(&track, Math(2.0), Math(1.0));
+    assert_eq!(down.len(), 2);
+    assert_eq!(down[1], Mono::from(x(0.0)));
+}
+
+#[test]
+fn test_sinc_resampler() {
+    let input = sine(4800, 1000.0, 48000.0);
+    let expected = sine(4410, 1000.0, 44100.0);
+
+    let output = resample_sinc(&input, Math(48000.0), Math(44100.0));
+    assert_eq!(output.len(), 4410);
+    // Away from the edges, the resampled sine matches the ideal one.
+    for (o, e) in output.iter().zip(expected.iter()).skip(100).take(4200) {
+        assert!(o.approx_eq(e, 1e-3));
+    }
+
+    let up = resample_sinc(&expected, Math(44100.0), Math(48000.0));
+    assert_eq!(up.len(), 4800);
+    for (o, e) in up.iter().zip(input.iter()).skip(100).take(4600) {
+        assert!(o.approx_eq(e, 1e-3));
+    }
+}
+
+#[test]
+fn test_resampler_streaming() {
+    let input = sine(1000, 440.0, 48000.0);
+    let whole = SincResampler::new(Math(48000.0), Math(32000.0), 8).resample_track(&input);
+
+    let mut resampler = SincResampler::new(Math(48000.0), Math(32000.0), 8);
+    let mut chunked = Vec::new();
+    for chunk in input.chunks(37) {
+        resampler.process(chunk, &mut chunked);
+    }
+    resampler.flush(&mut chunked);
+    chunked.truncate(whole.len());
+    assert_eq!(whole.into_vec(), chunked);
+
+    let mut linear = LinearResampler::<Stereo>::new(Math(3.0), Math(2.0));
+    let mut output = Vec::new();
+    linear.process(&[Stereo::SILENCE; 3], &mut output);
+    linear.process(&[Stereo::SILENCE; 3], &mut output);
+    assert_eq!(output.len(), 4);
+    assert!((linear.ratio() - 2.0 / 3.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn test_resampler_zero_rate() {
+    LinearResampler::<Mono>::new(Math(0.0), Math(48000.0));
+}
@@ -0,0 +1,95 @@
+use bae_sf::*;
+use bae_types::Sample;
+
+#[test]
+fn test_ring_buffer_fifo() {
+    let x = |v: usize| Mono::from(Sample(v as f32));
+    let mut rb = RingBuffer::<Mono>::new(3, RingBufferPolicy::Block);
+
+    assert_eq!(rb.capacity(), 4);
+    assert!(rb.is_empty());
+    assert_eq!(rb.pop(), None);
+
+    assert_eq!(rb.push_slice(&[x(1), x(2), x(3), x(4), x(5)]), 4);
+    assert!(rb.is_full());
+    assert!(!rb.push(x(6)));
+    assert_eq!(rb.pop(), Some(x(1)));
+    assert!(rb.push(x(6)));
+
+    let mut out = [Mono::SILENCE; 6];
+    assert_eq!(rb.pop_slice(&mut out), 4);
+    assert_eq!(out[..4], [x(2), x(3), x(4), x(6)]);
+    assert!(rb.is_empty());
+}
+
+#[test]
+fn test_ring_buffer_delay_line() {
+    let x = |v: usize| Stereo::from(Sample(v as f32), Sample(-(v as f32)));
+    let mut rb = RingBuffer::<Stereo>::new(4, RingBufferPolicy::Overwrite);
+
+    for i in 1..=6 {
+        assert!(rb.push(x(i)));
+    }
+    assert_eq!(rb.len(), 4);
+    assert_eq!(rb.tap(0), x(6));
+    assert_eq!(rb.tap(3), x(3));
+    assert_eq!(rb.tap(4), Stereo::SILENCE);
+    assert_eq!(rb.pop(), Some(x(3)));
+    assert_eq!(rb.policy(), RingBufferPolicy::Overwrite);
+
+    rb.clear();
+    assert!(rb.is_empty());
+    assert_eq!(
+        RingBuffer::<Mono>::new(0, RingBufferPolicy::Block).capacity(),
+        1
+    );
+}
+
+#[test]
+fn test_ring_buffer_split() {
+    let x = |v: usize| Mono::from(Sample(v as f32));
+    let mut rb = RingBuffer::<Mono>::new(4, RingBufferPolicy::Overwrite);
+    rb.push_slice(&[x(1), x(2)]);
+
+    let (mut producer, mut consumer) = rb.split();
+    assert_eq!(consumer.len(), 2);
+    assert_eq!(producer.push_slice(&[x(3), x(4), x(5)]), 2);
+    assert!(producer.is_full());
+
+    let mut out = [Mono::SILENCE; 3];
+    assert_eq!(consumer.pop_slice(&mut out), 3);
+    assert_eq!(out, [x(1), x(2), x(3)]);
+    assert_eq!(consumer.pop(), Some(x(4)));
+    assert_eq!(consumer.pop(), None);
+    assert!(producer.is_empty());
+}
+
+#[test]
+fn test_ring_buffer_split_threads() {
+    const COUNT: usize = 100_000;
+
+    let x = |v: usize| Mono::from(Sample(v as f32));
+    let (mut producer, mut consumer) = RingBuffer::<Mono>::new(64, RingBufferPolicy::Block).split();
+
+    let writer = std::thread::spawn(move || {
+        for i in 0..COUNT {
+            while !producer.push(x(i)) {
+                std::thread::yield_now();
+            }
+        }
+    });
+
+    let mut i = 0;
+    while i < COUNT {
+        match consumer.pop() {
+            Some(s) => {
+                assert_eq!(s, x(i));
+                i += 1;
+            }
+            None => std::thread::yield_now(),
+        }
+    }
+
+    writer.join().unwrap();
+    assert!(consumer.is_empty());
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "serde")]
+
+use bae_sf::*;
+use bae_types::Sample;
+
+#[test]
+fn test_round_trip() {
+    let track: StereoTrackT = vec![
+        Stereo::from(Sample(0.5), Sample(-0.5)),
+        Stereo::from(Sample(0.25), Sample(0.0)),
+    ];
+
+    let json = serde_json::to_string(&track).unwrap();
+    assert_eq!(json, "[[0.5,-0.5],[0.25,0.0]]");
+    assert_eq!(serde_json::from_str::<StereoTrackT>(&json).unwrap(), track);
+
+    let d: DynFormat = serde_json::from_str("[1.0,0.5,0.25]").unwrap();
+    assert_eq!(d.num_samples(), 3);
+    assert_eq!(serde_json::to_string(&d).unwrap(), "[1.0,0.5,0.25]");
+}
+
+#[test]
+fn test_wrong_length() {
+    assert!(serde_json::from_str::<Stereo>("[1.0]").is_err());
+    assert!(serde_json::from_str::<Stereo>("[1.0,0.5,0.25]").is_err());
+}
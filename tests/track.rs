@@ -0,0 +1,237 @@
+use bae_sf::*;
+use bae_types::{Math, Sample};
+
+#[test]
+fn test_track() {
+    let x = |v| Sample(v);
+    let mut t: Track<Mono> = vec![Mono::from(x(0.5)), Mono::from(x(-0.25))].into();
+
+    assert_eq!(t.len(), 2);
+    assert_eq!(t[1], Mono::from(x(-0.25)));
+
+    t.gain(Math(-6.020_599_913_279_624));
+    assert!(t[0].approx_eq(&Mono::from(x(0.25)), 1e-6));
+
+    let mut other = Track::silence(3);
+    other[2] = Mono::from(x(1.0));
+    t.mix(&other);
+    assert_eq!(t.len(), 3);
+    assert_eq!(t[2], Mono::from(x(1.0)));
+    assert!(t[1].approx_eq(&Mono::from(x(-0.125)), 1e-6));
+
+    t.append(&other);
+    assert_eq!(t.len(), 6);
+    assert_eq!(t[5], Mono::from(x(1.0)));
+
+    let (head, tail) = t.clone().split_at(4);
+    assert_eq!(head.len(), 4);
+    assert_eq!(tail.into_vec(), vec![Mono::new(), Mono::from(x(1.0))]);
+
+    t.resize_silence(8);
+    assert!(t[6..].iter().all(|s| s.is_silent()));
+    t.resize_silence(1);
+    assert_eq!(t.len(), 1);
+
+    t.push(Mono::from(x(0.5)));
+    t.extend(vec![Mono::from(x(0.5))]);
+    for s in &mut t {
+        *s = s.abs();
+    }
+    let sum: f32 = t.iter().map(|s| s.mono.0).sum();
+    assert!((sum - 1.25).abs() < 1e-6);
+
+    let collected: Track<Mono> = t.into_iter().rev().collect();
+    let v: Vec<Mono> = collected.into();
+    assert_eq!(v[0], Mono::from(x(0.5)));
+}
+
+#[test]
+#[should_panic]
+fn test_track_split_out_of_range() {
+    let _ = Track::<Stereo>::silence(2).split_at(3);
+}
+
+#[test]
+fn test_dc_offset() {
+    let x = |v| Sample(v);
+    let mut track = vec![
+        Stereo::from(x(0.5), x(-0.25)),
+        Stereo::from(x(0.0), x(-0.75)),
+    ];
+
+    assert_eq!(dc_offset(&track), Stereo::from(x(0.25), x(-0.5)));
+    assert_eq!(remove_dc_offset(&mut track), Stereo::from(x(0.25), x(-0.5)));
+    assert_eq!(
+        track,
+        vec![
+            Stereo::from(x(0.25), x(0.25)),
+            Stereo::from(x(-0.25), x(-0.25))
+        ]
+    );
+    assert_eq!(dc_offset::<Stereo>(&[]), Stereo::SILENCE);
+
+    let mut blocker = DcBlocker::<Mono>::new(Math(48000.0), Math(5.0));
+    let mut offset = vec![Mono::from(x(0.5)); 48000];
+    blocker.process_track(&mut offset);
+    assert!(offset[0].approx_eq(&Mono::from(x(0.5)), 1e-6));
+    assert!(offset[47999].into_sample().0.abs() < 1e-6);
+
+    blocker.reset();
+    assert!(blocker
+        .process(Mono::from(x(0.5)))
+        .approx_eq(&Mono::from(x(0.5)), 1e-6));
+}
+
+#[test]
+fn test_fades() {
+    let x = |v| Sample(v);
+    let mut track = vec![Mono::from(x(1.0)); 6];
+
+    fade_in(&mut track, 4, FadeCurve::Linear);
+    let values: Vec<f32> = track.iter().map(|s| s.into_sample().0).collect();
+    assert_eq!(values, [0.0, 0.25, 0.5, 0.75, 1.0, 1.0]);
+
+    let mut track = vec![Mono::from(x(1.0)); 3];
+    fade_out(&mut track, 10, FadeCurve::SCurve);
+    assert!(track[0].approx_eq(&Mono::from(x(0.75)), 1e-6));
+    assert!(track[1].approx_eq(&Mono::from(x(0.25)), 1e-6));
+    assert_eq!(track[2], Mono::SILENCE);
+
+    assert_eq!(FadeCurve::EqualPower.gain(1.0), 1.0);
+    assert_eq!(FadeCurve::Linear.gain(2.0), 1.0);
+    let power = FadeCurve::EqualPower.gain(0.3).powi(2) + FadeCurve::EqualPower.gain(0.7).powi(2);
+    assert!((power - 1.0).abs() < 1e-9);
+
+    let a = vec![Mono::from(x(1.0)); 4];
+    let b = vec![Mono::from(x(0.5)); 3];
+    let joined = crossfade(&a, &b, 2, FadeCurve::Linear);
+    assert_eq!(joined.len(), 5);
+    assert_eq!(joined[1], Mono::from(x(1.0)));
+    assert!(joined[2].approx_eq(&Mono::from(x(0.875)), 1e-6));
+    assert!(joined[3].approx_eq(&Mono::from(x(0.625)), 1e-6));
+    assert_eq!(joined[4], Mono::from(x(0.5)));
+    assert_eq!(crossfade(&a, &b, 100, FadeCurve::EqualPower).len(), 4);
+}
+
+#[test]
+fn test_clamp_track() {
+    let x = |v| Sample(v);
+
+    assert_eq!(
+        Stereo::from(x(1.5), x(-0.5)).clamped(x(-1.0), x(1.0)),
+        (Stereo::from(x(1.0), x(-0.5)), 1)
+    );
+
+    let mut track: Track<Stereo> = vec![
+        Stereo::from(x(1.5), x(-1.5)),
+        Stereo::from(x(0.5), x(-0.5)),
+        Stereo::from(x(0.0), x(-2.0)),
+    ]
+    .into();
+    let report = clamp_track(&mut track, x(-1.0), x(1.0));
+    assert_eq!(
+        report,
+        ClipReport {
+            samples: 2,
+            values: 3
+        }
+    );
+    assert!(!report.is_clean());
+    assert_eq!(track[0], Stereo::from(x(1.0), x(-1.0)));
+    assert_eq!(track[2], Stereo::from(x(0.0), x(-1.0)));
+    assert!(clamp_track(&mut track, x(-1.0), x(1.0)).is_clean());
+}
+
+#[test]
+fn test_mix_tracks() {
+    let x = |v| Sample(v);
+    let a: Track<Mono> = vec![Mono::from(x(0.5)); 2].into();
+    let b: Track<Mono> = vec![Mono::from(x(0.25)); 3].into();
+
+    let mixed = mix(&[(&a, Math(0.0)), (&b, Math(-6.020_599_913_279_624))]);
+    assert_eq!(mixed.len(), 3);
+    assert!(mixed[0].approx_eq(&Mono::from(x(0.625)), 1e-6));
+    assert!(mixed[2].approx_eq(&Mono::from(x(0.125)), 1e-6));
+    assert!(mix::<Stereo>(&[]).is_empty());
+
+    let mut bus = [Mono::from(x(0.125)); 2];
+    mix_into(&mut bus, &[(&b, Math(0.0)), (&a, Math(f64::NEG_INFINITY))]);
+    assert_eq!(bus, [Mono::from(x(0.375)); 2]);
+}
+
+#[test]
+fn test_split_channels() {
+    let x = |v| Sample(v);
+    let track: Track<Stereo> = vec![
+        Stereo::from(x(0.5), x(-0.5)),
+        Stereo::from(x(0.25), x(-0.25)),
+    ]
+    .into();
+
+    let channels = split_channels(&track);
+    assert_eq!(channels.len(), 2);
+    assert_eq!(channels[0], vec![Mono::from(x(0.5)), Mono::from(x(0.25))]);
+    assert_eq!(channels[1], vec![Mono::from(x(-0.5)), Mono::from(x(-0.25))]);
+    assert_eq!(
+        split_channels(&Track::<Surround51>::new()),
+        vec![Vec::new(); 6]
+    );
+}
+
+#[test]
+fn test_merge_channels() {
+    let x = |v| Sample(v);
+    let track: Track<Stereo> = vec![
+        Stereo::from(x(0.5), x(-0.5)),
+        Stereo::from(x(0.25), x(-0.25)),
+    ]
+    .into();
+
+    assert_eq!(merge_channels(&split_channels(&track)), Ok(track));
+
+    let mono = vec![Mono::from(x(1.0)); 2];
+    assert_eq!(
+        merge_channels::<Stereo>(std::slice::from_ref(&mono)),
+        Err(SampleFormatError::ChannelCountMismatch { left: 1, right: 2 })
+    );
+    assert_eq!(
+        merge_channels::<Stereo>(&[mono.clone(), vec![Mono::SILENCE; 3]]),
+        Err(SampleFormatError::WrongLength {
+            expected: 2,
+            actual: 3
+        })
+    );
+    assert_eq!(
+        merge_channels::<Mono>(std::slice::from_ref(&mono)),
+        Ok(Track::from(mono))
+    );
+}
+
+#[test]
+fn test_reverse_looped() {
+    let x = |v| Sample(v);
+    let mut track: Track<Mono> = (1..=4).map(|i| Mono::from(x(i as f32 / 4.0))).collect();
+
+    reverse(&mut track);
+    assert_eq!(track[0], Mono::from(x(1.0)));
+    assert_eq!(track[3], Mono::from(x(0.25)));
+    reverse(&mut track);
+
+    let hard = looped(&track, 3, 0);
+    assert_eq!(hard.len(), 12);
+    assert_eq!(hard[4], track[0]);
+    assert_eq!(hard[11], track[3]);
+
+    let smooth = looped(&track, 3, 2);
+    assert_eq!(smooth.len(), 8);
+    assert_eq!(smooth[..2], track[..2]);
+    let joined = crossfade(&track, &track, 2, FadeCurve::EqualPower);
+    assert_eq!(smooth[..4], joined[..4]);
+    assert_eq!(smooth[4..6], smooth[2..4]);
+    assert_eq!(smooth[7], track[3]);
+
+    assert!(looped(&track, 0, 2).is_empty());
+    assert_eq!(looped(&track, 1, 10), track);
+    assert_eq!(looped(&track, 2, 10), looped(&track, 2, 2));
+    assert_eq!(looped(&track, 2, 10).len(), 6);
+}